@@ -1,9 +1,11 @@
+use chrono::{DateTime, Utc};
 use config::{Environment, File, FileFormat};
 use rsa::{PublicKeyPemEncoding, RSAPrivateKey, RSAPublicKey};
 use serde_derive::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use std::{fs, io};
 
 #[derive(thiserror::Error, Debug)]
@@ -48,41 +50,1383 @@ pub struct Security {
 impl Security {
     #[cfg(feature = "client")]
     pub fn public_key_pkcs1(&self) -> String {
+        self.public_key()
+            .to_pem_pkcs1()
+            .expect("Public key is not encodable")
+    }
+
+    #[cfg(feature = "client")]
+    pub fn public_key(&self) -> RSAPublicKey {
         let pem = rsa::pem::parse(self.public_key.as_bytes()).expect("Public key is not valid PEM");
 
-        RSAPublicKey::try_from(pem)
-            .expect("Public key is not valid RSA key material")
-            .to_pem_pkcs1()
-            .expect("Public key is not encodable")
+        RSAPublicKey::try_from(pem).expect("Public key is not valid RSA key material")
+    }
+
+    #[cfg(feature = "server")]
+    pub fn private_key_pkcs1(&self) -> String {
+        self.private_key()
+            .to_pem_pkcs1()
+            .expect("Private key is not encodable")
+    }
+
+    #[cfg(feature = "server")]
+    pub fn private_key(&self) -> RSAPrivateKey {
+        let pem =
+            rsa::pem::parse(self.private_key.as_bytes()).expect("Private key is not valid PEM");
+
+        RSAPrivateKey::try_from(pem).expect("Private key is not valid RSA key material")
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Server {
+    hostname: String,
+
+    #[cfg(feature = "server")]
+    bind_address: String,
+
+    #[cfg(feature = "server")]
+    #[serde(default = "Server::default_login_port")]
+    login_port: u16,
+
+    #[cfg(feature = "server")]
+    #[serde(default = "Server::default_auth_port")]
+    auth_port: u16,
+
+    #[cfg(feature = "server")]
+    #[serde(default = "Server::default_game_port")]
+    game_port: u16,
+}
+
+impl Server {
+    pub fn hostname(&self) -> &str {
+        &self.hostname
+    }
+
+    pub fn bind_addr(&self) -> &str {
+        &self.bind_address
+    }
+
+    /// The port the login server listens on. The game client always connects to 50050.
+    #[cfg(feature = "server")]
+    pub fn login_port(&self) -> u16 {
+        self.login_port
+    }
+
+    /// The port advertised to clients by the login server for the auth server.
+    #[cfg(feature = "server")]
+    pub fn auth_port(&self) -> u16 {
+        self.auth_port
+    }
+
+    /// The port advertised to clients by the auth server for the game server.
+    #[cfg(feature = "server")]
+    pub fn game_port(&self) -> u16 {
+        self.game_port
+    }
+
+    #[cfg(feature = "server")]
+    fn default_login_port() -> u16 {
+        50050
+    }
+
+    #[cfg(feature = "server")]
+    fn default_auth_port() -> u16 {
+        50000
+    }
+
+    #[cfg(feature = "server")]
+    fn default_game_port() -> u16 {
+        50010
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Logging {
+    level: String,
+}
+
+#[cfg(feature = "server")]
+impl Logging {
+    /// A `tracing` filter directive, e.g. `info` or `dks3_server=debug,warn`.
+    pub fn level(&self) -> &str {
+        &self.level
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Logging {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Sessions {
+    #[serde(default = "Sessions::default_idle_timeout_secs")]
+    idle_timeout_secs: u64,
+
+    #[serde(default = "Sessions::default_request_rate")]
+    request_rate: u32,
+
+    #[serde(default = "Sessions::default_request_burst")]
+    request_burst: u32,
+}
+
+#[cfg(feature = "server")]
+impl Sessions {
+    /// How long a session may go without any activity before it is expired.
+    pub fn idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.idle_timeout_secs)
+    }
+
+    /// The sustained number of requests per second a session may make.
+    pub fn request_rate(&self) -> u32 {
+        self.request_rate
+    }
+
+    /// How many requests a session may make at once before being held to `request_rate`.
+    pub fn request_burst(&self) -> u32 {
+        self.request_burst
+    }
+
+    fn default_idle_timeout_secs() -> u64 {
+        5 * 60
+    }
+
+    fn default_request_rate() -> u32 {
+        20
+    }
+
+    fn default_request_burst() -> u32 {
+        50
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Sessions {
+    fn default() -> Self {
+        Self {
+            idle_timeout_secs: Self::default_idle_timeout_secs(),
+            request_rate: Self::default_request_rate(),
+            request_burst: Self::default_request_burst(),
+        }
+    }
+}
+
+/// The HTTP API that lets admins and community tools look at, and act on, the running server.
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Admin {
+    port: Option<u16>,
+
+    #[serde(default = "Admin::default_bind_address")]
+    bind_address: String,
+
+    token: Option<String>,
+}
+
+#[cfg(feature = "server")]
+impl Admin {
+    /// The port the admin API listens on, or `None` if it is disabled.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// The address the admin API listens on. Defaults to localhost, so that it isn't exposed
+    /// alongside the game server unless asked for.
+    pub fn bind_addr(&self) -> &str {
+        &self.bind_address
+    }
+
+    /// The bearer token requests to the admin API must present, if any. It is required for the
+    /// admin API to listen on anything but a loopback address.
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    fn default_bind_address() -> String {
+        "127.0.0.1".to_string()
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Admin {
+    fn default() -> Self {
+        Self {
+            port: None,
+            bind_address: Self::default_bind_address(),
+            token: None,
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Announcements {
+    path: Option<PathBuf>,
+
+    #[serde(default = "Announcements::default_reload_interval_secs")]
+    reload_interval_secs: u64,
+}
+
+#[cfg(feature = "server")]
+impl Announcements {
+    /// The TOML or JSON file holding the announcements shown on the login screen, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// How often to check the announcements file for changes.
+    pub fn reload_interval(&self) -> Duration {
+        Duration::from_secs(self.reload_interval_secs)
+    }
+
+    fn default_reload_interval_secs() -> u64 {
+        5
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Announcements {
+    fn default() -> Self {
+        Self {
+            path: None,
+            reload_interval_secs: Self::default_reload_interval_secs(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Storage {
+    data_dir: Option<PathBuf>,
+
+    #[serde(default = "Storage::default_flush_interval_secs")]
+    flush_interval_secs: u64,
+}
+
+#[cfg(feature = "server")]
+impl Storage {
+    /// Where the server keeps the state that should survive a restart. If this isn't set
+    /// nothing is persisted.
+    pub fn data_dir(&self) -> Option<&Path> {
+        self.data_dir.as_deref()
+    }
+
+    /// How often changed state is written to the data directory.
+    pub fn flush_interval(&self) -> Duration {
+        Duration::from_secs(self.flush_interval_secs)
+    }
+
+    fn default_flush_interval_secs() -> u64 {
+        30
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Storage {
+    fn default() -> Self {
+        Self {
+            data_dir: None,
+            flush_interval_secs: Self::default_flush_interval_secs(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BloodMessages {
+    #[serde(default = "BloodMessages::default_max_list_entries")]
+    max_list_entries: usize,
+
+    #[serde(default = "BloodMessages::default_max_per_player")]
+    max_per_player: usize,
+}
+
+#[cfg(feature = "server")]
+impl BloodMessages {
+    /// The most messages returned when a client asks for the messages in its area.
+    pub fn max_list_entries(&self) -> usize {
+        self.max_list_entries
+    }
+
+    /// How many messages a player may have placed at once. Placing another removes their oldest.
+    pub fn max_per_player(&self) -> usize {
+        self.max_per_player
+    }
+
+    fn default_max_list_entries() -> usize {
+        10
+    }
+
+    fn default_max_per_player() -> usize {
+        10
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for BloodMessages {
+    fn default() -> Self {
+        Self {
+            max_list_entries: Self::default_max_list_entries(),
+            max_per_player: Self::default_max_per_player(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Bloodstains {
+    #[serde(default = "Bloodstains::default_max_list_entries")]
+    max_list_entries: usize,
+
+    #[serde(default = "Bloodstains::default_max_replay_bytes")]
+    max_replay_bytes: usize,
+
+    #[serde(default = "Bloodstains::default_max_age_secs")]
+    max_age_secs: u64,
+
+    #[serde(default = "Bloodstains::default_max_per_player")]
+    max_per_player: usize,
+
+    #[serde(default = "Bloodstains::default_max_per_area")]
+    max_per_area: usize,
+}
+
+#[cfg(feature = "server")]
+impl Bloodstains {
+    /// The most bloodstains returned when a client asks for the bloodstains in its area.
+    pub fn max_list_entries(&self) -> usize {
+        self.max_list_entries
+    }
+
+    /// The largest replay a client may upload with a bloodstain.
+    pub fn max_replay_bytes(&self) -> usize {
+        self.max_replay_bytes
+    }
+
+    /// How long a bloodstain is kept before it is removed.
+    pub fn max_age(&self) -> Duration {
+        Duration::from_secs(self.max_age_secs)
+    }
+
+    /// How many bloodstains a player may have left at once. Leaving another removes their
+    /// oldest.
+    pub fn max_per_player(&self) -> usize {
+        self.max_per_player
+    }
+
+    /// How many bloodstains are kept for each online area. Leaving another removes the oldest.
+    pub fn max_per_area(&self) -> usize {
+        self.max_per_area
+    }
+
+    fn default_max_list_entries() -> usize {
+        10
+    }
+
+    fn default_max_replay_bytes() -> usize {
+        64 * 1024
+    }
+
+    fn default_max_age_secs() -> u64 {
+        7 * 24 * 60 * 60
+    }
+
+    fn default_max_per_player() -> usize {
+        10
+    }
+
+    fn default_max_per_area() -> usize {
+        200
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Bloodstains {
+    fn default() -> Self {
+        Self {
+            max_list_entries: Self::default_max_list_entries(),
+            max_replay_bytes: Self::default_max_replay_bytes(),
+            max_age_secs: Self::default_max_age_secs(),
+            max_per_player: Self::default_max_per_player(),
+            max_per_area: Self::default_max_per_area(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Ghosts {
+    #[serde(default = "Ghosts::default_max_list_entries")]
+    max_list_entries: usize,
+
+    #[serde(default = "Ghosts::default_max_per_area")]
+    max_per_area: usize,
+
+    #[serde(default = "Ghosts::default_max_data_bytes")]
+    max_data_bytes: usize,
+
+    #[serde(default = "Ghosts::default_upload_interval_secs")]
+    upload_interval_secs: u64,
+}
+
+#[cfg(feature = "server")]
+impl Ghosts {
+    /// The most ghosts returned when a client asks for the ghosts in its area.
+    pub fn max_list_entries(&self) -> usize {
+        self.max_list_entries
+    }
+
+    /// How many ghosts are kept for each area. Once there are this many, each new upload
+    /// replaces the oldest.
+    pub fn max_per_area(&self) -> usize {
+        self.max_per_area
+    }
+
+    /// The largest recording a client may upload.
+    pub fn max_data_bytes(&self) -> usize {
+        self.max_data_bytes
+    }
+
+    /// The shortest time a session must wait between uploads.
+    pub fn upload_interval(&self) -> Duration {
+        Duration::from_secs(self.upload_interval_secs)
+    }
+
+    fn default_max_list_entries() -> usize {
+        5
+    }
+
+    fn default_max_per_area() -> usize {
+        50
+    }
+
+    fn default_max_data_bytes() -> usize {
+        16 * 1024
+    }
+
+    fn default_upload_interval_secs() -> u64 {
+        30
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Ghosts {
+    fn default() -> Self {
+        Self {
+            max_list_entries: Self::default_max_list_entries(),
+            max_per_area: Self::default_max_per_area(),
+            max_data_bytes: Self::default_max_data_bytes(),
+            upload_interval_secs: Self::default_upload_interval_secs(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Signs {
+    #[serde(default = "Signs::default_max_list_entries")]
+    max_list_entries: usize,
+
+    #[serde(default = "Signs::default_summon_timeout_secs")]
+    summon_timeout_secs: u64,
+}
+
+#[cfg(feature = "server")]
+impl Signs {
+    /// The most signs returned when a host asks for the signs in their area.
+    pub fn max_list_entries(&self) -> usize {
+        self.max_list_entries
+    }
+
+    /// How long a sign's owner has to answer a summon before another host may summon them.
+    pub fn summon_timeout(&self) -> Duration {
+        Duration::from_secs(self.summon_timeout_secs)
+    }
+
+    fn default_max_list_entries() -> usize {
+        10
+    }
+
+    fn default_summon_timeout_secs() -> u64 {
+        30
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Signs {
+    fn default() -> Self {
+        Self {
+            max_list_entries: Self::default_max_list_entries(),
+            summon_timeout_secs: Self::default_summon_timeout_secs(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Invasions {
+    #[serde(default = "Invasions::default_max_list_entries")]
+    max_list_entries: usize,
+
+    #[serde(default = "Invasions::default_cooldown_secs")]
+    cooldown_secs: u64,
+}
+
+#[cfg(feature = "server")]
+impl Invasions {
+    /// The most hosts returned when an invader asks for targets.
+    pub fn max_list_entries(&self) -> usize {
+        self.max_list_entries
+    }
+
+    /// How long a host is left alone after being invaded.
+    pub fn cooldown(&self) -> Duration {
+        Duration::from_secs(self.cooldown_secs)
+    }
+
+    fn default_max_list_entries() -> usize {
+        5
+    }
+
+    fn default_cooldown_secs() -> u64 {
+        120
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Invasions {
+    fn default() -> Self {
+        Self {
+            max_list_entries: Self::default_max_list_entries(),
+            cooldown_secs: Self::default_cooldown_secs(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuickMatches {
+    #[serde(default = "QuickMatches::default_max_list_entries")]
+    max_list_entries: usize,
+
+    #[serde(default = "QuickMatches::default_lobby_timeout_secs")]
+    lobby_timeout_secs: u64,
+}
+
+#[cfg(feature = "server")]
+impl QuickMatches {
+    /// The most arena matches returned when a player searches for one to join.
+    pub fn max_list_entries(&self) -> usize {
+        self.max_list_entries
+    }
+
+    /// How long a match can go without anything happening before it's dissolved.
+    pub fn lobby_timeout(&self) -> Duration {
+        Duration::from_secs(self.lobby_timeout_secs)
+    }
+
+    fn default_max_list_entries() -> usize {
+        10
+    }
+
+    fn default_lobby_timeout_secs() -> u64 {
+        300
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for QuickMatches {
+    fn default() -> Self {
+        Self {
+            max_list_entries: Self::default_max_list_entries(),
+            lobby_timeout_secs: Self::default_lobby_timeout_secs(),
+        }
+    }
+}
+
+/// Elo ratings for arena players, kept separately for each mode.
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Ratings {
+    #[serde(default = "Ratings::default_initial_rating")]
+    initial_rating: f64,
+
+    #[serde(default = "Ratings::default_k_factor")]
+    k_factor: f64,
+
+    #[serde(default = "Ratings::default_pairing_range")]
+    pairing_range: f64,
+
+    #[serde(default = "Ratings::default_max_ladder_entries")]
+    max_ladder_entries: usize,
+}
+
+#[cfg(feature = "server")]
+impl Ratings {
+    /// The rating players start out with.
+    pub fn initial_rating(&self) -> f64 {
+        self.initial_rating
+    }
+
+    /// The most a player's rating can change by in a single match.
+    pub fn k_factor(&self) -> f64 {
+        self.k_factor
+    }
+
+    /// How far apart two players' ratings may be for one to find the other's match.
+    pub fn pairing_range(&self) -> f64 {
+        self.pairing_range
+    }
+
+    /// The most players returned from the ladder at once.
+    pub fn max_ladder_entries(&self) -> usize {
+        self.max_ladder_entries
+    }
+
+    fn default_initial_rating() -> f64 {
+        1500.0
+    }
+
+    fn default_k_factor() -> f64 {
+        32.0
+    }
+
+    fn default_pairing_range() -> f64 {
+        400.0
+    }
+
+    fn default_max_ladder_entries() -> usize {
+        20
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Ratings {
+    fn default() -> Self {
+        Self {
+            initial_rating: Self::default_initial_rating(),
+            k_factor: Self::default_k_factor(),
+            pairing_range: Self::default_pairing_range(),
+            max_ladder_entries: Self::default_max_ladder_entries(),
+        }
+    }
+}
+
+/// How far from a player's soul level another player's may be for them to match: the other
+/// player may be up to `below` plus `below_percent`% lower, and up to `above` plus
+/// `above_percent`% higher. Percentages are of the first player's soul level, rounded down.
+#[cfg(feature = "server")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub struct SoulLevelBand {
+    below: u32,
+    below_percent: u32,
+    above: u32,
+    above_percent: u32,
+}
+
+#[cfg(feature = "server")]
+impl SoulLevelBand {
+    pub fn new(below: u32, below_percent: u32, above: u32, above_percent: u32) -> Self {
+        Self {
+            below,
+            below_percent,
+            above,
+            above_percent,
+        }
+    }
+
+    /// The lowest and highest soul levels that match a player at `soul_level`.
+    pub fn range(&self, soul_level: u32) -> (u32, u32) {
+        let below = self.below + soul_level * self.below_percent / 100;
+        let above = self.above + soul_level * self.above_percent / 100;
+
+        (soul_level.saturating_sub(below), soul_level + above)
+    }
+}
+
+/// The weapon upgrade levels a player may be matched with, given their own.
+#[cfg(feature = "server")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub struct WeaponLevelRange {
+    lowest: u32,
+    highest: u32,
+}
+
+#[cfg(feature = "server")]
+impl WeaponLevelRange {
+    pub fn new(lowest: u32, highest: u32) -> Self {
+        Self { lowest, highest }
+    }
+
+    pub fn contains(&self, weapon_level: u32) -> bool {
+        weapon_level >= self.lowest && weapon_level <= self.highest
+    }
+}
+
+/// The matchmaking rules for one kind of multiplayer.
+#[cfg(feature = "server")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MatchingRules {
+    soul_levels: SoulLevelBand,
+    check_weapon_level: bool,
+    password_bypass: bool,
+}
+
+#[cfg(feature = "server")]
+impl MatchingRules {
+    pub fn soul_levels(&self) -> &SoulLevelBand {
+        &self.soul_levels
+    }
+
+    /// Whether players' weapon upgrade levels have to match.
+    pub fn check_weapon_level(&self) -> bool {
+        self.check_weapon_level
+    }
+
+    /// Whether players using the same password match regardless of soul level. Players using
+    /// different passwords never match either way.
+    pub fn password_bypass(&self) -> bool {
+        self.password_bypass
+    }
+
+    fn default_coop() -> Self {
+        Self {
+            soul_levels: SoulLevelBand::new(10, 10, 10, 10),
+            check_weapon_level: true,
+            password_bypass: true,
+        }
+    }
+
+    fn default_invasion() -> Self {
+        Self {
+            soul_levels: SoulLevelBand::new(0, 10, 20, 10),
+            check_weapon_level: true,
+            password_bypass: true,
+        }
+    }
+
+    fn default_covenant() -> Self {
+        Self {
+            soul_levels: SoulLevelBand::new(0, 10, 20, 10),
+            check_weapon_level: true,
+            password_bypass: false,
+        }
+    }
+}
+
+/// Who gets to play with whom. The defaults are the rules of the official servers.
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Matchmaking {
+    #[serde(default = "Matchmaking::default_weapon_levels")]
+    weapon_levels: Vec<WeaponLevelRange>,
+
+    #[serde(default = "MatchingRules::default_coop")]
+    coop: MatchingRules,
+
+    #[serde(default = "MatchingRules::default_invasion")]
+    invasion: MatchingRules,
+
+    #[serde(default = "MatchingRules::default_covenant")]
+    covenant: MatchingRules,
+
+    #[serde(default = "MatchingRules::default_coop")]
+    arena: MatchingRules,
+}
+
+#[cfg(feature = "server")]
+impl Matchmaking {
+    /// The weapon levels a player with the weapon level `weapon_level` may be matched with.
+    /// Levels past the end of the table use its last entry.
+    pub fn weapon_levels(&self, weapon_level: u32) -> WeaponLevelRange {
+        let index = (weapon_level as usize).min(self.weapon_levels.len().saturating_sub(1));
+        self.weapon_levels
+            .get(index)
+            .copied()
+            .unwrap_or_else(|| WeaponLevelRange::new(0, u32::MAX))
+    }
+
+    /// The rules for summoning co-op phantoms and duelists.
+    pub fn coop(&self) -> &MatchingRules {
+        &self.coop
+    }
+
+    /// The rules for invading another player's world.
+    pub fn invasion(&self) -> &MatchingRules {
+        &self.invasion
+    }
+
+    /// The rules for covenants that summon their members into other players' worlds.
+    pub fn covenant(&self) -> &MatchingRules {
+        &self.covenant
+    }
+
+    /// The rules for joining an arena match.
+    pub fn arena(&self) -> &MatchingRules {
+        &self.arena
+    }
+
+    fn default_weapon_levels() -> Vec<WeaponLevelRange> {
+        [
+            (0, 1),
+            (0, 2),
+            (1, 3),
+            (2, 4),
+            (2, 5),
+            (3, 6),
+            (4, 7),
+            (5, 8),
+            (5, 9),
+            (6, 10),
+            (7, 10),
+        ]
+        .iter()
+        .map(|&(lowest, highest)| WeaponLevelRange::new(lowest, highest))
+        .collect()
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Matchmaking {
+    fn default() -> Self {
+        Self {
+            weapon_levels: Self::default_weapon_levels(),
+            coop: MatchingRules::default_coop(),
+            invasion: MatchingRules::default_invasion(),
+            covenant: MatchingRules::default_covenant(),
+            arena: MatchingRules::default_coop(),
+        }
+    }
+}
+
+/// A covenant players join by equipping its item.
+#[cfg(feature = "server")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Covenant {
+    BladeOfTheDarkmoon,
+    WarriorOfSunlight,
+    MoundMakers,
+    SpearsOfTheChurch,
+    RosariasFingers,
+    WatchdogsOfFarron,
+    AldrichFaithful,
+    WayOfBlue,
+    BlueSentinels,
+}
+
+#[cfg(feature = "server")]
+impl Covenant {
+    /// The ID the client uses for this covenant.
+    pub fn id(self) -> u32 {
+        match self {
+            Covenant::BladeOfTheDarkmoon => 1,
+            Covenant::WarriorOfSunlight => 2,
+            Covenant::MoundMakers => 3,
+            Covenant::SpearsOfTheChurch => 4,
+            Covenant::RosariasFingers => 5,
+            Covenant::WatchdogsOfFarron => 6,
+            Covenant::AldrichFaithful => 7,
+            Covenant::WayOfBlue => 8,
+            Covenant::BlueSentinels => 9,
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl TryFrom<u32> for Covenant {
+    type Error = u32;
+
+    fn try_from(id: u32) -> Result<Self, Self::Error> {
+        Ok(match id {
+            1 => Covenant::BladeOfTheDarkmoon,
+            2 => Covenant::WarriorOfSunlight,
+            3 => Covenant::MoundMakers,
+            4 => Covenant::SpearsOfTheChurch,
+            5 => Covenant::RosariasFingers,
+            6 => Covenant::WatchdogsOfFarron,
+            7 => Covenant::AldrichFaithful,
+            8 => Covenant::WayOfBlue,
+            9 => Covenant::BlueSentinels,
+            _ => return Err(id),
+        })
+    }
+}
+
+/// When members of `covenant` are summoned to defend a host who has just been invaded: either
+/// because the host is in one of `online_area_ids`, or because they're in one of
+/// `host_covenants`.
+#[cfg(feature = "server")]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct VisitPolicy {
+    covenant: Covenant,
+
+    #[serde(default)]
+    online_area_ids: Vec<u32>,
+
+    #[serde(default)]
+    host_covenants: Vec<Covenant>,
+
+    #[serde(default = "VisitPolicy::default_max_visitors")]
+    max_visitors: usize,
+}
+
+#[cfg(feature = "server")]
+impl VisitPolicy {
+    /// The covenant whose members are summoned.
+    pub fn covenant(&self) -> Covenant {
+        self.covenant
+    }
+
+    /// Whether invading a host in `online_area_id`, who is in `host_covenant`, summons members.
+    pub fn applies_to(&self, online_area_id: u32, host_covenant: Option<Covenant>) -> bool {
+        self.online_area_ids.contains(&online_area_id)
+            || host_covenant
+                .iter()
+                .any(|covenant| self.host_covenants.contains(covenant))
+    }
+
+    /// The most members summoned into a host's world per invasion.
+    pub fn max_visitors(&self) -> usize {
+        self.max_visitors
+    }
+
+    fn guarding(covenant: Covenant, online_area_ids: &[u32]) -> Self {
+        Self {
+            covenant,
+            online_area_ids: online_area_ids.to_vec(),
+            host_covenants: vec![],
+            max_visitors: Self::default_max_visitors(),
+        }
+    }
+
+    fn defending(covenant: Covenant, host_covenant: Covenant) -> Self {
+        Self {
+            covenant,
+            online_area_ids: vec![],
+            host_covenants: vec![host_covenant],
+            max_visitors: Self::default_max_visitors(),
+        }
+    }
+
+    fn default_max_visitors() -> usize {
+        1
+    }
+}
+
+/// The online area IDs of Farron Keep, guarded by the Watchdogs of Farron.
+#[cfg(feature = "server")]
+pub const FARRON_KEEP_ONLINE_AREA_IDS: &[u32] = &[3_301_000];
+
+/// The online area IDs of Anor Londo, guarded by the Aldrich Faithful.
+#[cfg(feature = "server")]
+pub const ANOR_LONDO_ONLINE_AREA_IDS: &[u32] = &[3_701_000];
+
+/// Covenants that summon their members into other players' worlds. By default Blue Sentinels
+/// and Blades of the Darkmoon defend members of the Way of Blue, the Watchdogs of Farron guard
+/// Farron Keep and the Aldrich Faithful guard Anor Londo.
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Covenants {
+    #[serde(default = "Covenants::default_visit_policies")]
+    visit_policies: Vec<VisitPolicy>,
+}
+
+#[cfg(feature = "server")]
+impl Covenants {
+    pub fn visit_policies(&self) -> &[VisitPolicy] {
+        &self.visit_policies
+    }
+
+    fn default_visit_policies() -> Vec<VisitPolicy> {
+        vec![
+            VisitPolicy::defending(Covenant::BlueSentinels, Covenant::WayOfBlue),
+            VisitPolicy::defending(Covenant::BladeOfTheDarkmoon, Covenant::WayOfBlue),
+            VisitPolicy::guarding(Covenant::WatchdogsOfFarron, FARRON_KEEP_ONLINE_AREA_IDS),
+            VisitPolicy::guarding(Covenant::AldrichFaithful, ANOR_LONDO_ONLINE_AREA_IDS),
+        ]
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Covenants {
+    fn default() -> Self {
+        Self {
+            visit_policies: Self::default_visit_policies(),
+        }
+    }
+}
+
+/// How a player's new score on a leaderboard combines with the one they already have.
+#[cfg(feature = "server")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScorePolicy {
+    /// Keep the best score the player has registered.
+    Highest,
+    /// Add every score the player registers to their total, as with covenant offerings.
+    Total,
+    /// Keep the score the player registered most recently.
+    Latest,
+}
+
+/// A single leaderboard, identified by the ID the client asks for it with.
+#[cfg(feature = "server")]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Leaderboard {
+    id: u32,
+
+    score_policy: ScorePolicy,
+
+    #[serde(default)]
+    reset_interval_secs: Option<u64>,
+
+    #[serde(default = "Leaderboard::default_max_page_entries")]
+    max_page_entries: usize,
+
+    #[serde(default)]
+    min_score: u64,
+
+    #[serde(default = "Leaderboard::default_max_score")]
+    max_score: u64,
+}
+
+#[cfg(feature = "server")]
+impl Leaderboard {
+    pub fn new(id: u32, score_policy: ScorePolicy) -> Self {
+        Self {
+            id,
+            score_policy,
+            reset_interval_secs: None,
+            max_page_entries: Self::default_max_page_entries(),
+            min_score: 0,
+            max_score: Self::default_max_score(),
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn score_policy(&self) -> ScorePolicy {
+        self.score_policy
+    }
+
+    /// How often the leaderboard is cleared, if ever.
+    pub fn reset_interval(&self) -> Option<Duration> {
+        self.reset_interval_secs.map(Duration::from_secs)
+    }
+
+    /// The most entries returned from a single page of the leaderboard.
+    pub fn max_page_entries(&self) -> usize {
+        self.max_page_entries
+    }
+
+    /// The scores a player may register in one go. Anything outside it is rejected.
+    pub fn score_range(&self) -> std::ops::RangeInclusive<u64> {
+        self.min_score..=self.max_score
+    }
+
+    fn default_max_page_entries() -> usize {
+        20
+    }
+
+    fn default_max_score() -> u64 {
+        999_999
+    }
+}
+
+/// The leaderboards players can register scores on. By default there's one for the offerings
+/// made to each covenant, using the covenant's ID.
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Leaderboards {
+    #[serde(default = "Leaderboards::default_boards")]
+    boards: Vec<Leaderboard>,
+}
+
+#[cfg(feature = "server")]
+impl Leaderboards {
+    pub fn boards(&self) -> &[Leaderboard] {
+        &self.boards
+    }
+
+    /// The leaderboard with `id`, if there is one.
+    pub fn board(&self, id: u32) -> Option<&Leaderboard> {
+        self.boards.iter().find(|board| board.id == id)
+    }
+
+    fn default_boards() -> Vec<Leaderboard> {
+        (1..=9)
+            .filter_map(|id| Covenant::try_from(id).ok())
+            .map(|covenant| Leaderboard::new(covenant.id(), ScorePolicy::Total))
+            .collect()
     }
+}
 
-    #[cfg(feature = "server")]
-    pub fn private_key_pkcs1(&self) -> String {
-        let pem =
-            rsa::pem::parse(self.private_key.as_bytes()).expect("Private key is not valid PEM");
+#[cfg(feature = "server")]
+impl Default for Leaderboards {
+    fn default() -> Self {
+        Self {
+            boards: Self::default_boards(),
+        }
+    }
+}
 
-        RSAPrivateKey::try_from(pem)
-            .expect("Private key is not valid RSA key material")
-            .to_pem_pkcs1()
-            .expect("Private key is not encodable")
+/// The character status players' clients report while they're logged in.
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Players {
+    #[serde(default = "Players::default_max_history_entries")]
+    max_history_entries: usize,
+}
+
+#[cfg(feature = "server")]
+impl Players {
+    /// The most status changes kept for each player for moderators to look back over.
+    pub fn max_history_entries(&self) -> usize {
+        self.max_history_entries
+    }
+
+    fn default_max_history_entries() -> usize {
+        100
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Players {
+    fn default() -> Self {
+        Self {
+            max_history_entries: Self::default_max_history_entries(),
+        }
     }
 }
 
+/// Gameplay statistics gathered from the notifications clients send.
+#[cfg(feature = "server")]
 #[derive(Debug, Deserialize, Serialize)]
-pub struct Server {
-    hostname: String,
+pub struct Telemetry {
+    #[serde(default = "Telemetry::default_max_days")]
+    max_days: usize,
+}
 
-    #[cfg(feature = "server")]
-    bind_address: String,
+#[cfg(feature = "server")]
+impl Telemetry {
+    /// How many days of statistics to keep, dropping the oldest first.
+    pub fn max_days(&self) -> usize {
+        self.max_days
+    }
+
+    fn default_max_days() -> usize {
+        365
+    }
 }
 
-impl Server {
-    pub fn hostname(&self) -> &str {
-        &self.hostname
+#[cfg(feature = "server")]
+impl Default for Telemetry {
+    fn default() -> Self {
+        Self {
+            max_days: Self::default_max_days(),
+        }
     }
+}
 
-    pub fn bind_addr(&self) -> &str {
-        &self.bind_address
+/// The bandwidth measurements clients make before playing online, and what counts as a poor
+/// connection for matchmaking.
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Bandwidth {
+    #[serde(default = "Bandwidth::default_max_payload_bytes")]
+    max_payload_bytes: usize,
+
+    #[serde(default = "Bandwidth::default_probe_timeout_secs")]
+    probe_timeout_secs: u64,
+
+    #[serde(default = "Bandwidth::default_poor_connection_kbps")]
+    poor_connection_kbps: u32,
+}
+
+#[cfg(feature = "server")]
+impl Bandwidth {
+    /// The largest payload sent or accepted for a single measurement. Payloads have to fit in
+    /// one datagram.
+    pub fn max_payload_bytes(&self) -> usize {
+        self.max_payload_bytes
+    }
+
+    /// How long after a measurement the next one may arrive and still be timed against it.
+    pub fn probe_timeout(&self) -> Duration {
+        Duration::from_secs(self.probe_timeout_secs)
+    }
+
+    /// The upload or download speed below which players are offered to others last.
+    pub fn poor_connection_kbps(&self) -> u32 {
+        self.poor_connection_kbps
+    }
+
+    fn default_max_payload_bytes() -> usize {
+        16 * 1024
+    }
+
+    fn default_probe_timeout_secs() -> u64 {
+        5
+    }
+
+    fn default_poor_connection_kbps() -> u32 {
+        256
+    }
+}
+
+#[cfg(feature = "server")]
+impl Default for Bandwidth {
+    fn default() -> Self {
+        Self {
+            max_payload_bytes: Self::default_max_payload_bytes(),
+            probe_timeout_secs: Self::default_probe_timeout_secs(),
+            poor_connection_kbps: Self::default_poor_connection_kbps(),
+        }
+    }
+}
+
+/// A single piece of news shown on the login screen.
+///
+/// Dates are RFC 3339 timestamps, e.g. `2020-11-01T12:00:00Z`.
+#[cfg(feature = "server")]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Announcement {
+    header: String,
+    message: String,
+    date: DateTime<Utc>,
+
+    #[serde(default)]
+    unk01: u32,
+
+    #[serde(default)]
+    unk02: u32,
+
+    #[serde(default)]
+    unk03: u32,
+
+    #[serde(default)]
+    visible_from: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    visible_until: Option<DateTime<Utc>>,
+}
+
+#[cfg(feature = "server")]
+impl Announcement {
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The date displayed alongside the announcement.
+    pub fn date(&self) -> DateTime<Utc> {
+        self.date
+    }
+
+    pub fn unk01(&self) -> u32 {
+        self.unk01
+    }
+
+    pub fn unk02(&self) -> u32 {
+        self.unk02
+    }
+
+    pub fn unk03(&self) -> u32 {
+        self.unk03
+    }
+
+    /// Whether the announcement should be shown at `now`, according to its visibility window.
+    pub fn is_visible_at(&self, now: DateTime<Utc>) -> bool {
+        self.visible_from.iter().all(|from| *from <= now)
+            && self.visible_until.iter().all(|until| now < *until)
+    }
+}
+
+/// The contents of an announcements file.
+#[cfg(feature = "server")]
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AnnouncementList {
+    #[serde(default)]
+    announcements: Vec<Announcement>,
+}
+
+#[cfg(feature = "server")]
+impl AnnouncementList {
+    /// Load announcements from a TOML or JSON file, chosen by its extension.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let mut builder = config::Config::new();
+        builder.merge(File::from(path.as_ref()))?;
+
+        Ok(builder.try_into()?)
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, ConfigError> {
+        Self::parse(text, FileFormat::Toml)
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, ConfigError> {
+        Self::parse(text, FileFormat::Json)
+    }
+
+    fn parse(text: &str, format: FileFormat) -> Result<Self, ConfigError> {
+        let mut builder = config::Config::new();
+        builder.merge(File::from_str(text, format))?;
+
+        Ok(builder.try_into()?)
+    }
+
+    pub fn announcements(&self) -> &[Announcement] {
+        &self.announcements
+    }
+
+    pub fn into_announcements(self) -> Vec<Announcement> {
+        self.announcements
+    }
+}
+
+/// How the auth server verifies the Steam session ticket a client presents.
+#[cfg(feature = "server")]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "verifier", rename_all = "snake_case")]
+pub enum SteamVerifier {
+    /// Accept every ticket. Only suitable for LAN play.
+    AcceptAll,
+
+    /// Accept any ticket from one of the listed Steam IDs. The ticket itself isn't checked, and
+    /// the Steam ID is whatever the client claims, so anyone who knows an allowed ID can log in
+    /// as that player. Use `http` where that matters.
+    Allowlist { steam_ids: Vec<String> },
+
+    /// Ask an HTTP endpoint whether a ticket is valid.
+    Http {
+        endpoint: String,
+
+        #[serde(default = "SteamVerifier::default_timeout_secs")]
+        timeout_secs: u64,
+    },
+}
+
+#[cfg(feature = "server")]
+impl SteamVerifier {
+    fn default_verifier() -> Self {
+        Self::AcceptAll
+    }
+
+    fn default_timeout_secs() -> u64 {
+        10
     }
 }
 
@@ -90,6 +1434,82 @@ impl Server {
 pub struct Config {
     security: Security,
     server: Server,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    logging: Logging,
+
+    #[cfg(feature = "server")]
+    #[serde(default = "SteamVerifier::default_verifier")]
+    steam: SteamVerifier,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    sessions: Sessions,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    admin: Admin,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    storage: Storage,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    announcements: Announcements,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    blood_messages: BloodMessages,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    bloodstains: Bloodstains,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    ghosts: Ghosts,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    signs: Signs,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    invasions: Invasions,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    matchmaking: Matchmaking,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    covenants: Covenants,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    quick_matches: QuickMatches,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    ratings: Ratings,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    leaderboards: Leaderboards,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    players: Players,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    telemetry: Telemetry,
+
+    #[cfg(feature = "server")]
+    #[serde(default)]
+    bandwidth: Bandwidth,
 }
 
 pub struct ConfigBuilder {
@@ -101,13 +1521,21 @@ impl Default for ConfigBuilder {
         let mut builder = config::Config::new();
 
         // Merging env vars into the config builder should never fail.
-        builder.merge(Environment::with_prefix("DKS3")).unwrap();
-        builder.merge(Environment::with_prefix("DS3")).unwrap();
+        for prefix in ENV_PREFIXES {
+            builder.merge(environment(prefix)).unwrap();
+        }
 
         Self { builder }
     }
 }
 
+const ENV_PREFIXES: &[&str] = &["DKS3", "DS3"];
+
+/// Variables like `DKS3_SERVER__BIND_ADDRESS`, with `__` separating nested keys.
+fn environment(prefix: &str) -> Environment {
+    Environment::with_prefix(prefix).separator("__")
+}
+
 impl ConfigBuilder {
     pub fn add_text<S>(mut self, text: S) -> Result<Self, ConfigError>
     where
@@ -128,6 +1556,16 @@ impl ConfigBuilder {
         Ok(self)
     }
 
+    /// Merge the `DKS3_`/`DS3_` environment variables again, so they override anything added
+    /// before this.
+    pub fn add_env(mut self) -> Result<Self, ConfigError> {
+        for prefix in ENV_PREFIXES {
+            self.builder.merge(environment(prefix))?;
+        }
+
+        Ok(self)
+    }
+
     pub fn build(self) -> Result<Config, ConfigError> {
         Ok(self.builder.try_into()?)
     }
@@ -149,6 +1587,101 @@ impl Config {
     pub fn server(&self) -> &Server {
         &self.server
     }
+
+    #[cfg(feature = "server")]
+    pub fn logging(&self) -> &Logging {
+        &self.logging
+    }
+
+    #[cfg(feature = "server")]
+    pub fn steam(&self) -> &SteamVerifier {
+        &self.steam
+    }
+
+    #[cfg(feature = "server")]
+    pub fn sessions(&self) -> &Sessions {
+        &self.sessions
+    }
+
+    #[cfg(feature = "server")]
+    pub fn admin(&self) -> &Admin {
+        &self.admin
+    }
+
+    #[cfg(feature = "server")]
+    pub fn storage(&self) -> &Storage {
+        &self.storage
+    }
+
+    #[cfg(feature = "server")]
+    pub fn announcements(&self) -> &Announcements {
+        &self.announcements
+    }
+
+    #[cfg(feature = "server")]
+    pub fn blood_messages(&self) -> &BloodMessages {
+        &self.blood_messages
+    }
+
+    #[cfg(feature = "server")]
+    pub fn bloodstains(&self) -> &Bloodstains {
+        &self.bloodstains
+    }
+
+    #[cfg(feature = "server")]
+    pub fn ghosts(&self) -> &Ghosts {
+        &self.ghosts
+    }
+
+    #[cfg(feature = "server")]
+    pub fn signs(&self) -> &Signs {
+        &self.signs
+    }
+
+    #[cfg(feature = "server")]
+    pub fn invasions(&self) -> &Invasions {
+        &self.invasions
+    }
+
+    #[cfg(feature = "server")]
+    pub fn matchmaking(&self) -> &Matchmaking {
+        &self.matchmaking
+    }
+
+    #[cfg(feature = "server")]
+    pub fn covenants(&self) -> &Covenants {
+        &self.covenants
+    }
+
+    #[cfg(feature = "server")]
+    pub fn quick_matches(&self) -> &QuickMatches {
+        &self.quick_matches
+    }
+
+    #[cfg(feature = "server")]
+    pub fn ratings(&self) -> &Ratings {
+        &self.ratings
+    }
+
+    #[cfg(feature = "server")]
+    pub fn leaderboards(&self) -> &Leaderboards {
+        &self.leaderboards
+    }
+
+    #[cfg(feature = "server")]
+    pub fn players(&self) -> &Players {
+        &self.players
+    }
+
+    #[cfg(feature = "server")]
+    pub fn telemetry(&self) -> &Telemetry {
+        &self.telemetry
+    }
+
+    #[cfg(feature = "server")]
+    pub fn bandwidth(&self) -> &Bandwidth {
+        &self.bandwidth
+    }
 }
 
 #[test]
@@ -199,6 +1732,230 @@ n4wCGPP4LAWAdWqUJlCAegz57JoVLcrbQj2p9XBx
 
     assert_eq!("localhost", config.server().hostname());
     assert_eq!("127.0.0.1", config.server().bind_addr());
+    assert_eq!(50050, config.server().login_port());
+    assert_eq!(50000, config.server().auth_port());
+    assert_eq!(50010, config.server().game_port());
     assert!(!config.security().public_key_pkcs1().is_empty());
     assert!(!config.security().private_key_pkcs1().is_empty());
+    assert_eq!("info", config.logging().level());
+    assert!(matches!(config.steam(), SteamVerifier::AcceptAll));
+    assert_eq!(300, config.sessions().idle_timeout().as_secs());
+    assert_eq!(20, config.sessions().request_rate());
+    assert_eq!(50, config.sessions().request_burst());
+    assert_eq!(None, config.admin().port());
+    assert_eq!("127.0.0.1", config.admin().bind_addr());
+    assert_eq!(None, config.admin().token());
+    assert_eq!(None, config.storage().data_dir());
+    assert_eq!(None, config.announcements().path());
+    assert_eq!(10, config.blood_messages().max_list_entries());
+    assert_eq!(10, config.blood_messages().max_per_player());
+    assert_eq!(10, config.bloodstains().max_list_entries());
+    assert_eq!(65536, config.bloodstains().max_replay_bytes());
+    assert_eq!(604_800, config.bloodstains().max_age().as_secs());
+    assert_eq!(10, config.bloodstains().max_per_player());
+    assert_eq!(200, config.bloodstains().max_per_area());
+    assert_eq!(5, config.ghosts().max_list_entries());
+    assert_eq!(50, config.ghosts().max_per_area());
+    assert_eq!(30, config.ghosts().upload_interval().as_secs());
+    assert_eq!(10, config.signs().max_list_entries());
+    assert_eq!(30, config.signs().summon_timeout().as_secs());
+    assert_eq!(5, config.invasions().max_list_entries());
+    assert_eq!(120, config.invasions().cooldown().as_secs());
+    assert_eq!(4, config.covenants().visit_policies().len());
+    assert_eq!(10, config.quick_matches().max_list_entries());
+    assert_eq!(300, config.quick_matches().lobby_timeout().as_secs());
+    assert_eq!(1500.0, config.ratings().initial_rating());
+    assert_eq!(32.0, config.ratings().k_factor());
+    assert_eq!(400.0, config.ratings().pairing_range());
+    assert_eq!(20, config.ratings().max_ladder_entries());
+    assert_eq!(9, config.leaderboards().boards().len());
+    assert_eq!(100, config.players().max_history_entries());
+    assert_eq!(365, config.telemetry().max_days());
+    assert_eq!(16 * 1024, config.bandwidth().max_payload_bytes());
+    assert_eq!(5, config.bandwidth().probe_timeout().as_secs());
+    assert_eq!(256, config.bandwidth().poor_connection_kbps());
+}
+
+#[test]
+pub fn test_env_overrides_file() {
+    std::env::set_var("DKS3_SERVER__HOSTNAME", "from-env");
+
+    let config = ConfigBuilder::default()
+        .add_text(
+            r#"
+[server]
+hostname = "from-file"
+bind_address = "127.0.0.1"
+
+[security]
+public_key = ""
+private_key = ""
+"#,
+        )
+        .and_then(|c| c.add_env())
+        .and_then(|c| c.build());
+    std::env::remove_var("DKS3_SERVER__HOSTNAME");
+
+    assert_eq!("from-env", config.unwrap().server().hostname());
+}
+
+#[test]
+pub fn test_read_steam_verifier() {
+    let config: SteamVerifier = toml::from_str(
+        r#"
+verifier = "allowlist"
+steam_ids = ["0110000100000001"]
+"#,
+    )
+    .unwrap();
+
+    match config {
+        SteamVerifier::Allowlist { steam_ids } => assert_eq!(vec!["0110000100000001"], steam_ids),
+        other => panic!("unexpected verifier {:?}", other),
+    }
+}
+
+#[test]
+pub fn test_read_matchmaking() {
+    let config: Matchmaking = toml::from_str(
+        r#"
+[[weapon_levels]]
+lowest = 0
+highest = 10
+
+[coop]
+soul_levels = { below = 50, below_percent = 0, above = 50, above_percent = 0 }
+check_weapon_level = false
+password_bypass = false
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(WeaponLevelRange::new(0, 10), config.weapon_levels(0));
+    assert_eq!(WeaponLevelRange::new(0, 10), config.weapon_levels(10));
+    assert_eq!((50, 150), config.coop().soul_levels().range(100));
+    assert!(!config.coop().check_weapon_level());
+    assert!(!config.coop().password_bypass());
+    assert_eq!(&MatchingRules::default_invasion(), config.invasion());
+
+    let defaults = Matchmaking::default();
+    assert_eq!(WeaponLevelRange::new(0, 1), defaults.weapon_levels(0));
+    assert_eq!(WeaponLevelRange::new(7, 10), defaults.weapon_levels(15));
+    assert_eq!((80, 120), defaults.coop().soul_levels().range(100));
+    assert_eq!((90, 130), defaults.invasion().soul_levels().range(100));
+    assert_eq!(defaults.coop(), defaults.arena());
+}
+
+#[test]
+pub fn test_read_covenants() {
+    let config: Covenants = toml::from_str(
+        r#"
+[[visit_policies]]
+covenant = "watchdogs_of_farron"
+online_area_ids = [300000]
+max_visitors = 2
+"#,
+    )
+    .unwrap();
+
+    let policy = &config.visit_policies()[0];
+    assert_eq!(1, config.visit_policies().len());
+    assert_eq!(Covenant::WatchdogsOfFarron, policy.covenant());
+    assert_eq!(2, policy.max_visitors());
+    assert!(policy.applies_to(300_000, None));
+    assert!(!policy.applies_to(300_001, Some(Covenant::WayOfBlue)));
+
+    let defaults = Covenants::default();
+    let summoned = |online_area_id, host_covenant| -> Vec<Covenant> {
+        defaults
+            .visit_policies()
+            .iter()
+            .filter(|policy| policy.applies_to(online_area_id, host_covenant))
+            .map(|policy| policy.covenant())
+            .collect()
+    };
+    assert_eq!(
+        vec![Covenant::BlueSentinels, Covenant::BladeOfTheDarkmoon],
+        summoned(300_001, Some(Covenant::WayOfBlue))
+    );
+    assert_eq!(
+        vec![Covenant::WatchdogsOfFarron],
+        summoned(FARRON_KEEP_ONLINE_AREA_IDS[0], None)
+    );
+    assert_eq!(
+        vec![Covenant::AldrichFaithful],
+        summoned(ANOR_LONDO_ONLINE_AREA_IDS[0], None)
+    );
+    assert!(summoned(300_001, None).is_empty());
+    assert_eq!(Ok(Covenant::WayOfBlue), Covenant::try_from(8));
+    assert_eq!(Err(0), Covenant::try_from(0));
+}
+
+#[test]
+pub fn test_read_leaderboards() {
+    let config: Leaderboards = toml::from_str(
+        r#"
+[[boards]]
+id = 100
+score_policy = "highest"
+reset_interval_secs = 604800
+max_page_entries = 50
+min_score = 1
+max_score = 100
+"#,
+    )
+    .unwrap();
+
+    let board = config.board(100).unwrap();
+    assert_eq!(ScorePolicy::Highest, board.score_policy());
+    assert_eq!(Some(Duration::from_secs(604_800)), board.reset_interval());
+    assert_eq!(50, board.max_page_entries());
+    assert_eq!(1..=100, board.score_range());
+    assert_eq!(None, config.board(Covenant::WayOfBlue.id()));
+
+    let defaults = Leaderboards::default();
+    let board = defaults.board(Covenant::WayOfBlue.id()).unwrap();
+    assert_eq!(ScorePolicy::Total, board.score_policy());
+    assert_eq!(None, board.reset_interval());
+    assert_eq!(0..=999_999, board.score_range());
+}
+
+#[test]
+pub fn test_read_announcements() {
+    let toml = AnnouncementList::from_toml(
+        r#"
+[[announcements]]
+header = "Maintenance"
+message = "The server will be down on Sunday."
+date = 2020-11-01T12:00:00Z
+unk01 = 1
+visible_until = "2020-11-08T00:00:00Z"
+"#,
+    )
+    .unwrap();
+
+    let json = AnnouncementList::from_json(
+        r#"{
+  "announcements": [{
+    "header": "Maintenance",
+    "message": "The server will be down on Sunday.",
+    "date": "2020-11-01T12:00:00Z",
+    "unk01": 1,
+    "visible_until": "2020-11-08T00:00:00Z"
+  }]
+}"#,
+    )
+    .unwrap();
+
+    assert_eq!(toml.announcements(), json.announcements());
+
+    let announcement = &toml.announcements()[0];
+    assert_eq!("Maintenance", announcement.header());
+    assert_eq!(1, announcement.unk01());
+    assert_eq!(0, announcement.unk02());
+
+    let date = "2020-11-01T12:00:00Z".parse().unwrap();
+    assert_eq!(date, announcement.date());
+    assert!(announcement.is_visible_at(date));
+    assert!(!announcement.is_visible_at("2020-11-08T00:00:00Z".parse().unwrap()));
 }
@@ -25,6 +25,7 @@ const CONNECT_INFO_KEY: [u8; 16] = hex!("4B694CD6 96ADA235 EC91D9D4 23F562E5");
 fn main() -> Result<(), Box<dyn Error>> {
     let config = ConfigBuilder::default()
         .add_file("server/config.toml")
+        .and_then(|c| c.add_env())
         .and_then(|c| c.build())?;
 
     let dks3_exe_path: PathBuf = std::env::args()
@@ -294,28 +294,48 @@ impl ::protobuf::reflect::ProtobufValue for GetServiceStatus {
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct RequestQueryLoginServerInfo {
+pub struct GetServiceStatusResponse {
     // message fields
+    f1: ::std::option::Option<i32>,
     steam_id: ::protobuf::SingularField<::std::string::String>,
-    f2: ::protobuf::SingularField<::std::string::String>,
-    f3: ::std::option::Option<u64>,
+    f3: ::std::option::Option<u32>,
+    f4: ::std::option::Option<u64>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RequestQueryLoginServerInfo {
-    fn default() -> &'a RequestQueryLoginServerInfo {
-        <RequestQueryLoginServerInfo as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GetServiceStatusResponse {
+    fn default() -> &'a GetServiceStatusResponse {
+        <GetServiceStatusResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RequestQueryLoginServerInfo {
-    pub fn new() -> RequestQueryLoginServerInfo {
+impl GetServiceStatusResponse {
+    pub fn new() -> GetServiceStatusResponse {
         ::std::default::Default::default()
     }
 
-    // required string steam_id = 1;
+    // required int32 f1 = 1;
+
+
+    pub fn get_f1(&self) -> i32 {
+        self.f1.unwrap_or(0)
+    }
+    pub fn clear_f1(&mut self) {
+        self.f1 = ::std::option::Option::None;
+    }
+
+    pub fn has_f1(&self) -> bool {
+        self.f1.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_f1(&mut self, v: i32) {
+        self.f1 = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
 
 
     pub fn get_steam_id(&self) -> &str {
@@ -351,70 +371,59 @@ impl RequestQueryLoginServerInfo {
         self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    // optional string f2 = 2;
+    // required uint32 f3 = 3;
 
 
-    pub fn get_f2(&self) -> &str {
-        match self.f2.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_f3(&self) -> u32 {
+        self.f3.unwrap_or(0)
     }
-    pub fn clear_f2(&mut self) {
-        self.f2.clear();
+    pub fn clear_f3(&mut self) {
+        self.f3 = ::std::option::Option::None;
     }
 
-    pub fn has_f2(&self) -> bool {
-        self.f2.is_some()
+    pub fn has_f3(&self) -> bool {
+        self.f3.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_f2(&mut self, v: ::std::string::String) {
-        self.f2 = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_f2(&mut self) -> &mut ::std::string::String {
-        if self.f2.is_none() {
-            self.f2.set_default();
-        }
-        self.f2.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_f2(&mut self) -> ::std::string::String {
-        self.f2.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_f3(&mut self, v: u32) {
+        self.f3 = ::std::option::Option::Some(v);
     }
 
-    // required uint64 f3 = 3;
+    // required uint64 f4 = 4;
 
 
-    pub fn get_f3(&self) -> u64 {
-        self.f3.unwrap_or(0)
+    pub fn get_f4(&self) -> u64 {
+        self.f4.unwrap_or(0)
     }
-    pub fn clear_f3(&mut self) {
-        self.f3 = ::std::option::Option::None;
+    pub fn clear_f4(&mut self) {
+        self.f4 = ::std::option::Option::None;
     }
 
-    pub fn has_f3(&self) -> bool {
-        self.f3.is_some()
+    pub fn has_f4(&self) -> bool {
+        self.f4.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_f3(&mut self, v: u64) {
-        self.f3 = ::std::option::Option::Some(v);
+    pub fn set_f4(&mut self, v: u64) {
+        self.f4 = ::std::option::Option::Some(v);
     }
 }
 
-impl ::protobuf::Message for RequestQueryLoginServerInfo {
+impl ::protobuf::Message for GetServiceStatusResponse {
     fn is_initialized(&self) -> bool {
+        if self.f1.is_none() {
+            return false;
+        }
         if self.steam_id.is_none() {
             return false;
         }
         if self.f3.is_none() {
             return false;
         }
+        if self.f4.is_none() {
+            return false;
+        }
         true
     }
 
@@ -423,18 +432,29 @@ impl ::protobuf::Message for RequestQueryLoginServerInfo {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.f1 = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.f2)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
+                    let tmp = is.read_uint32()?;
                     self.f3 = ::std::option::Option::Some(tmp);
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.f4 = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -447,29 +467,35 @@ impl ::protobuf::Message for RequestQueryLoginServerInfo {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.steam_id.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
+        if let Some(v) = self.f1 {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.f2.as_ref() {
+        if let Some(ref v) = self.steam_id.as_ref() {
             my_size += ::protobuf::rt::string_size(2, &v);
         }
         if let Some(v) = self.f3 {
             my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(v) = self.f4 {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.steam_id.as_ref() {
-            os.write_string(1, &v)?;
+        if let Some(v) = self.f1 {
+            os.write_int32(1, v)?;
         }
-        if let Some(ref v) = self.f2.as_ref() {
+        if let Some(ref v) = self.steam_id.as_ref() {
             os.write_string(2, &v)?;
         }
         if let Some(v) = self.f3 {
-            os.write_uint64(3, v)?;
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.f4 {
+            os.write_uint64(4, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -501,95 +527,94 @@ impl ::protobuf::Message for RequestQueryLoginServerInfo {
         Self::descriptor_static()
     }
 
-    fn new() -> RequestQueryLoginServerInfo {
-        RequestQueryLoginServerInfo::new()
+    fn new() -> GetServiceStatusResponse {
+        GetServiceStatusResponse::new()
     }
 
-    fn default_instance() -> &'static RequestQueryLoginServerInfo {
-        static instance: ::protobuf::rt::LazyV2<RequestQueryLoginServerInfo> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(RequestQueryLoginServerInfo::new)
+    fn default_instance() -> &'static GetServiceStatusResponse {
+        static instance: ::protobuf::rt::LazyV2<GetServiceStatusResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetServiceStatusResponse::new)
     }
 }
 
-impl ::protobuf::Clear for RequestQueryLoginServerInfo {
+impl ::protobuf::Clear for GetServiceStatusResponse {
     fn clear(&mut self) {
+        self.f1 = ::std::option::Option::None;
         self.steam_id.clear();
-        self.f2.clear();
         self.f3 = ::std::option::Option::None;
+        self.f4 = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RequestQueryLoginServerInfo {
+impl ::protobuf::reflect::ProtobufValue for GetServiceStatusResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct RequestGetAnnounceMessageListResponse {
+pub struct RequestHandshake {
     // message fields
-    pub data_list: ::protobuf::SingularPtrField<AnnounceMessageDataList>,
+    aes_cwc_key: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RequestGetAnnounceMessageListResponse {
-    fn default() -> &'a RequestGetAnnounceMessageListResponse {
-        <RequestGetAnnounceMessageListResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RequestHandshake {
+    fn default() -> &'a RequestHandshake {
+        <RequestHandshake as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RequestGetAnnounceMessageListResponse {
-    pub fn new() -> RequestGetAnnounceMessageListResponse {
+impl RequestHandshake {
+    pub fn new() -> RequestHandshake {
         ::std::default::Default::default()
     }
 
-    // required .AnnounceMessageDataList data_list = 1;
+    // required bytes aes_cwc_key = 1;
 
 
-    pub fn get_data_list(&self) -> &AnnounceMessageDataList {
-        self.data_list.as_ref().unwrap_or_else(|| <AnnounceMessageDataList as ::protobuf::Message>::default_instance())
+    pub fn get_aes_cwc_key(&self) -> &[u8] {
+        match self.aes_cwc_key.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
     }
-    pub fn clear_data_list(&mut self) {
-        self.data_list.clear();
+    pub fn clear_aes_cwc_key(&mut self) {
+        self.aes_cwc_key.clear();
     }
 
-    pub fn has_data_list(&self) -> bool {
-        self.data_list.is_some()
+    pub fn has_aes_cwc_key(&self) -> bool {
+        self.aes_cwc_key.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_data_list(&mut self, v: AnnounceMessageDataList) {
-        self.data_list = ::protobuf::SingularPtrField::some(v);
+    pub fn set_aes_cwc_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.aes_cwc_key = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_data_list(&mut self) -> &mut AnnounceMessageDataList {
-        if self.data_list.is_none() {
-            self.data_list.set_default();
+    pub fn mut_aes_cwc_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.aes_cwc_key.is_none() {
+            self.aes_cwc_key.set_default();
         }
-        self.data_list.as_mut().unwrap()
+        self.aes_cwc_key.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_data_list(&mut self) -> AnnounceMessageDataList {
-        self.data_list.take().unwrap_or_else(|| AnnounceMessageDataList::new())
+    pub fn take_aes_cwc_key(&mut self) -> ::std::vec::Vec<u8> {
+        self.aes_cwc_key.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for RequestGetAnnounceMessageListResponse {
+impl ::protobuf::Message for RequestHandshake {
     fn is_initialized(&self) -> bool {
-        if self.data_list.is_none() {
+        if self.aes_cwc_key.is_none() {
             return false;
         }
-        for v in &self.data_list {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -598,7 +623,7 @@ impl ::protobuf::Message for RequestGetAnnounceMessageListResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.data_list)?;
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.aes_cwc_key)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -612,9 +637,8 @@ impl ::protobuf::Message for RequestGetAnnounceMessageListResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.data_list.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if let Some(ref v) = self.aes_cwc_key.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -622,10 +646,8 @@ impl ::protobuf::Message for RequestGetAnnounceMessageListResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.data_list.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if let Some(ref v) = self.aes_cwc_key.as_ref() {
+            os.write_bytes(1, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -657,82 +679,50 @@ impl ::protobuf::Message for RequestGetAnnounceMessageListResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> RequestGetAnnounceMessageListResponse {
-        RequestGetAnnounceMessageListResponse::new()
+    fn new() -> RequestHandshake {
+        RequestHandshake::new()
     }
 
-    fn default_instance() -> &'static RequestGetAnnounceMessageListResponse {
-        static instance: ::protobuf::rt::LazyV2<RequestGetAnnounceMessageListResponse> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(RequestGetAnnounceMessageListResponse::new)
+    fn default_instance() -> &'static RequestHandshake {
+        static instance: ::protobuf::rt::LazyV2<RequestHandshake> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestHandshake::new)
     }
 }
 
-impl ::protobuf::Clear for RequestGetAnnounceMessageListResponse {
+impl ::protobuf::Clear for RequestHandshake {
     fn clear(&mut self) {
-        self.data_list.clear();
+        self.aes_cwc_key.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RequestGetAnnounceMessageListResponse {
+impl ::protobuf::reflect::ProtobufValue for RequestHandshake {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AnnounceMessageDataList {
-    // message fields
-    pub items: ::protobuf::RepeatedField<AnnounceMessageData>,
+pub struct RequestHandshakeResponse {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a AnnounceMessageDataList {
-    fn default() -> &'a AnnounceMessageDataList {
-        <AnnounceMessageDataList as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RequestHandshakeResponse {
+    fn default() -> &'a RequestHandshakeResponse {
+        <RequestHandshakeResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AnnounceMessageDataList {
-    pub fn new() -> AnnounceMessageDataList {
+impl RequestHandshakeResponse {
+    pub fn new() -> RequestHandshakeResponse {
         ::std::default::Default::default()
     }
-
-    // repeated .AnnounceMessageData items = 1;
-
-
-    pub fn get_items(&self) -> &[AnnounceMessageData] {
-        &self.items
-    }
-    pub fn clear_items(&mut self) {
-        self.items.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<AnnounceMessageData>) {
-        self.items = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<AnnounceMessageData> {
-        &mut self.items
-    }
-
-    // Take field
-    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<AnnounceMessageData> {
-        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
-    }
 }
 
-impl ::protobuf::Message for AnnounceMessageDataList {
+impl ::protobuf::Message for RequestHandshakeResponse {
     fn is_initialized(&self) -> bool {
-        for v in &self.items {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -740,9 +730,6 @@ impl ::protobuf::Message for AnnounceMessageDataList {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -755,21 +742,12 @@ impl ::protobuf::Message for AnnounceMessageDataList {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.items {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.items {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -800,112 +778,20636 @@ impl ::protobuf::Message for AnnounceMessageDataList {
         Self::descriptor_static()
     }
 
-    fn new() -> AnnounceMessageDataList {
-        AnnounceMessageDataList::new()
+    fn new() -> RequestHandshakeResponse {
+        RequestHandshakeResponse::new()
     }
 
-    fn default_instance() -> &'static AnnounceMessageDataList {
-        static instance: ::protobuf::rt::LazyV2<AnnounceMessageDataList> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(AnnounceMessageDataList::new)
+    fn default_instance() -> &'static RequestHandshakeResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestHandshakeResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestHandshakeResponse::new)
     }
 }
 
-impl ::protobuf::Clear for AnnounceMessageDataList {
+impl ::protobuf::Clear for RequestHandshakeResponse {
     fn clear(&mut self) {
-        self.items.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AnnounceMessageDataList {
+impl ::protobuf::reflect::ProtobufValue for RequestHandshakeResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AnnounceMessageData {
+pub struct KeyMaterial {
     // message fields
-    unk01: ::std::option::Option<u32>,
-    unk02: ::std::option::Option<u32>,
-    unk03: ::std::option::Option<u32>,
-    header: ::protobuf::SingularField<::std::string::String>,
-    message: ::protobuf::SingularField<::std::string::String>,
-    pub date_time: ::protobuf::SingularPtrField<super::common::DateTime>,
+    key_material: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a AnnounceMessageData {
-    fn default() -> &'a AnnounceMessageData {
-        <AnnounceMessageData as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a KeyMaterial {
+    fn default() -> &'a KeyMaterial {
+        <KeyMaterial as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AnnounceMessageData {
-    pub fn new() -> AnnounceMessageData {
+impl KeyMaterial {
+    pub fn new() -> KeyMaterial {
         ::std::default::Default::default()
     }
 
-    // required uint32 unk01 = 1;
+    // required bytes key_material = 1;
 
 
-    pub fn get_unk01(&self) -> u32 {
-        self.unk01.unwrap_or(0)
-    }
+    pub fn get_key_material(&self) -> &[u8] {
+        match self.key_material.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_key_material(&mut self) {
+        self.key_material.clear();
+    }
+
+    pub fn has_key_material(&self) -> bool {
+        self.key_material.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_material(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key_material = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_material(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.key_material.is_none() {
+            self.key_material.set_default();
+        }
+        self.key_material.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_material(&mut self) -> ::std::vec::Vec<u8> {
+        self.key_material.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for KeyMaterial {
+    fn is_initialized(&self) -> bool {
+        if self.key_material.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.key_material)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.key_material.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.key_material.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KeyMaterial {
+        KeyMaterial::new()
+    }
+
+    fn default_instance() -> &'static KeyMaterial {
+        static instance: ::protobuf::rt::LazyV2<KeyMaterial> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(KeyMaterial::new)
+    }
+}
+
+impl ::protobuf::Clear for KeyMaterial {
+    fn clear(&mut self) {
+        self.key_material.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyMaterial {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SteamTicket {
+    // message fields
+    ticket: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SteamTicket {
+    fn default() -> &'a SteamTicket {
+        <SteamTicket as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SteamTicket {
+    pub fn new() -> SteamTicket {
+        ::std::default::Default::default()
+    }
+
+    // required bytes ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &[u8] {
+        match self.ticket.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    pub fn has_ticket(&self) -> bool {
+        self.ticket.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ticket = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.ticket.is_none() {
+            self.ticket.set_default();
+        }
+        self.ticket.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::vec::Vec<u8> {
+        self.ticket.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for SteamTicket {
+    fn is_initialized(&self) -> bool {
+        if self.ticket.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ticket)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.ticket.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.ticket.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SteamTicket {
+        SteamTicket::new()
+    }
+
+    fn default_instance() -> &'static SteamTicket {
+        static instance: ::protobuf::rt::LazyV2<SteamTicket> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SteamTicket::new)
+    }
+}
+
+impl ::protobuf::Clear for SteamTicket {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SteamTicket {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GameServerInfo {
+    // message fields
+    auth_token: ::std::option::Option<u64>,
+    server_ip: ::protobuf::SingularField<::std::string::String>,
+    port: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameServerInfo {
+    fn default() -> &'a GameServerInfo {
+        <GameServerInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameServerInfo {
+    pub fn new() -> GameServerInfo {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 auth_token = 1;
+
+
+    pub fn get_auth_token(&self) -> u64 {
+        self.auth_token.unwrap_or(0)
+    }
+    pub fn clear_auth_token(&mut self) {
+        self.auth_token = ::std::option::Option::None;
+    }
+
+    pub fn has_auth_token(&self) -> bool {
+        self.auth_token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_auth_token(&mut self, v: u64) {
+        self.auth_token = ::std::option::Option::Some(v);
+    }
+
+    // required string server_ip = 2;
+
+
+    pub fn get_server_ip(&self) -> &str {
+        match self.server_ip.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_server_ip(&mut self) {
+        self.server_ip.clear();
+    }
+
+    pub fn has_server_ip(&self) -> bool {
+        self.server_ip.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_server_ip(&mut self, v: ::std::string::String) {
+        self.server_ip = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_server_ip(&mut self) -> &mut ::std::string::String {
+        if self.server_ip.is_none() {
+            self.server_ip.set_default();
+        }
+        self.server_ip.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_server_ip(&mut self) -> ::std::string::String {
+        self.server_ip.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 port = 3;
+
+
+    pub fn get_port(&self) -> u32 {
+        self.port.unwrap_or(0)
+    }
+    pub fn clear_port(&mut self) {
+        self.port = ::std::option::Option::None;
+    }
+
+    pub fn has_port(&self) -> bool {
+        self.port.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_port(&mut self, v: u32) {
+        self.port = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for GameServerInfo {
+    fn is_initialized(&self) -> bool {
+        if self.auth_token.is_none() {
+            return false;
+        }
+        if self.server_ip.is_none() {
+            return false;
+        }
+        if self.port.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.auth_token = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.server_ip)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.port = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.auth_token {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.server_ip.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.port {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.auth_token {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.server_ip.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.port {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameServerInfo {
+        GameServerInfo::new()
+    }
+
+    fn default_instance() -> &'static GameServerInfo {
+        static instance: ::protobuf::rt::LazyV2<GameServerInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameServerInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for GameServerInfo {
+    fn clear(&mut self) {
+        self.auth_token = ::std::option::Option::None;
+        self.server_ip.clear();
+        self.port = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameServerInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestQueryLoginServerInfo {
+    // message fields
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    f2: ::protobuf::SingularField<::std::string::String>,
+    f3: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestQueryLoginServerInfo {
+    fn default() -> &'a RequestQueryLoginServerInfo {
+        <RequestQueryLoginServerInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestQueryLoginServerInfo {
+    pub fn new() -> RequestQueryLoginServerInfo {
+        ::std::default::Default::default()
+    }
+
+    // required string steam_id = 1;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string f2 = 2;
+
+
+    pub fn get_f2(&self) -> &str {
+        match self.f2.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_f2(&mut self) {
+        self.f2.clear();
+    }
+
+    pub fn has_f2(&self) -> bool {
+        self.f2.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_f2(&mut self, v: ::std::string::String) {
+        self.f2 = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_f2(&mut self) -> &mut ::std::string::String {
+        if self.f2.is_none() {
+            self.f2.set_default();
+        }
+        self.f2.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_f2(&mut self) -> ::std::string::String {
+        self.f2.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint64 f3 = 3;
+
+
+    pub fn get_f3(&self) -> u64 {
+        self.f3.unwrap_or(0)
+    }
+    pub fn clear_f3(&mut self) {
+        self.f3 = ::std::option::Option::None;
+    }
+
+    pub fn has_f3(&self) -> bool {
+        self.f3.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_f3(&mut self, v: u64) {
+        self.f3 = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestQueryLoginServerInfo {
+    fn is_initialized(&self) -> bool {
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.f3.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.f2)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.f3 = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.f2.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.f3 {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.f2.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.f3 {
+            os.write_uint64(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestQueryLoginServerInfo {
+        RequestQueryLoginServerInfo::new()
+    }
+
+    fn default_instance() -> &'static RequestQueryLoginServerInfo {
+        static instance: ::protobuf::rt::LazyV2<RequestQueryLoginServerInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestQueryLoginServerInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestQueryLoginServerInfo {
+    fn clear(&mut self) {
+        self.steam_id.clear();
+        self.f2.clear();
+        self.f3 = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestQueryLoginServerInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestQueryLoginServerInfoResponse {
+    // message fields
+    server_ip: ::protobuf::SingularField<::std::string::String>,
+    port: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestQueryLoginServerInfoResponse {
+    fn default() -> &'a RequestQueryLoginServerInfoResponse {
+        <RequestQueryLoginServerInfoResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestQueryLoginServerInfoResponse {
+    pub fn new() -> RequestQueryLoginServerInfoResponse {
+        ::std::default::Default::default()
+    }
+
+    // required string server_ip = 1;
+
+
+    pub fn get_server_ip(&self) -> &str {
+        match self.server_ip.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_server_ip(&mut self) {
+        self.server_ip.clear();
+    }
+
+    pub fn has_server_ip(&self) -> bool {
+        self.server_ip.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_server_ip(&mut self, v: ::std::string::String) {
+        self.server_ip = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_server_ip(&mut self) -> &mut ::std::string::String {
+        if self.server_ip.is_none() {
+            self.server_ip.set_default();
+        }
+        self.server_ip.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_server_ip(&mut self) -> ::std::string::String {
+        self.server_ip.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint64 port = 2;
+
+
+    pub fn get_port(&self) -> u64 {
+        self.port.unwrap_or(0)
+    }
+    pub fn clear_port(&mut self) {
+        self.port = ::std::option::Option::None;
+    }
+
+    pub fn has_port(&self) -> bool {
+        self.port.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_port(&mut self, v: u64) {
+        self.port = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestQueryLoginServerInfoResponse {
+    fn is_initialized(&self) -> bool {
+        if self.server_ip.is_none() {
+            return false;
+        }
+        if self.port.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.server_ip)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.port = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.server_ip.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.port {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.server_ip.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.port {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestQueryLoginServerInfoResponse {
+        RequestQueryLoginServerInfoResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestQueryLoginServerInfoResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestQueryLoginServerInfoResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestQueryLoginServerInfoResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestQueryLoginServerInfoResponse {
+    fn clear(&mut self) {
+        self.server_ip.clear();
+        self.port = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestQueryLoginServerInfoResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetAnnounceMessageList {
+    // message fields
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetAnnounceMessageList {
+    fn default() -> &'a RequestGetAnnounceMessageList {
+        <RequestGetAnnounceMessageList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetAnnounceMessageList {
+    pub fn new() -> RequestGetAnnounceMessageList {
+        ::std::default::Default::default()
+    }
+
+    // optional uint32 max_entries = 1;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetAnnounceMessageList {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.max_entries {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetAnnounceMessageList {
+        RequestGetAnnounceMessageList::new()
+    }
+
+    fn default_instance() -> &'static RequestGetAnnounceMessageList {
+        static instance: ::protobuf::rt::LazyV2<RequestGetAnnounceMessageList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetAnnounceMessageList::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetAnnounceMessageList {
+    fn clear(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetAnnounceMessageList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetAnnounceMessageListResponse {
+    // message fields
+    pub data_list: ::protobuf::SingularPtrField<AnnounceMessageDataList>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetAnnounceMessageListResponse {
+    fn default() -> &'a RequestGetAnnounceMessageListResponse {
+        <RequestGetAnnounceMessageListResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetAnnounceMessageListResponse {
+    pub fn new() -> RequestGetAnnounceMessageListResponse {
+        ::std::default::Default::default()
+    }
+
+    // required .AnnounceMessageDataList data_list = 1;
+
+
+    pub fn get_data_list(&self) -> &AnnounceMessageDataList {
+        self.data_list.as_ref().unwrap_or_else(|| <AnnounceMessageDataList as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_data_list(&mut self) {
+        self.data_list.clear();
+    }
+
+    pub fn has_data_list(&self) -> bool {
+        self.data_list.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_list(&mut self, v: AnnounceMessageDataList) {
+        self.data_list = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data_list(&mut self) -> &mut AnnounceMessageDataList {
+        if self.data_list.is_none() {
+            self.data_list.set_default();
+        }
+        self.data_list.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_data_list(&mut self) -> AnnounceMessageDataList {
+        self.data_list.take().unwrap_or_else(|| AnnounceMessageDataList::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetAnnounceMessageListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.data_list.is_none() {
+            return false;
+        }
+        for v in &self.data_list {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.data_list)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.data_list.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.data_list.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetAnnounceMessageListResponse {
+        RequestGetAnnounceMessageListResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetAnnounceMessageListResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetAnnounceMessageListResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetAnnounceMessageListResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetAnnounceMessageListResponse {
+    fn clear(&mut self) {
+        self.data_list.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetAnnounceMessageListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AnnounceMessageDataList {
+    // message fields
+    pub items: ::protobuf::RepeatedField<AnnounceMessageData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AnnounceMessageDataList {
+    fn default() -> &'a AnnounceMessageDataList {
+        <AnnounceMessageDataList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AnnounceMessageDataList {
+    pub fn new() -> AnnounceMessageDataList {
+        ::std::default::Default::default()
+    }
+
+    // repeated .AnnounceMessageData items = 1;
+
+
+    pub fn get_items(&self) -> &[AnnounceMessageData] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<AnnounceMessageData>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<AnnounceMessageData> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<AnnounceMessageData> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for AnnounceMessageDataList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AnnounceMessageDataList {
+        AnnounceMessageDataList::new()
+    }
+
+    fn default_instance() -> &'static AnnounceMessageDataList {
+        static instance: ::protobuf::rt::LazyV2<AnnounceMessageDataList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AnnounceMessageDataList::new)
+    }
+}
+
+impl ::protobuf::Clear for AnnounceMessageDataList {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AnnounceMessageDataList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AnnounceMessageData {
+    // message fields
+    unk01: ::std::option::Option<u32>,
+    unk02: ::std::option::Option<u32>,
+    unk03: ::std::option::Option<u32>,
+    header: ::protobuf::SingularField<::std::string::String>,
+    message: ::protobuf::SingularField<::std::string::String>,
+    pub date_time: ::protobuf::SingularPtrField<super::common::DateTime>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AnnounceMessageData {
+    fn default() -> &'a AnnounceMessageData {
+        <AnnounceMessageData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AnnounceMessageData {
+    pub fn new() -> AnnounceMessageData {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 unk01 = 1;
+
+
+    pub fn get_unk01(&self) -> u32 {
+        self.unk01.unwrap_or(0)
+    }
     pub fn clear_unk01(&mut self) {
         self.unk01 = ::std::option::Option::None;
     }
 
-    pub fn has_unk01(&self) -> bool {
-        self.unk01.is_some()
+    pub fn has_unk01(&self) -> bool {
+        self.unk01.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unk01(&mut self, v: u32) {
+        self.unk01 = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 unk02 = 2;
+
+
+    pub fn get_unk02(&self) -> u32 {
+        self.unk02.unwrap_or(0)
+    }
+    pub fn clear_unk02(&mut self) {
+        self.unk02 = ::std::option::Option::None;
+    }
+
+    pub fn has_unk02(&self) -> bool {
+        self.unk02.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unk02(&mut self, v: u32) {
+        self.unk02 = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 unk03 = 3;
+
+
+    pub fn get_unk03(&self) -> u32 {
+        self.unk03.unwrap_or(0)
+    }
+    pub fn clear_unk03(&mut self) {
+        self.unk03 = ::std::option::Option::None;
+    }
+
+    pub fn has_unk03(&self) -> bool {
+        self.unk03.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unk03(&mut self, v: u32) {
+        self.unk03 = ::std::option::Option::Some(v);
+    }
+
+    // required string header = 4;
+
+
+    pub fn get_header(&self) -> &str {
+        match self.header.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_header(&mut self) {
+        self.header.clear();
+    }
+
+    pub fn has_header(&self) -> bool {
+        self.header.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header(&mut self, v: ::std::string::String) {
+        self.header = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header(&mut self) -> &mut ::std::string::String {
+        if self.header.is_none() {
+            self.header.set_default();
+        }
+        self.header.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header(&mut self) -> ::std::string::String {
+        self.header.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string message = 5;
+
+
+    pub fn get_message(&self) -> &str {
+        match self.message.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        if self.message.is_none() {
+            self.message.set_default();
+        }
+        self.message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        self.message.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required .DateTime date_time = 6;
+
+
+    pub fn get_date_time(&self) -> &super::common::DateTime {
+        self.date_time.as_ref().unwrap_or_else(|| <super::common::DateTime as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_date_time(&mut self) {
+        self.date_time.clear();
+    }
+
+    pub fn has_date_time(&self) -> bool {
+        self.date_time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date_time(&mut self, v: super::common::DateTime) {
+        self.date_time = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_date_time(&mut self) -> &mut super::common::DateTime {
+        if self.date_time.is_none() {
+            self.date_time.set_default();
+        }
+        self.date_time.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_date_time(&mut self) -> super::common::DateTime {
+        self.date_time.take().unwrap_or_else(|| super::common::DateTime::new())
+    }
+}
+
+impl ::protobuf::Message for AnnounceMessageData {
+    fn is_initialized(&self) -> bool {
+        if self.unk01.is_none() {
+            return false;
+        }
+        if self.unk02.is_none() {
+            return false;
+        }
+        if self.unk03.is_none() {
+            return false;
+        }
+        if self.header.is_none() {
+            return false;
+        }
+        if self.message.is_none() {
+            return false;
+        }
+        if self.date_time.is_none() {
+            return false;
+        }
+        for v in &self.date_time {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.unk01 = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.unk02 = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.unk03 = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.header)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.message)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.date_time)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.unk01 {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.unk02 {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.unk03 {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(ref v) = self.message.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(ref v) = self.date_time.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.unk01 {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.unk02 {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.unk03 {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(ref v) = self.header.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(ref v) = self.message.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        if let Some(ref v) = self.date_time.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AnnounceMessageData {
+        AnnounceMessageData::new()
+    }
+
+    fn default_instance() -> &'static AnnounceMessageData {
+        static instance: ::protobuf::rt::LazyV2<AnnounceMessageData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AnnounceMessageData::new)
+    }
+}
+
+impl ::protobuf::Clear for AnnounceMessageData {
+    fn clear(&mut self) {
+        self.unk01 = ::std::option::Option::None;
+        self.unk02 = ::std::option::Option::None;
+        self.unk03 = ::std::option::Option::None;
+        self.header.clear();
+        self.message.clear();
+        self.date_time.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AnnounceMessageData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestErrorResponse {
+    // message fields
+    code: ::std::option::Option<u32>,
+    message_type: ::std::option::Option<u32>,
+    reason: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestErrorResponse {
+    fn default() -> &'a RequestErrorResponse {
+        <RequestErrorResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestErrorResponse {
+    pub fn new() -> RequestErrorResponse {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 code = 1;
+
+
+    pub fn get_code(&self) -> u32 {
+        self.code.unwrap_or(0)
+    }
+    pub fn clear_code(&mut self) {
+        self.code = ::std::option::Option::None;
+    }
+
+    pub fn has_code(&self) -> bool {
+        self.code.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: u32) {
+        self.code = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 message_type = 2;
+
+
+    pub fn get_message_type(&self) -> u32 {
+        self.message_type.unwrap_or(0)
+    }
+    pub fn clear_message_type(&mut self) {
+        self.message_type = ::std::option::Option::None;
+    }
+
+    pub fn has_message_type(&self) -> bool {
+        self.message_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_type(&mut self, v: u32) {
+        self.message_type = ::std::option::Option::Some(v);
+    }
+
+    // optional string reason = 3;
+
+
+    pub fn get_reason(&self) -> &str {
+        match self.reason.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    pub fn has_reason(&self) -> bool {
+        self.reason.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        if self.reason.is_none() {
+            self.reason.set_default();
+        }
+        self.reason.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        self.reason.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RequestErrorResponse {
+    fn is_initialized(&self) -> bool {
+        if self.code.is_none() {
+            return false;
+        }
+        if self.message_type.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.code = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.message_type = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.reason)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.code {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.message_type {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.reason.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.code {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.message_type {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.reason.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestErrorResponse {
+        RequestErrorResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestErrorResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestErrorResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestErrorResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestErrorResponse {
+    fn clear(&mut self) {
+        self.code = ::std::option::Option::None;
+        self.message_type = ::std::option::Option::None;
+        self.reason.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestErrorResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BloodMessageText {
+    // message fields
+    template_id: ::std::option::Option<u32>,
+    word_id: ::std::option::Option<u32>,
+    conjunction_id: ::std::option::Option<u32>,
+    second_template_id: ::std::option::Option<u32>,
+    second_word_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BloodMessageText {
+    fn default() -> &'a BloodMessageText {
+        <BloodMessageText as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BloodMessageText {
+    pub fn new() -> BloodMessageText {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 template_id = 1;
+
+
+    pub fn get_template_id(&self) -> u32 {
+        self.template_id.unwrap_or(0)
+    }
+    pub fn clear_template_id(&mut self) {
+        self.template_id = ::std::option::Option::None;
+    }
+
+    pub fn has_template_id(&self) -> bool {
+        self.template_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_template_id(&mut self, v: u32) {
+        self.template_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 word_id = 2;
+
+
+    pub fn get_word_id(&self) -> u32 {
+        self.word_id.unwrap_or(0)
+    }
+    pub fn clear_word_id(&mut self) {
+        self.word_id = ::std::option::Option::None;
+    }
+
+    pub fn has_word_id(&self) -> bool {
+        self.word_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_word_id(&mut self, v: u32) {
+        self.word_id = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 conjunction_id = 3;
+
+
+    pub fn get_conjunction_id(&self) -> u32 {
+        self.conjunction_id.unwrap_or(0)
+    }
+    pub fn clear_conjunction_id(&mut self) {
+        self.conjunction_id = ::std::option::Option::None;
+    }
+
+    pub fn has_conjunction_id(&self) -> bool {
+        self.conjunction_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conjunction_id(&mut self, v: u32) {
+        self.conjunction_id = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 second_template_id = 4;
+
+
+    pub fn get_second_template_id(&self) -> u32 {
+        self.second_template_id.unwrap_or(0)
+    }
+    pub fn clear_second_template_id(&mut self) {
+        self.second_template_id = ::std::option::Option::None;
+    }
+
+    pub fn has_second_template_id(&self) -> bool {
+        self.second_template_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_second_template_id(&mut self, v: u32) {
+        self.second_template_id = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 second_word_id = 5;
+
+
+    pub fn get_second_word_id(&self) -> u32 {
+        self.second_word_id.unwrap_or(0)
+    }
+    pub fn clear_second_word_id(&mut self) {
+        self.second_word_id = ::std::option::Option::None;
+    }
+
+    pub fn has_second_word_id(&self) -> bool {
+        self.second_word_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_second_word_id(&mut self, v: u32) {
+        self.second_word_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for BloodMessageText {
+    fn is_initialized(&self) -> bool {
+        if self.template_id.is_none() {
+            return false;
+        }
+        if self.word_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.template_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.word_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.conjunction_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.second_template_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.second_word_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.template_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.word_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.conjunction_id {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.second_template_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.second_word_id {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.template_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.word_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.conjunction_id {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.second_template_id {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.second_word_id {
+            os.write_uint32(5, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BloodMessageText {
+        BloodMessageText::new()
+    }
+
+    fn default_instance() -> &'static BloodMessageText {
+        static instance: ::protobuf::rt::LazyV2<BloodMessageText> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BloodMessageText::new)
+    }
+}
+
+impl ::protobuf::Clear for BloodMessageText {
+    fn clear(&mut self) {
+        self.template_id = ::std::option::Option::None;
+        self.word_id = ::std::option::Option::None;
+        self.conjunction_id = ::std::option::Option::None;
+        self.second_template_id = ::std::option::Option::None;
+        self.second_word_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BloodMessageText {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCreateBloodMessage {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    pub position: ::protobuf::SingularPtrField<super::common::Vector3>,
+    angle: ::std::option::Option<f32>,
+    pub text: ::protobuf::SingularPtrField<BloodMessageText>,
+    gesture_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCreateBloodMessage {
+    fn default() -> &'a RequestCreateBloodMessage {
+        <RequestCreateBloodMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCreateBloodMessage {
+    pub fn new() -> RequestCreateBloodMessage {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 2;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required .Vector3 position = 3;
+
+
+    pub fn get_position(&self) -> &super::common::Vector3 {
+        self.position.as_ref().unwrap_or_else(|| <super::common::Vector3 as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_position(&mut self) {
+        self.position.clear();
+    }
+
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: super::common::Vector3) {
+        self.position = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_position(&mut self) -> &mut super::common::Vector3 {
+        if self.position.is_none() {
+            self.position.set_default();
+        }
+        self.position.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_position(&mut self) -> super::common::Vector3 {
+        self.position.take().unwrap_or_else(|| super::common::Vector3::new())
+    }
+
+    // required float angle = 4;
+
+
+    pub fn get_angle(&self) -> f32 {
+        self.angle.unwrap_or(0.)
+    }
+    pub fn clear_angle(&mut self) {
+        self.angle = ::std::option::Option::None;
+    }
+
+    pub fn has_angle(&self) -> bool {
+        self.angle.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_angle(&mut self, v: f32) {
+        self.angle = ::std::option::Option::Some(v);
+    }
+
+    // required .BloodMessageText text = 5;
+
+
+    pub fn get_text(&self) -> &BloodMessageText {
+        self.text.as_ref().unwrap_or_else(|| <BloodMessageText as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    pub fn has_text(&self) -> bool {
+        self.text.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: BloodMessageText) {
+        self.text = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut BloodMessageText {
+        if self.text.is_none() {
+            self.text.set_default();
+        }
+        self.text.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> BloodMessageText {
+        self.text.take().unwrap_or_else(|| BloodMessageText::new())
+    }
+
+    // required uint32 gesture_id = 6;
+
+
+    pub fn get_gesture_id(&self) -> u32 {
+        self.gesture_id.unwrap_or(0)
+    }
+    pub fn clear_gesture_id(&mut self) {
+        self.gesture_id = ::std::option::Option::None;
+    }
+
+    pub fn has_gesture_id(&self) -> bool {
+        self.gesture_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gesture_id(&mut self, v: u32) {
+        self.gesture_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestCreateBloodMessage {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.position.is_none() {
+            return false;
+        }
+        if self.angle.is_none() {
+            return false;
+        }
+        if self.text.is_none() {
+            return false;
+        }
+        if self.gesture_id.is_none() {
+            return false;
+        }
+        for v in &self.position {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.text {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.position)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.angle = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.text)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.gesture_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.angle {
+            my_size += 5;
+        }
+        if let Some(ref v) = self.text.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.gesture_id {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.angle {
+            os.write_float(4, v)?;
+        }
+        if let Some(ref v) = self.text.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.gesture_id {
+            os.write_uint32(6, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCreateBloodMessage {
+        RequestCreateBloodMessage::new()
+    }
+
+    fn default_instance() -> &'static RequestCreateBloodMessage {
+        static instance: ::protobuf::rt::LazyV2<RequestCreateBloodMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCreateBloodMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCreateBloodMessage {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.position.clear();
+        self.angle = ::std::option::Option::None;
+        self.text.clear();
+        self.gesture_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCreateBloodMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCreateBloodMessageResponse {
+    // message fields
+    message_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCreateBloodMessageResponse {
+    fn default() -> &'a RequestCreateBloodMessageResponse {
+        <RequestCreateBloodMessageResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCreateBloodMessageResponse {
+    pub fn new() -> RequestCreateBloodMessageResponse {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 message_id = 1;
+
+
+    pub fn get_message_id(&self) -> u64 {
+        self.message_id.unwrap_or(0)
+    }
+    pub fn clear_message_id(&mut self) {
+        self.message_id = ::std::option::Option::None;
+    }
+
+    pub fn has_message_id(&self) -> bool {
+        self.message_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_id(&mut self, v: u64) {
+        self.message_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestCreateBloodMessageResponse {
+    fn is_initialized(&self) -> bool {
+        if self.message_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.message_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.message_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.message_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCreateBloodMessageResponse {
+        RequestCreateBloodMessageResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestCreateBloodMessageResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestCreateBloodMessageResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCreateBloodMessageResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCreateBloodMessageResponse {
+    fn clear(&mut self) {
+        self.message_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCreateBloodMessageResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetBloodMessageList {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetBloodMessageList {
+    fn default() -> &'a RequestGetBloodMessageList {
+        <RequestGetBloodMessageList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetBloodMessageList {
+    pub fn new() -> RequestGetBloodMessageList {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 max_entries = 2;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetBloodMessageList {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.max_entries {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetBloodMessageList {
+        RequestGetBloodMessageList::new()
+    }
+
+    fn default_instance() -> &'static RequestGetBloodMessageList {
+        static instance: ::protobuf::rt::LazyV2<RequestGetBloodMessageList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetBloodMessageList::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetBloodMessageList {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetBloodMessageList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BloodMessageData {
+    // message fields
+    message_id: ::std::option::Option<u64>,
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    character_name: ::protobuf::SingularField<::std::string::String>,
+    online_area_id: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    pub position: ::protobuf::SingularPtrField<super::common::Vector3>,
+    angle: ::std::option::Option<f32>,
+    pub text: ::protobuf::SingularPtrField<BloodMessageText>,
+    gesture_id: ::std::option::Option<u32>,
+    rating_good: ::std::option::Option<u32>,
+    rating_poor: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BloodMessageData {
+    fn default() -> &'a BloodMessageData {
+        <BloodMessageData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BloodMessageData {
+    pub fn new() -> BloodMessageData {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 message_id = 1;
+
+
+    pub fn get_message_id(&self) -> u64 {
+        self.message_id.unwrap_or(0)
+    }
+    pub fn clear_message_id(&mut self) {
+        self.message_id = ::std::option::Option::None;
+    }
+
+    pub fn has_message_id(&self) -> bool {
+        self.message_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_id(&mut self, v: u64) {
+        self.message_id = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string character_name = 3;
+
+
+    pub fn get_character_name(&self) -> &str {
+        match self.character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_character_name(&mut self) {
+        self.character_name.clear();
+    }
+
+    pub fn has_character_name(&self) -> bool {
+        self.character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_character_name(&mut self, v: ::std::string::String) {
+        self.character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_character_name(&mut self) -> &mut ::std::string::String {
+        if self.character_name.is_none() {
+            self.character_name.set_default();
+        }
+        self.character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_character_name(&mut self) -> ::std::string::String {
+        self.character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 online_area_id = 4;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 5;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required .Vector3 position = 6;
+
+
+    pub fn get_position(&self) -> &super::common::Vector3 {
+        self.position.as_ref().unwrap_or_else(|| <super::common::Vector3 as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_position(&mut self) {
+        self.position.clear();
+    }
+
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: super::common::Vector3) {
+        self.position = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_position(&mut self) -> &mut super::common::Vector3 {
+        if self.position.is_none() {
+            self.position.set_default();
+        }
+        self.position.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_position(&mut self) -> super::common::Vector3 {
+        self.position.take().unwrap_or_else(|| super::common::Vector3::new())
+    }
+
+    // required float angle = 7;
+
+
+    pub fn get_angle(&self) -> f32 {
+        self.angle.unwrap_or(0.)
+    }
+    pub fn clear_angle(&mut self) {
+        self.angle = ::std::option::Option::None;
+    }
+
+    pub fn has_angle(&self) -> bool {
+        self.angle.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_angle(&mut self, v: f32) {
+        self.angle = ::std::option::Option::Some(v);
+    }
+
+    // required .BloodMessageText text = 8;
+
+
+    pub fn get_text(&self) -> &BloodMessageText {
+        self.text.as_ref().unwrap_or_else(|| <BloodMessageText as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    pub fn has_text(&self) -> bool {
+        self.text.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: BloodMessageText) {
+        self.text = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut BloodMessageText {
+        if self.text.is_none() {
+            self.text.set_default();
+        }
+        self.text.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> BloodMessageText {
+        self.text.take().unwrap_or_else(|| BloodMessageText::new())
+    }
+
+    // required uint32 gesture_id = 9;
+
+
+    pub fn get_gesture_id(&self) -> u32 {
+        self.gesture_id.unwrap_or(0)
+    }
+    pub fn clear_gesture_id(&mut self) {
+        self.gesture_id = ::std::option::Option::None;
+    }
+
+    pub fn has_gesture_id(&self) -> bool {
+        self.gesture_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gesture_id(&mut self, v: u32) {
+        self.gesture_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 rating_good = 10;
+
+
+    pub fn get_rating_good(&self) -> u32 {
+        self.rating_good.unwrap_or(0)
+    }
+    pub fn clear_rating_good(&mut self) {
+        self.rating_good = ::std::option::Option::None;
+    }
+
+    pub fn has_rating_good(&self) -> bool {
+        self.rating_good.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rating_good(&mut self, v: u32) {
+        self.rating_good = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 rating_poor = 11;
+
+
+    pub fn get_rating_poor(&self) -> u32 {
+        self.rating_poor.unwrap_or(0)
+    }
+    pub fn clear_rating_poor(&mut self) {
+        self.rating_poor = ::std::option::Option::None;
+    }
+
+    pub fn has_rating_poor(&self) -> bool {
+        self.rating_poor.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rating_poor(&mut self, v: u32) {
+        self.rating_poor = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for BloodMessageData {
+    fn is_initialized(&self) -> bool {
+        if self.message_id.is_none() {
+            return false;
+        }
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.character_name.is_none() {
+            return false;
+        }
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.position.is_none() {
+            return false;
+        }
+        if self.angle.is_none() {
+            return false;
+        }
+        if self.text.is_none() {
+            return false;
+        }
+        if self.gesture_id.is_none() {
+            return false;
+        }
+        if self.rating_good.is_none() {
+            return false;
+        }
+        if self.rating_poor.is_none() {
+            return false;
+        }
+        for v in &self.position {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.text {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.message_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.character_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.position)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.angle = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.text)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.gesture_id = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rating_good = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rating_poor = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.message_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.angle {
+            my_size += 5;
+        }
+        if let Some(ref v) = self.text.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.gesture_id {
+            my_size += ::protobuf::rt::value_size(9, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.rating_good {
+            my_size += ::protobuf::rt::value_size(10, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.rating_poor {
+            my_size += ::protobuf::rt::value_size(11, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.message_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(5, v)?;
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.angle {
+            os.write_float(7, v)?;
+        }
+        if let Some(ref v) = self.text.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.gesture_id {
+            os.write_uint32(9, v)?;
+        }
+        if let Some(v) = self.rating_good {
+            os.write_uint32(10, v)?;
+        }
+        if let Some(v) = self.rating_poor {
+            os.write_uint32(11, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BloodMessageData {
+        BloodMessageData::new()
+    }
+
+    fn default_instance() -> &'static BloodMessageData {
+        static instance: ::protobuf::rt::LazyV2<BloodMessageData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BloodMessageData::new)
+    }
+}
+
+impl ::protobuf::Clear for BloodMessageData {
+    fn clear(&mut self) {
+        self.message_id = ::std::option::Option::None;
+        self.steam_id.clear();
+        self.character_name.clear();
+        self.online_area_id = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.position.clear();
+        self.angle = ::std::option::Option::None;
+        self.text.clear();
+        self.gesture_id = ::std::option::Option::None;
+        self.rating_good = ::std::option::Option::None;
+        self.rating_poor = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BloodMessageData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetBloodMessageListResponse {
+    // message fields
+    pub messages: ::protobuf::RepeatedField<BloodMessageData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetBloodMessageListResponse {
+    fn default() -> &'a RequestGetBloodMessageListResponse {
+        <RequestGetBloodMessageListResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetBloodMessageListResponse {
+    pub fn new() -> RequestGetBloodMessageListResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .BloodMessageData messages = 1;
+
+
+    pub fn get_messages(&self) -> &[BloodMessageData] {
+        &self.messages
+    }
+    pub fn clear_messages(&mut self) {
+        self.messages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_messages(&mut self, v: ::protobuf::RepeatedField<BloodMessageData>) {
+        self.messages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_messages(&mut self) -> &mut ::protobuf::RepeatedField<BloodMessageData> {
+        &mut self.messages
+    }
+
+    // Take field
+    pub fn take_messages(&mut self) -> ::protobuf::RepeatedField<BloodMessageData> {
+        ::std::mem::replace(&mut self.messages, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetBloodMessageListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.messages {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.messages)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.messages {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.messages {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetBloodMessageListResponse {
+        RequestGetBloodMessageListResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetBloodMessageListResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetBloodMessageListResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetBloodMessageListResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetBloodMessageListResponse {
+    fn clear(&mut self) {
+        self.messages.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetBloodMessageListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestReentryBloodMessage {
+    // message fields
+    pub message_ids: ::std::vec::Vec<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestReentryBloodMessage {
+    fn default() -> &'a RequestReentryBloodMessage {
+        <RequestReentryBloodMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestReentryBloodMessage {
+    pub fn new() -> RequestReentryBloodMessage {
+        ::std::default::Default::default()
+    }
+
+    // repeated uint64 message_ids = 1;
+
+
+    pub fn get_message_ids(&self) -> &[u64] {
+        &self.message_ids
+    }
+    pub fn clear_message_ids(&mut self) {
+        self.message_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_ids(&mut self, v: ::std::vec::Vec<u64>) {
+        self.message_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_message_ids(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.message_ids
+    }
+
+    // Take field
+    pub fn take_message_ids(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.message_ids, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for RequestReentryBloodMessage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.message_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.message_ids {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.message_ids {
+            os.write_uint64(1, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestReentryBloodMessage {
+        RequestReentryBloodMessage::new()
+    }
+
+    fn default_instance() -> &'static RequestReentryBloodMessage {
+        static instance: ::protobuf::rt::LazyV2<RequestReentryBloodMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestReentryBloodMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestReentryBloodMessage {
+    fn clear(&mut self) {
+        self.message_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestReentryBloodMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestReentryBloodMessageResponse {
+    // message fields
+    pub message_ids: ::std::vec::Vec<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestReentryBloodMessageResponse {
+    fn default() -> &'a RequestReentryBloodMessageResponse {
+        <RequestReentryBloodMessageResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestReentryBloodMessageResponse {
+    pub fn new() -> RequestReentryBloodMessageResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated uint64 message_ids = 1;
+
+
+    pub fn get_message_ids(&self) -> &[u64] {
+        &self.message_ids
+    }
+    pub fn clear_message_ids(&mut self) {
+        self.message_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_ids(&mut self, v: ::std::vec::Vec<u64>) {
+        self.message_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_message_ids(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.message_ids
+    }
+
+    // Take field
+    pub fn take_message_ids(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.message_ids, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for RequestReentryBloodMessageResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.message_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.message_ids {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.message_ids {
+            os.write_uint64(1, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestReentryBloodMessageResponse {
+        RequestReentryBloodMessageResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestReentryBloodMessageResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestReentryBloodMessageResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestReentryBloodMessageResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestReentryBloodMessageResponse {
+    fn clear(&mut self) {
+        self.message_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestReentryBloodMessageResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRemoveBloodMessage {
+    // message fields
+    message_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRemoveBloodMessage {
+    fn default() -> &'a RequestRemoveBloodMessage {
+        <RequestRemoveBloodMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRemoveBloodMessage {
+    pub fn new() -> RequestRemoveBloodMessage {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 message_id = 1;
+
+
+    pub fn get_message_id(&self) -> u64 {
+        self.message_id.unwrap_or(0)
+    }
+    pub fn clear_message_id(&mut self) {
+        self.message_id = ::std::option::Option::None;
+    }
+
+    pub fn has_message_id(&self) -> bool {
+        self.message_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_id(&mut self, v: u64) {
+        self.message_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestRemoveBloodMessage {
+    fn is_initialized(&self) -> bool {
+        if self.message_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.message_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.message_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.message_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRemoveBloodMessage {
+        RequestRemoveBloodMessage::new()
+    }
+
+    fn default_instance() -> &'static RequestRemoveBloodMessage {
+        static instance: ::protobuf::rt::LazyV2<RequestRemoveBloodMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRemoveBloodMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRemoveBloodMessage {
+    fn clear(&mut self) {
+        self.message_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRemoveBloodMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRemoveBloodMessageResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRemoveBloodMessageResponse {
+    fn default() -> &'a RequestRemoveBloodMessageResponse {
+        <RequestRemoveBloodMessageResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRemoveBloodMessageResponse {
+    pub fn new() -> RequestRemoveBloodMessageResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestRemoveBloodMessageResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRemoveBloodMessageResponse {
+        RequestRemoveBloodMessageResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestRemoveBloodMessageResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestRemoveBloodMessageResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRemoveBloodMessageResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRemoveBloodMessageResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRemoveBloodMessageResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestEvaluateBloodMessage {
+    // message fields
+    message_id: ::std::option::Option<u64>,
+    good: ::std::option::Option<bool>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestEvaluateBloodMessage {
+    fn default() -> &'a RequestEvaluateBloodMessage {
+        <RequestEvaluateBloodMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestEvaluateBloodMessage {
+    pub fn new() -> RequestEvaluateBloodMessage {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 message_id = 1;
+
+
+    pub fn get_message_id(&self) -> u64 {
+        self.message_id.unwrap_or(0)
+    }
+    pub fn clear_message_id(&mut self) {
+        self.message_id = ::std::option::Option::None;
+    }
+
+    pub fn has_message_id(&self) -> bool {
+        self.message_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_id(&mut self, v: u64) {
+        self.message_id = ::std::option::Option::Some(v);
+    }
+
+    // required bool good = 2;
+
+
+    pub fn get_good(&self) -> bool {
+        self.good.unwrap_or(false)
+    }
+    pub fn clear_good(&mut self) {
+        self.good = ::std::option::Option::None;
+    }
+
+    pub fn has_good(&self) -> bool {
+        self.good.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_good(&mut self, v: bool) {
+        self.good = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestEvaluateBloodMessage {
+    fn is_initialized(&self) -> bool {
+        if self.message_id.is_none() {
+            return false;
+        }
+        if self.good.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.message_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.good = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.message_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.good {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.message_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.good {
+            os.write_bool(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestEvaluateBloodMessage {
+        RequestEvaluateBloodMessage::new()
+    }
+
+    fn default_instance() -> &'static RequestEvaluateBloodMessage {
+        static instance: ::protobuf::rt::LazyV2<RequestEvaluateBloodMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestEvaluateBloodMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestEvaluateBloodMessage {
+    fn clear(&mut self) {
+        self.message_id = ::std::option::Option::None;
+        self.good = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestEvaluateBloodMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestEvaluateBloodMessageResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestEvaluateBloodMessageResponse {
+    fn default() -> &'a RequestEvaluateBloodMessageResponse {
+        <RequestEvaluateBloodMessageResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestEvaluateBloodMessageResponse {
+    pub fn new() -> RequestEvaluateBloodMessageResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestEvaluateBloodMessageResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestEvaluateBloodMessageResponse {
+        RequestEvaluateBloodMessageResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestEvaluateBloodMessageResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestEvaluateBloodMessageResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestEvaluateBloodMessageResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestEvaluateBloodMessageResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestEvaluateBloodMessageResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushEvaluateBloodMessage {
+    // message fields
+    message_id: ::std::option::Option<u64>,
+    good: ::std::option::Option<bool>,
+    rating_good: ::std::option::Option<u32>,
+    rating_poor: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushEvaluateBloodMessage {
+    fn default() -> &'a PushEvaluateBloodMessage {
+        <PushEvaluateBloodMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushEvaluateBloodMessage {
+    pub fn new() -> PushEvaluateBloodMessage {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 message_id = 1;
+
+
+    pub fn get_message_id(&self) -> u64 {
+        self.message_id.unwrap_or(0)
+    }
+    pub fn clear_message_id(&mut self) {
+        self.message_id = ::std::option::Option::None;
+    }
+
+    pub fn has_message_id(&self) -> bool {
+        self.message_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_id(&mut self, v: u64) {
+        self.message_id = ::std::option::Option::Some(v);
+    }
+
+    // required bool good = 2;
+
+
+    pub fn get_good(&self) -> bool {
+        self.good.unwrap_or(false)
+    }
+    pub fn clear_good(&mut self) {
+        self.good = ::std::option::Option::None;
+    }
+
+    pub fn has_good(&self) -> bool {
+        self.good.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_good(&mut self, v: bool) {
+        self.good = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 rating_good = 3;
+
+
+    pub fn get_rating_good(&self) -> u32 {
+        self.rating_good.unwrap_or(0)
+    }
+    pub fn clear_rating_good(&mut self) {
+        self.rating_good = ::std::option::Option::None;
+    }
+
+    pub fn has_rating_good(&self) -> bool {
+        self.rating_good.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rating_good(&mut self, v: u32) {
+        self.rating_good = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 rating_poor = 4;
+
+
+    pub fn get_rating_poor(&self) -> u32 {
+        self.rating_poor.unwrap_or(0)
+    }
+    pub fn clear_rating_poor(&mut self) {
+        self.rating_poor = ::std::option::Option::None;
+    }
+
+    pub fn has_rating_poor(&self) -> bool {
+        self.rating_poor.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rating_poor(&mut self, v: u32) {
+        self.rating_poor = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for PushEvaluateBloodMessage {
+    fn is_initialized(&self) -> bool {
+        if self.message_id.is_none() {
+            return false;
+        }
+        if self.good.is_none() {
+            return false;
+        }
+        if self.rating_good.is_none() {
+            return false;
+        }
+        if self.rating_poor.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.message_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.good = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rating_good = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rating_poor = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.message_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.good {
+            my_size += 2;
+        }
+        if let Some(v) = self.rating_good {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.rating_poor {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.message_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.good {
+            os.write_bool(2, v)?;
+        }
+        if let Some(v) = self.rating_good {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.rating_poor {
+            os.write_uint32(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushEvaluateBloodMessage {
+        PushEvaluateBloodMessage::new()
+    }
+
+    fn default_instance() -> &'static PushEvaluateBloodMessage {
+        static instance: ::protobuf::rt::LazyV2<PushEvaluateBloodMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushEvaluateBloodMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for PushEvaluateBloodMessage {
+    fn clear(&mut self) {
+        self.message_id = ::std::option::Option::None;
+        self.good = ::std::option::Option::None;
+        self.rating_good = ::std::option::Option::None;
+        self.rating_poor = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushEvaluateBloodMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCreateBloodstain {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    pub position: ::protobuf::SingularPtrField<super::common::Vector3>,
+    angle: ::std::option::Option<f32>,
+    replay_data: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCreateBloodstain {
+    fn default() -> &'a RequestCreateBloodstain {
+        <RequestCreateBloodstain as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCreateBloodstain {
+    pub fn new() -> RequestCreateBloodstain {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 2;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required .Vector3 position = 3;
+
+
+    pub fn get_position(&self) -> &super::common::Vector3 {
+        self.position.as_ref().unwrap_or_else(|| <super::common::Vector3 as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_position(&mut self) {
+        self.position.clear();
+    }
+
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: super::common::Vector3) {
+        self.position = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_position(&mut self) -> &mut super::common::Vector3 {
+        if self.position.is_none() {
+            self.position.set_default();
+        }
+        self.position.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_position(&mut self) -> super::common::Vector3 {
+        self.position.take().unwrap_or_else(|| super::common::Vector3::new())
+    }
+
+    // required float angle = 4;
+
+
+    pub fn get_angle(&self) -> f32 {
+        self.angle.unwrap_or(0.)
+    }
+    pub fn clear_angle(&mut self) {
+        self.angle = ::std::option::Option::None;
+    }
+
+    pub fn has_angle(&self) -> bool {
+        self.angle.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_angle(&mut self, v: f32) {
+        self.angle = ::std::option::Option::Some(v);
+    }
+
+    // required bytes replay_data = 5;
+
+
+    pub fn get_replay_data(&self) -> &[u8] {
+        match self.replay_data.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_replay_data(&mut self) {
+        self.replay_data.clear();
+    }
+
+    pub fn has_replay_data(&self) -> bool {
+        self.replay_data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replay_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.replay_data = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_replay_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.replay_data.is_none() {
+            self.replay_data.set_default();
+        }
+        self.replay_data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_replay_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.replay_data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for RequestCreateBloodstain {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.position.is_none() {
+            return false;
+        }
+        if self.angle.is_none() {
+            return false;
+        }
+        if self.replay_data.is_none() {
+            return false;
+        }
+        for v in &self.position {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.position)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.angle = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.replay_data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.angle {
+            my_size += 5;
+        }
+        if let Some(ref v) = self.replay_data.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(5, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.angle {
+            os.write_float(4, v)?;
+        }
+        if let Some(ref v) = self.replay_data.as_ref() {
+            os.write_bytes(5, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCreateBloodstain {
+        RequestCreateBloodstain::new()
+    }
+
+    fn default_instance() -> &'static RequestCreateBloodstain {
+        static instance: ::protobuf::rt::LazyV2<RequestCreateBloodstain> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCreateBloodstain::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCreateBloodstain {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.position.clear();
+        self.angle = ::std::option::Option::None;
+        self.replay_data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCreateBloodstain {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCreateBloodstainResponse {
+    // message fields
+    bloodstain_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCreateBloodstainResponse {
+    fn default() -> &'a RequestCreateBloodstainResponse {
+        <RequestCreateBloodstainResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCreateBloodstainResponse {
+    pub fn new() -> RequestCreateBloodstainResponse {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 bloodstain_id = 1;
+
+
+    pub fn get_bloodstain_id(&self) -> u64 {
+        self.bloodstain_id.unwrap_or(0)
+    }
+    pub fn clear_bloodstain_id(&mut self) {
+        self.bloodstain_id = ::std::option::Option::None;
+    }
+
+    pub fn has_bloodstain_id(&self) -> bool {
+        self.bloodstain_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bloodstain_id(&mut self, v: u64) {
+        self.bloodstain_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestCreateBloodstainResponse {
+    fn is_initialized(&self) -> bool {
+        if self.bloodstain_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bloodstain_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.bloodstain_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.bloodstain_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCreateBloodstainResponse {
+        RequestCreateBloodstainResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestCreateBloodstainResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestCreateBloodstainResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCreateBloodstainResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCreateBloodstainResponse {
+    fn clear(&mut self) {
+        self.bloodstain_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCreateBloodstainResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetBloodstainList {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetBloodstainList {
+    fn default() -> &'a RequestGetBloodstainList {
+        <RequestGetBloodstainList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetBloodstainList {
+    pub fn new() -> RequestGetBloodstainList {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 max_entries = 2;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetBloodstainList {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.max_entries {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetBloodstainList {
+        RequestGetBloodstainList::new()
+    }
+
+    fn default_instance() -> &'static RequestGetBloodstainList {
+        static instance: ::protobuf::rt::LazyV2<RequestGetBloodstainList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetBloodstainList::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetBloodstainList {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetBloodstainList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BloodstainData {
+    // message fields
+    bloodstain_id: ::std::option::Option<u64>,
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    online_area_id: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    pub position: ::protobuf::SingularPtrField<super::common::Vector3>,
+    angle: ::std::option::Option<f32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BloodstainData {
+    fn default() -> &'a BloodstainData {
+        <BloodstainData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BloodstainData {
+    pub fn new() -> BloodstainData {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 bloodstain_id = 1;
+
+
+    pub fn get_bloodstain_id(&self) -> u64 {
+        self.bloodstain_id.unwrap_or(0)
+    }
+    pub fn clear_bloodstain_id(&mut self) {
+        self.bloodstain_id = ::std::option::Option::None;
+    }
+
+    pub fn has_bloodstain_id(&self) -> bool {
+        self.bloodstain_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bloodstain_id(&mut self, v: u64) {
+        self.bloodstain_id = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 online_area_id = 3;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 4;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required .Vector3 position = 5;
+
+
+    pub fn get_position(&self) -> &super::common::Vector3 {
+        self.position.as_ref().unwrap_or_else(|| <super::common::Vector3 as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_position(&mut self) {
+        self.position.clear();
+    }
+
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: super::common::Vector3) {
+        self.position = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_position(&mut self) -> &mut super::common::Vector3 {
+        if self.position.is_none() {
+            self.position.set_default();
+        }
+        self.position.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_position(&mut self) -> super::common::Vector3 {
+        self.position.take().unwrap_or_else(|| super::common::Vector3::new())
+    }
+
+    // required float angle = 6;
+
+
+    pub fn get_angle(&self) -> f32 {
+        self.angle.unwrap_or(0.)
+    }
+    pub fn clear_angle(&mut self) {
+        self.angle = ::std::option::Option::None;
+    }
+
+    pub fn has_angle(&self) -> bool {
+        self.angle.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_angle(&mut self, v: f32) {
+        self.angle = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for BloodstainData {
+    fn is_initialized(&self) -> bool {
+        if self.bloodstain_id.is_none() {
+            return false;
+        }
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.position.is_none() {
+            return false;
+        }
+        if self.angle.is_none() {
+            return false;
+        }
+        for v in &self.position {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bloodstain_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.position)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.angle = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.bloodstain_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.angle {
+            my_size += 5;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.bloodstain_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.angle {
+            os.write_float(6, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BloodstainData {
+        BloodstainData::new()
+    }
+
+    fn default_instance() -> &'static BloodstainData {
+        static instance: ::protobuf::rt::LazyV2<BloodstainData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BloodstainData::new)
+    }
+}
+
+impl ::protobuf::Clear for BloodstainData {
+    fn clear(&mut self) {
+        self.bloodstain_id = ::std::option::Option::None;
+        self.steam_id.clear();
+        self.online_area_id = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.position.clear();
+        self.angle = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BloodstainData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetBloodstainListResponse {
+    // message fields
+    pub bloodstains: ::protobuf::RepeatedField<BloodstainData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetBloodstainListResponse {
+    fn default() -> &'a RequestGetBloodstainListResponse {
+        <RequestGetBloodstainListResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetBloodstainListResponse {
+    pub fn new() -> RequestGetBloodstainListResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .BloodstainData bloodstains = 1;
+
+
+    pub fn get_bloodstains(&self) -> &[BloodstainData] {
+        &self.bloodstains
+    }
+    pub fn clear_bloodstains(&mut self) {
+        self.bloodstains.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bloodstains(&mut self, v: ::protobuf::RepeatedField<BloodstainData>) {
+        self.bloodstains = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_bloodstains(&mut self) -> &mut ::protobuf::RepeatedField<BloodstainData> {
+        &mut self.bloodstains
+    }
+
+    // Take field
+    pub fn take_bloodstains(&mut self) -> ::protobuf::RepeatedField<BloodstainData> {
+        ::std::mem::replace(&mut self.bloodstains, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetBloodstainListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.bloodstains {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.bloodstains)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.bloodstains {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.bloodstains {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetBloodstainListResponse {
+        RequestGetBloodstainListResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetBloodstainListResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetBloodstainListResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetBloodstainListResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetBloodstainListResponse {
+    fn clear(&mut self) {
+        self.bloodstains.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetBloodstainListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetBloodstainReplay {
+    // message fields
+    bloodstain_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetBloodstainReplay {
+    fn default() -> &'a RequestGetBloodstainReplay {
+        <RequestGetBloodstainReplay as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetBloodstainReplay {
+    pub fn new() -> RequestGetBloodstainReplay {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 bloodstain_id = 1;
+
+
+    pub fn get_bloodstain_id(&self) -> u64 {
+        self.bloodstain_id.unwrap_or(0)
+    }
+    pub fn clear_bloodstain_id(&mut self) {
+        self.bloodstain_id = ::std::option::Option::None;
+    }
+
+    pub fn has_bloodstain_id(&self) -> bool {
+        self.bloodstain_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bloodstain_id(&mut self, v: u64) {
+        self.bloodstain_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetBloodstainReplay {
+    fn is_initialized(&self) -> bool {
+        if self.bloodstain_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bloodstain_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.bloodstain_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.bloodstain_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetBloodstainReplay {
+        RequestGetBloodstainReplay::new()
+    }
+
+    fn default_instance() -> &'static RequestGetBloodstainReplay {
+        static instance: ::protobuf::rt::LazyV2<RequestGetBloodstainReplay> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetBloodstainReplay::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetBloodstainReplay {
+    fn clear(&mut self) {
+        self.bloodstain_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetBloodstainReplay {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetBloodstainReplayResponse {
+    // message fields
+    bloodstain_id: ::std::option::Option<u64>,
+    replay_data: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetBloodstainReplayResponse {
+    fn default() -> &'a RequestGetBloodstainReplayResponse {
+        <RequestGetBloodstainReplayResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetBloodstainReplayResponse {
+    pub fn new() -> RequestGetBloodstainReplayResponse {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 bloodstain_id = 1;
+
+
+    pub fn get_bloodstain_id(&self) -> u64 {
+        self.bloodstain_id.unwrap_or(0)
+    }
+    pub fn clear_bloodstain_id(&mut self) {
+        self.bloodstain_id = ::std::option::Option::None;
+    }
+
+    pub fn has_bloodstain_id(&self) -> bool {
+        self.bloodstain_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bloodstain_id(&mut self, v: u64) {
+        self.bloodstain_id = ::std::option::Option::Some(v);
+    }
+
+    // required bytes replay_data = 2;
+
+
+    pub fn get_replay_data(&self) -> &[u8] {
+        match self.replay_data.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_replay_data(&mut self) {
+        self.replay_data.clear();
+    }
+
+    pub fn has_replay_data(&self) -> bool {
+        self.replay_data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replay_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.replay_data = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_replay_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.replay_data.is_none() {
+            self.replay_data.set_default();
+        }
+        self.replay_data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_replay_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.replay_data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetBloodstainReplayResponse {
+    fn is_initialized(&self) -> bool {
+        if self.bloodstain_id.is_none() {
+            return false;
+        }
+        if self.replay_data.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bloodstain_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.replay_data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.bloodstain_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.replay_data.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.bloodstain_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.replay_data.as_ref() {
+            os.write_bytes(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetBloodstainReplayResponse {
+        RequestGetBloodstainReplayResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetBloodstainReplayResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetBloodstainReplayResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetBloodstainReplayResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetBloodstainReplayResponse {
+    fn clear(&mut self) {
+        self.bloodstain_id = ::std::option::Option::None;
+        self.replay_data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetBloodstainReplayResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCreateGhostData {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    ghost_data: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCreateGhostData {
+    fn default() -> &'a RequestCreateGhostData {
+        <RequestCreateGhostData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCreateGhostData {
+    pub fn new() -> RequestCreateGhostData {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 2;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required bytes ghost_data = 3;
+
+
+    pub fn get_ghost_data(&self) -> &[u8] {
+        match self.ghost_data.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_ghost_data(&mut self) {
+        self.ghost_data.clear();
+    }
+
+    pub fn has_ghost_data(&self) -> bool {
+        self.ghost_data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ghost_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ghost_data = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ghost_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.ghost_data.is_none() {
+            self.ghost_data.set_default();
+        }
+        self.ghost_data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ghost_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.ghost_data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for RequestCreateGhostData {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.ghost_data.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ghost_data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.ghost_data.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(3, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.ghost_data.as_ref() {
+            os.write_bytes(3, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCreateGhostData {
+        RequestCreateGhostData::new()
+    }
+
+    fn default_instance() -> &'static RequestCreateGhostData {
+        static instance: ::protobuf::rt::LazyV2<RequestCreateGhostData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCreateGhostData::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCreateGhostData {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.ghost_data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCreateGhostData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCreateGhostDataResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCreateGhostDataResponse {
+    fn default() -> &'a RequestCreateGhostDataResponse {
+        <RequestCreateGhostDataResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCreateGhostDataResponse {
+    pub fn new() -> RequestCreateGhostDataResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestCreateGhostDataResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCreateGhostDataResponse {
+        RequestCreateGhostDataResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestCreateGhostDataResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestCreateGhostDataResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCreateGhostDataResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCreateGhostDataResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCreateGhostDataResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetGhostDataList {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetGhostDataList {
+    fn default() -> &'a RequestGetGhostDataList {
+        <RequestGetGhostDataList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetGhostDataList {
+    pub fn new() -> RequestGetGhostDataList {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 max_entries = 2;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetGhostDataList {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.max_entries {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetGhostDataList {
+        RequestGetGhostDataList::new()
+    }
+
+    fn default_instance() -> &'static RequestGetGhostDataList {
+        static instance: ::protobuf::rt::LazyV2<RequestGetGhostDataList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetGhostDataList::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetGhostDataList {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetGhostDataList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GhostData {
+    // message fields
+    ghost_id: ::std::option::Option<u64>,
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    online_area_id: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    ghost_data: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GhostData {
+    fn default() -> &'a GhostData {
+        <GhostData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GhostData {
+    pub fn new() -> GhostData {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 ghost_id = 1;
+
+
+    pub fn get_ghost_id(&self) -> u64 {
+        self.ghost_id.unwrap_or(0)
+    }
+    pub fn clear_ghost_id(&mut self) {
+        self.ghost_id = ::std::option::Option::None;
+    }
+
+    pub fn has_ghost_id(&self) -> bool {
+        self.ghost_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ghost_id(&mut self, v: u64) {
+        self.ghost_id = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 online_area_id = 3;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 4;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required bytes ghost_data = 5;
+
+
+    pub fn get_ghost_data(&self) -> &[u8] {
+        match self.ghost_data.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_ghost_data(&mut self) {
+        self.ghost_data.clear();
+    }
+
+    pub fn has_ghost_data(&self) -> bool {
+        self.ghost_data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ghost_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ghost_data = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ghost_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.ghost_data.is_none() {
+            self.ghost_data.set_default();
+        }
+        self.ghost_data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ghost_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.ghost_data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for GhostData {
+    fn is_initialized(&self) -> bool {
+        if self.ghost_id.is_none() {
+            return false;
+        }
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.ghost_data.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ghost_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ghost_data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.ghost_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.ghost_data.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(5, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.ghost_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(ref v) = self.ghost_data.as_ref() {
+            os.write_bytes(5, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GhostData {
+        GhostData::new()
+    }
+
+    fn default_instance() -> &'static GhostData {
+        static instance: ::protobuf::rt::LazyV2<GhostData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GhostData::new)
+    }
+}
+
+impl ::protobuf::Clear for GhostData {
+    fn clear(&mut self) {
+        self.ghost_id = ::std::option::Option::None;
+        self.steam_id.clear();
+        self.online_area_id = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.ghost_data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GhostData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetGhostDataListResponse {
+    // message fields
+    pub ghosts: ::protobuf::RepeatedField<GhostData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetGhostDataListResponse {
+    fn default() -> &'a RequestGetGhostDataListResponse {
+        <RequestGetGhostDataListResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetGhostDataListResponse {
+    pub fn new() -> RequestGetGhostDataListResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .GhostData ghosts = 1;
+
+
+    pub fn get_ghosts(&self) -> &[GhostData] {
+        &self.ghosts
+    }
+    pub fn clear_ghosts(&mut self) {
+        self.ghosts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ghosts(&mut self, v: ::protobuf::RepeatedField<GhostData>) {
+        self.ghosts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ghosts(&mut self) -> &mut ::protobuf::RepeatedField<GhostData> {
+        &mut self.ghosts
+    }
+
+    // Take field
+    pub fn take_ghosts(&mut self) -> ::protobuf::RepeatedField<GhostData> {
+        ::std::mem::replace(&mut self.ghosts, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetGhostDataListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ghosts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ghosts)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ghosts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ghosts {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetGhostDataListResponse {
+        RequestGetGhostDataListResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetGhostDataListResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetGhostDataListResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetGhostDataListResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetGhostDataListResponse {
+    fn clear(&mut self) {
+        self.ghosts.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetGhostDataListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct MatchingParameter {
+    // message fields
+    soul_level: ::std::option::Option<u32>,
+    max_weapon_level: ::std::option::Option<u32>,
+    password: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MatchingParameter {
+    fn default() -> &'a MatchingParameter {
+        <MatchingParameter as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MatchingParameter {
+    pub fn new() -> MatchingParameter {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 soul_level = 1;
+
+
+    pub fn get_soul_level(&self) -> u32 {
+        self.soul_level.unwrap_or(0)
+    }
+    pub fn clear_soul_level(&mut self) {
+        self.soul_level = ::std::option::Option::None;
+    }
+
+    pub fn has_soul_level(&self) -> bool {
+        self.soul_level.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_soul_level(&mut self, v: u32) {
+        self.soul_level = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 max_weapon_level = 2;
+
+
+    pub fn get_max_weapon_level(&self) -> u32 {
+        self.max_weapon_level.unwrap_or(0)
+    }
+    pub fn clear_max_weapon_level(&mut self) {
+        self.max_weapon_level = ::std::option::Option::None;
+    }
+
+    pub fn has_max_weapon_level(&self) -> bool {
+        self.max_weapon_level.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_weapon_level(&mut self, v: u32) {
+        self.max_weapon_level = ::std::option::Option::Some(v);
+    }
+
+    // optional string password = 3;
+
+
+    pub fn get_password(&self) -> &str {
+        match self.password.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_password(&mut self) {
+        self.password.clear();
+    }
+
+    pub fn has_password(&self) -> bool {
+        self.password.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password(&mut self, v: ::std::string::String) {
+        self.password = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password(&mut self) -> &mut ::std::string::String {
+        if self.password.is_none() {
+            self.password.set_default();
+        }
+        self.password.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_password(&mut self) -> ::std::string::String {
+        self.password.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for MatchingParameter {
+    fn is_initialized(&self) -> bool {
+        if self.soul_level.is_none() {
+            return false;
+        }
+        if self.max_weapon_level.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.soul_level = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_weapon_level = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.password)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.soul_level {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_weapon_level {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.password.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.soul_level {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.max_weapon_level {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.password.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MatchingParameter {
+        MatchingParameter::new()
+    }
+
+    fn default_instance() -> &'static MatchingParameter {
+        static instance: ::protobuf::rt::LazyV2<MatchingParameter> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MatchingParameter::new)
+    }
+}
+
+impl ::protobuf::Clear for MatchingParameter {
+    fn clear(&mut self) {
+        self.soul_level = ::std::option::Option::None;
+        self.max_weapon_level = ::std::option::Option::None;
+        self.password.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MatchingParameter {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCreateSign {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    pub position: ::protobuf::SingularPtrField<super::common::Vector3>,
+    angle: ::std::option::Option<f32>,
+    sign_type: ::std::option::Option<u32>,
+    item_id: ::std::option::Option<u32>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCreateSign {
+    fn default() -> &'a RequestCreateSign {
+        <RequestCreateSign as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCreateSign {
+    pub fn new() -> RequestCreateSign {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 2;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required .Vector3 position = 3;
+
+
+    pub fn get_position(&self) -> &super::common::Vector3 {
+        self.position.as_ref().unwrap_or_else(|| <super::common::Vector3 as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_position(&mut self) {
+        self.position.clear();
+    }
+
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: super::common::Vector3) {
+        self.position = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_position(&mut self) -> &mut super::common::Vector3 {
+        if self.position.is_none() {
+            self.position.set_default();
+        }
+        self.position.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_position(&mut self) -> super::common::Vector3 {
+        self.position.take().unwrap_or_else(|| super::common::Vector3::new())
+    }
+
+    // required float angle = 4;
+
+
+    pub fn get_angle(&self) -> f32 {
+        self.angle.unwrap_or(0.)
+    }
+    pub fn clear_angle(&mut self) {
+        self.angle = ::std::option::Option::None;
+    }
+
+    pub fn has_angle(&self) -> bool {
+        self.angle.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_angle(&mut self, v: f32) {
+        self.angle = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 sign_type = 5;
+
+
+    pub fn get_sign_type(&self) -> u32 {
+        self.sign_type.unwrap_or(0)
+    }
+    pub fn clear_sign_type(&mut self) {
+        self.sign_type = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_type(&self) -> bool {
+        self.sign_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_type(&mut self, v: u32) {
+        self.sign_type = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 item_id = 6;
+
+
+    pub fn get_item_id(&self) -> u32 {
+        self.item_id.unwrap_or(0)
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id = ::std::option::Option::None;
+    }
+
+    pub fn has_item_id(&self) -> bool {
+        self.item_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: u32) {
+        self.item_id = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 7;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+}
+
+impl ::protobuf::Message for RequestCreateSign {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.position.is_none() {
+            return false;
+        }
+        if self.angle.is_none() {
+            return false;
+        }
+        if self.sign_type.is_none() {
+            return false;
+        }
+        if self.item_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.position {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.position)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.angle = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.sign_type = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.item_id = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.angle {
+            my_size += 5;
+        }
+        if let Some(v) = self.sign_type {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.item_id {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.angle {
+            os.write_float(4, v)?;
+        }
+        if let Some(v) = self.sign_type {
+            os.write_uint32(5, v)?;
+        }
+        if let Some(v) = self.item_id {
+            os.write_uint32(6, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCreateSign {
+        RequestCreateSign::new()
+    }
+
+    fn default_instance() -> &'static RequestCreateSign {
+        static instance: ::protobuf::rt::LazyV2<RequestCreateSign> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCreateSign::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCreateSign {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.position.clear();
+        self.angle = ::std::option::Option::None;
+        self.sign_type = ::std::option::Option::None;
+        self.item_id = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCreateSign {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCreateSignResponse {
+    // message fields
+    sign_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCreateSignResponse {
+    fn default() -> &'a RequestCreateSignResponse {
+        <RequestCreateSignResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCreateSignResponse {
+    pub fn new() -> RequestCreateSignResponse {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 sign_id = 1;
+
+
+    pub fn get_sign_id(&self) -> u64 {
+        self.sign_id.unwrap_or(0)
+    }
+    pub fn clear_sign_id(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_id(&self) -> bool {
+        self.sign_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_id(&mut self, v: u64) {
+        self.sign_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestCreateSignResponse {
+    fn is_initialized(&self) -> bool {
+        if self.sign_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.sign_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.sign_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCreateSignResponse {
+        RequestCreateSignResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestCreateSignResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestCreateSignResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCreateSignResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCreateSignResponse {
+    fn clear(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCreateSignResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRemoveSign {
+    // message fields
+    sign_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRemoveSign {
+    fn default() -> &'a RequestRemoveSign {
+        <RequestRemoveSign as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRemoveSign {
+    pub fn new() -> RequestRemoveSign {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 sign_id = 1;
+
+
+    pub fn get_sign_id(&self) -> u64 {
+        self.sign_id.unwrap_or(0)
+    }
+    pub fn clear_sign_id(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_id(&self) -> bool {
+        self.sign_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_id(&mut self, v: u64) {
+        self.sign_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestRemoveSign {
+    fn is_initialized(&self) -> bool {
+        if self.sign_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.sign_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.sign_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRemoveSign {
+        RequestRemoveSign::new()
+    }
+
+    fn default_instance() -> &'static RequestRemoveSign {
+        static instance: ::protobuf::rt::LazyV2<RequestRemoveSign> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRemoveSign::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRemoveSign {
+    fn clear(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRemoveSign {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRemoveSignResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRemoveSignResponse {
+    fn default() -> &'a RequestRemoveSignResponse {
+        <RequestRemoveSignResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRemoveSignResponse {
+    pub fn new() -> RequestRemoveSignResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestRemoveSignResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRemoveSignResponse {
+        RequestRemoveSignResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestRemoveSignResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestRemoveSignResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRemoveSignResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRemoveSignResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRemoveSignResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetSignList {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetSignList {
+    fn default() -> &'a RequestGetSignList {
+        <RequestGetSignList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetSignList {
+    pub fn new() -> RequestGetSignList {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 2;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+
+    // optional uint32 max_entries = 3;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetSignList {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.max_entries {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetSignList {
+        RequestGetSignList::new()
+    }
+
+    fn default_instance() -> &'static RequestGetSignList {
+        static instance: ::protobuf::rt::LazyV2<RequestGetSignList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetSignList::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetSignList {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetSignList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SignData {
+    // message fields
+    sign_id: ::std::option::Option<u64>,
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    character_name: ::protobuf::SingularField<::std::string::String>,
+    online_area_id: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    pub position: ::protobuf::SingularPtrField<super::common::Vector3>,
+    angle: ::std::option::Option<f32>,
+    sign_type: ::std::option::Option<u32>,
+    item_id: ::std::option::Option<u32>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SignData {
+    fn default() -> &'a SignData {
+        <SignData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SignData {
+    pub fn new() -> SignData {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 sign_id = 1;
+
+
+    pub fn get_sign_id(&self) -> u64 {
+        self.sign_id.unwrap_or(0)
+    }
+    pub fn clear_sign_id(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_id(&self) -> bool {
+        self.sign_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_id(&mut self, v: u64) {
+        self.sign_id = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string character_name = 3;
+
+
+    pub fn get_character_name(&self) -> &str {
+        match self.character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_character_name(&mut self) {
+        self.character_name.clear();
+    }
+
+    pub fn has_character_name(&self) -> bool {
+        self.character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_character_name(&mut self, v: ::std::string::String) {
+        self.character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_character_name(&mut self) -> &mut ::std::string::String {
+        if self.character_name.is_none() {
+            self.character_name.set_default();
+        }
+        self.character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_character_name(&mut self) -> ::std::string::String {
+        self.character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 online_area_id = 4;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 5;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required .Vector3 position = 6;
+
+
+    pub fn get_position(&self) -> &super::common::Vector3 {
+        self.position.as_ref().unwrap_or_else(|| <super::common::Vector3 as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_position(&mut self) {
+        self.position.clear();
+    }
+
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: super::common::Vector3) {
+        self.position = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_position(&mut self) -> &mut super::common::Vector3 {
+        if self.position.is_none() {
+            self.position.set_default();
+        }
+        self.position.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_position(&mut self) -> super::common::Vector3 {
+        self.position.take().unwrap_or_else(|| super::common::Vector3::new())
+    }
+
+    // required float angle = 7;
+
+
+    pub fn get_angle(&self) -> f32 {
+        self.angle.unwrap_or(0.)
+    }
+    pub fn clear_angle(&mut self) {
+        self.angle = ::std::option::Option::None;
+    }
+
+    pub fn has_angle(&self) -> bool {
+        self.angle.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_angle(&mut self, v: f32) {
+        self.angle = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 sign_type = 8;
+
+
+    pub fn get_sign_type(&self) -> u32 {
+        self.sign_type.unwrap_or(0)
+    }
+    pub fn clear_sign_type(&mut self) {
+        self.sign_type = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_type(&self) -> bool {
+        self.sign_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_type(&mut self, v: u32) {
+        self.sign_type = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 item_id = 9;
+
+
+    pub fn get_item_id(&self) -> u32 {
+        self.item_id.unwrap_or(0)
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id = ::std::option::Option::None;
+    }
+
+    pub fn has_item_id(&self) -> bool {
+        self.item_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: u32) {
+        self.item_id = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 10;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+}
+
+impl ::protobuf::Message for SignData {
+    fn is_initialized(&self) -> bool {
+        if self.sign_id.is_none() {
+            return false;
+        }
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.character_name.is_none() {
+            return false;
+        }
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.position.is_none() {
+            return false;
+        }
+        if self.angle.is_none() {
+            return false;
+        }
+        if self.sign_type.is_none() {
+            return false;
+        }
+        if self.item_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.position {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.character_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.position)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.angle = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.sign_type = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.item_id = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.sign_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.angle {
+            my_size += 5;
+        }
+        if let Some(v) = self.sign_type {
+            my_size += ::protobuf::rt::value_size(8, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.item_id {
+            my_size += ::protobuf::rt::value_size(9, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.sign_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(5, v)?;
+        }
+        if let Some(ref v) = self.position.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.angle {
+            os.write_float(7, v)?;
+        }
+        if let Some(v) = self.sign_type {
+            os.write_uint32(8, v)?;
+        }
+        if let Some(v) = self.item_id {
+            os.write_uint32(9, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SignData {
+        SignData::new()
+    }
+
+    fn default_instance() -> &'static SignData {
+        static instance: ::protobuf::rt::LazyV2<SignData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SignData::new)
+    }
+}
+
+impl ::protobuf::Clear for SignData {
+    fn clear(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+        self.steam_id.clear();
+        self.character_name.clear();
+        self.online_area_id = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.position.clear();
+        self.angle = ::std::option::Option::None;
+        self.sign_type = ::std::option::Option::None;
+        self.item_id = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SignData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetSignListResponse {
+    // message fields
+    pub signs: ::protobuf::RepeatedField<SignData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetSignListResponse {
+    fn default() -> &'a RequestGetSignListResponse {
+        <RequestGetSignListResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetSignListResponse {
+    pub fn new() -> RequestGetSignListResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .SignData signs = 1;
+
+
+    pub fn get_signs(&self) -> &[SignData] {
+        &self.signs
+    }
+    pub fn clear_signs(&mut self) {
+        self.signs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signs(&mut self, v: ::protobuf::RepeatedField<SignData>) {
+        self.signs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_signs(&mut self) -> &mut ::protobuf::RepeatedField<SignData> {
+        &mut self.signs
+    }
+
+    // Take field
+    pub fn take_signs(&mut self) -> ::protobuf::RepeatedField<SignData> {
+        ::std::mem::replace(&mut self.signs, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetSignListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.signs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.signs)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.signs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.signs {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetSignListResponse {
+        RequestGetSignListResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetSignListResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetSignListResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetSignListResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetSignListResponse {
+    fn clear(&mut self) {
+        self.signs.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetSignListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestSummonSign {
+    // message fields
+    sign_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestSummonSign {
+    fn default() -> &'a RequestSummonSign {
+        <RequestSummonSign as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestSummonSign {
+    pub fn new() -> RequestSummonSign {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 sign_id = 1;
+
+
+    pub fn get_sign_id(&self) -> u64 {
+        self.sign_id.unwrap_or(0)
+    }
+    pub fn clear_sign_id(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_id(&self) -> bool {
+        self.sign_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_id(&mut self, v: u64) {
+        self.sign_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestSummonSign {
+    fn is_initialized(&self) -> bool {
+        if self.sign_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.sign_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.sign_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestSummonSign {
+        RequestSummonSign::new()
+    }
+
+    fn default_instance() -> &'static RequestSummonSign {
+        static instance: ::protobuf::rt::LazyV2<RequestSummonSign> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestSummonSign::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestSummonSign {
+    fn clear(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestSummonSign {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestSummonSignResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestSummonSignResponse {
+    fn default() -> &'a RequestSummonSignResponse {
+        <RequestSummonSignResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestSummonSignResponse {
+    pub fn new() -> RequestSummonSignResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestSummonSignResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestSummonSignResponse {
+        RequestSummonSignResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestSummonSignResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestSummonSignResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestSummonSignResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestSummonSignResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestSummonSignResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushRequestSummonSign {
+    // message fields
+    sign_id: ::std::option::Option<u64>,
+    host_steam_id: ::protobuf::SingularField<::std::string::String>,
+    host_character_name: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushRequestSummonSign {
+    fn default() -> &'a PushRequestSummonSign {
+        <PushRequestSummonSign as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushRequestSummonSign {
+    pub fn new() -> PushRequestSummonSign {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 sign_id = 1;
+
+
+    pub fn get_sign_id(&self) -> u64 {
+        self.sign_id.unwrap_or(0)
+    }
+    pub fn clear_sign_id(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_id(&self) -> bool {
+        self.sign_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_id(&mut self, v: u64) {
+        self.sign_id = ::std::option::Option::Some(v);
+    }
+
+    // required string host_steam_id = 2;
+
+
+    pub fn get_host_steam_id(&self) -> &str {
+        match self.host_steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_host_steam_id(&mut self) {
+        self.host_steam_id.clear();
+    }
+
+    pub fn has_host_steam_id(&self) -> bool {
+        self.host_steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host_steam_id(&mut self, v: ::std::string::String) {
+        self.host_steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.host_steam_id.is_none() {
+            self.host_steam_id.set_default();
+        }
+        self.host_steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_host_steam_id(&mut self) -> ::std::string::String {
+        self.host_steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string host_character_name = 3;
+
+
+    pub fn get_host_character_name(&self) -> &str {
+        match self.host_character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_host_character_name(&mut self) {
+        self.host_character_name.clear();
+    }
+
+    pub fn has_host_character_name(&self) -> bool {
+        self.host_character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host_character_name(&mut self, v: ::std::string::String) {
+        self.host_character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host_character_name(&mut self) -> &mut ::std::string::String {
+        if self.host_character_name.is_none() {
+            self.host_character_name.set_default();
+        }
+        self.host_character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_host_character_name(&mut self) -> ::std::string::String {
+        self.host_character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PushRequestSummonSign {
+    fn is_initialized(&self) -> bool {
+        if self.sign_id.is_none() {
+            return false;
+        }
+        if self.host_steam_id.is_none() {
+            return false;
+        }
+        if self.host_character_name.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.host_steam_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.host_character_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.sign_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.host_steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.host_character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.sign_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.host_steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.host_character_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushRequestSummonSign {
+        PushRequestSummonSign::new()
+    }
+
+    fn default_instance() -> &'static PushRequestSummonSign {
+        static instance: ::protobuf::rt::LazyV2<PushRequestSummonSign> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushRequestSummonSign::new)
+    }
+}
+
+impl ::protobuf::Clear for PushRequestSummonSign {
+    fn clear(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+        self.host_steam_id.clear();
+        self.host_character_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushRequestSummonSign {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRespondSummonSign {
+    // message fields
+    sign_id: ::std::option::Option<u64>,
+    accepted: ::std::option::Option<bool>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRespondSummonSign {
+    fn default() -> &'a RequestRespondSummonSign {
+        <RequestRespondSummonSign as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRespondSummonSign {
+    pub fn new() -> RequestRespondSummonSign {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 sign_id = 1;
+
+
+    pub fn get_sign_id(&self) -> u64 {
+        self.sign_id.unwrap_or(0)
+    }
+    pub fn clear_sign_id(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_id(&self) -> bool {
+        self.sign_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_id(&mut self, v: u64) {
+        self.sign_id = ::std::option::Option::Some(v);
+    }
+
+    // required bool accepted = 2;
+
+
+    pub fn get_accepted(&self) -> bool {
+        self.accepted.unwrap_or(false)
+    }
+    pub fn clear_accepted(&mut self) {
+        self.accepted = ::std::option::Option::None;
+    }
+
+    pub fn has_accepted(&self) -> bool {
+        self.accepted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accepted(&mut self, v: bool) {
+        self.accepted = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestRespondSummonSign {
+    fn is_initialized(&self) -> bool {
+        if self.sign_id.is_none() {
+            return false;
+        }
+        if self.accepted.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.accepted = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.sign_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.accepted {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.sign_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.accepted {
+            os.write_bool(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRespondSummonSign {
+        RequestRespondSummonSign::new()
+    }
+
+    fn default_instance() -> &'static RequestRespondSummonSign {
+        static instance: ::protobuf::rt::LazyV2<RequestRespondSummonSign> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRespondSummonSign::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRespondSummonSign {
+    fn clear(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+        self.accepted = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRespondSummonSign {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRespondSummonSignResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRespondSummonSignResponse {
+    fn default() -> &'a RequestRespondSummonSignResponse {
+        <RequestRespondSummonSignResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRespondSummonSignResponse {
+    pub fn new() -> RequestRespondSummonSignResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestRespondSummonSignResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRespondSummonSignResponse {
+        RequestRespondSummonSignResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestRespondSummonSignResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestRespondSummonSignResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRespondSummonSignResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRespondSummonSignResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRespondSummonSignResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushSummonSignResult {
+    // message fields
+    sign_id: ::std::option::Option<u64>,
+    accepted: ::std::option::Option<bool>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushSummonSignResult {
+    fn default() -> &'a PushSummonSignResult {
+        <PushSummonSignResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushSummonSignResult {
+    pub fn new() -> PushSummonSignResult {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 sign_id = 1;
+
+
+    pub fn get_sign_id(&self) -> u64 {
+        self.sign_id.unwrap_or(0)
+    }
+    pub fn clear_sign_id(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_id(&self) -> bool {
+        self.sign_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_id(&mut self, v: u64) {
+        self.sign_id = ::std::option::Option::Some(v);
+    }
+
+    // required bool accepted = 2;
+
+
+    pub fn get_accepted(&self) -> bool {
+        self.accepted.unwrap_or(false)
+    }
+    pub fn clear_accepted(&mut self) {
+        self.accepted = ::std::option::Option::None;
+    }
+
+    pub fn has_accepted(&self) -> bool {
+        self.accepted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accepted(&mut self, v: bool) {
+        self.accepted = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for PushSummonSignResult {
+    fn is_initialized(&self) -> bool {
+        if self.sign_id.is_none() {
+            return false;
+        }
+        if self.accepted.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.accepted = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.sign_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.accepted {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.sign_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.accepted {
+            os.write_bool(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushSummonSignResult {
+        PushSummonSignResult::new()
+    }
+
+    fn default_instance() -> &'static PushSummonSignResult {
+        static instance: ::protobuf::rt::LazyV2<PushSummonSignResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushSummonSignResult::new)
+    }
+}
+
+impl ::protobuf::Clear for PushSummonSignResult {
+    fn clear(&mut self) {
+        self.sign_id = ::std::option::Option::None;
+        self.accepted = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushSummonSignResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestUpdatePlayerStatus {
+    // message fields
+    character_name: ::protobuf::SingularField<::std::string::String>,
+    online_area_id: ::std::option::Option<u32>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    embered: ::std::option::Option<bool>,
+    in_coop: ::std::option::Option<bool>,
+    covenant: ::std::option::Option<u32>,
+    ng_cycle: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestUpdatePlayerStatus {
+    fn default() -> &'a RequestUpdatePlayerStatus {
+        <RequestUpdatePlayerStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestUpdatePlayerStatus {
+    pub fn new() -> RequestUpdatePlayerStatus {
+        ::std::default::Default::default()
+    }
+
+    // required string character_name = 1;
+
+
+    pub fn get_character_name(&self) -> &str {
+        match self.character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_character_name(&mut self) {
+        self.character_name.clear();
+    }
+
+    pub fn has_character_name(&self) -> bool {
+        self.character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_character_name(&mut self, v: ::std::string::String) {
+        self.character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_character_name(&mut self) -> &mut ::std::string::String {
+        if self.character_name.is_none() {
+            self.character_name.set_default();
+        }
+        self.character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_character_name(&mut self) -> ::std::string::String {
+        self.character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 online_area_id = 2;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 3;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+
+    // required bool embered = 4;
+
+
+    pub fn get_embered(&self) -> bool {
+        self.embered.unwrap_or(false)
+    }
+    pub fn clear_embered(&mut self) {
+        self.embered = ::std::option::Option::None;
+    }
+
+    pub fn has_embered(&self) -> bool {
+        self.embered.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_embered(&mut self, v: bool) {
+        self.embered = ::std::option::Option::Some(v);
+    }
+
+    // required bool in_coop = 5;
+
+
+    pub fn get_in_coop(&self) -> bool {
+        self.in_coop.unwrap_or(false)
+    }
+    pub fn clear_in_coop(&mut self) {
+        self.in_coop = ::std::option::Option::None;
+    }
+
+    pub fn has_in_coop(&self) -> bool {
+        self.in_coop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_in_coop(&mut self, v: bool) {
+        self.in_coop = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 covenant = 6;
+
+
+    pub fn get_covenant(&self) -> u32 {
+        self.covenant.unwrap_or(0)
+    }
+    pub fn clear_covenant(&mut self) {
+        self.covenant = ::std::option::Option::None;
+    }
+
+    pub fn has_covenant(&self) -> bool {
+        self.covenant.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_covenant(&mut self, v: u32) {
+        self.covenant = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 ng_cycle = 7;
+
+
+    pub fn get_ng_cycle(&self) -> u32 {
+        self.ng_cycle.unwrap_or(0)
+    }
+    pub fn clear_ng_cycle(&mut self) {
+        self.ng_cycle = ::std::option::Option::None;
+    }
+
+    pub fn has_ng_cycle(&self) -> bool {
+        self.ng_cycle.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ng_cycle(&mut self, v: u32) {
+        self.ng_cycle = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestUpdatePlayerStatus {
+    fn is_initialized(&self) -> bool {
+        if self.character_name.is_none() {
+            return false;
+        }
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        if self.embered.is_none() {
+            return false;
+        }
+        if self.in_coop.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.character_name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.embered = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.in_coop = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.covenant = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.ng_cycle = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.embered {
+            my_size += 2;
+        }
+        if let Some(v) = self.in_coop {
+            my_size += 2;
+        }
+        if let Some(v) = self.covenant {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.ng_cycle {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.character_name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.embered {
+            os.write_bool(4, v)?;
+        }
+        if let Some(v) = self.in_coop {
+            os.write_bool(5, v)?;
+        }
+        if let Some(v) = self.covenant {
+            os.write_uint32(6, v)?;
+        }
+        if let Some(v) = self.ng_cycle {
+            os.write_uint32(7, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestUpdatePlayerStatus {
+        RequestUpdatePlayerStatus::new()
+    }
+
+    fn default_instance() -> &'static RequestUpdatePlayerStatus {
+        static instance: ::protobuf::rt::LazyV2<RequestUpdatePlayerStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestUpdatePlayerStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestUpdatePlayerStatus {
+    fn clear(&mut self) {
+        self.character_name.clear();
+        self.online_area_id = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.embered = ::std::option::Option::None;
+        self.in_coop = ::std::option::Option::None;
+        self.covenant = ::std::option::Option::None;
+        self.ng_cycle = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestUpdatePlayerStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestUpdatePlayerStatusResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestUpdatePlayerStatusResponse {
+    fn default() -> &'a RequestUpdatePlayerStatusResponse {
+        <RequestUpdatePlayerStatusResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestUpdatePlayerStatusResponse {
+    pub fn new() -> RequestUpdatePlayerStatusResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestUpdatePlayerStatusResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestUpdatePlayerStatusResponse {
+        RequestUpdatePlayerStatusResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestUpdatePlayerStatusResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestUpdatePlayerStatusResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestUpdatePlayerStatusResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestUpdatePlayerStatusResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestUpdatePlayerStatusResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetBreakInTargetList {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetBreakInTargetList {
+    fn default() -> &'a RequestGetBreakInTargetList {
+        <RequestGetBreakInTargetList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetBreakInTargetList {
+    pub fn new() -> RequestGetBreakInTargetList {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 2;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+
+    // optional uint32 max_entries = 3;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetBreakInTargetList {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.max_entries {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetBreakInTargetList {
+        RequestGetBreakInTargetList::new()
+    }
+
+    fn default_instance() -> &'static RequestGetBreakInTargetList {
+        static instance: ::protobuf::rt::LazyV2<RequestGetBreakInTargetList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetBreakInTargetList::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetBreakInTargetList {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetBreakInTargetList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BreakInTargetData {
+    // message fields
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BreakInTargetData {
+    fn default() -> &'a BreakInTargetData {
+        <BreakInTargetData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BreakInTargetData {
+    pub fn new() -> BreakInTargetData {
+        ::std::default::Default::default()
+    }
+
+    // required string steam_id = 1;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required .MatchingParameter matching_parameter = 2;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+}
+
+impl ::protobuf::Message for BreakInTargetData {
+    fn is_initialized(&self) -> bool {
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BreakInTargetData {
+        BreakInTargetData::new()
+    }
+
+    fn default_instance() -> &'static BreakInTargetData {
+        static instance: ::protobuf::rt::LazyV2<BreakInTargetData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BreakInTargetData::new)
+    }
+}
+
+impl ::protobuf::Clear for BreakInTargetData {
+    fn clear(&mut self) {
+        self.steam_id.clear();
+        self.matching_parameter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BreakInTargetData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetBreakInTargetListResponse {
+    // message fields
+    pub targets: ::protobuf::RepeatedField<BreakInTargetData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetBreakInTargetListResponse {
+    fn default() -> &'a RequestGetBreakInTargetListResponse {
+        <RequestGetBreakInTargetListResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetBreakInTargetListResponse {
+    pub fn new() -> RequestGetBreakInTargetListResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .BreakInTargetData targets = 1;
+
+
+    pub fn get_targets(&self) -> &[BreakInTargetData] {
+        &self.targets
+    }
+    pub fn clear_targets(&mut self) {
+        self.targets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_targets(&mut self, v: ::protobuf::RepeatedField<BreakInTargetData>) {
+        self.targets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_targets(&mut self) -> &mut ::protobuf::RepeatedField<BreakInTargetData> {
+        &mut self.targets
+    }
+
+    // Take field
+    pub fn take_targets(&mut self) -> ::protobuf::RepeatedField<BreakInTargetData> {
+        ::std::mem::replace(&mut self.targets, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetBreakInTargetListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.targets {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.targets)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.targets {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.targets {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetBreakInTargetListResponse {
+        RequestGetBreakInTargetListResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetBreakInTargetListResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetBreakInTargetListResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetBreakInTargetListResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetBreakInTargetListResponse {
+    fn clear(&mut self) {
+        self.targets.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetBreakInTargetListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestBreakInTarget {
+    // message fields
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestBreakInTarget {
+    fn default() -> &'a RequestBreakInTarget {
+        <RequestBreakInTarget as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestBreakInTarget {
+    pub fn new() -> RequestBreakInTarget {
+        ::std::default::Default::default()
+    }
+
+    // required string steam_id = 1;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RequestBreakInTarget {
+    fn is_initialized(&self) -> bool {
+        if self.steam_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestBreakInTarget {
+        RequestBreakInTarget::new()
+    }
+
+    fn default_instance() -> &'static RequestBreakInTarget {
+        static instance: ::protobuf::rt::LazyV2<RequestBreakInTarget> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestBreakInTarget::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestBreakInTarget {
+    fn clear(&mut self) {
+        self.steam_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestBreakInTarget {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestBreakInTargetResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestBreakInTargetResponse {
+    fn default() -> &'a RequestBreakInTargetResponse {
+        <RequestBreakInTargetResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestBreakInTargetResponse {
+    pub fn new() -> RequestBreakInTargetResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestBreakInTargetResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestBreakInTargetResponse {
+        RequestBreakInTargetResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestBreakInTargetResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestBreakInTargetResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestBreakInTargetResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestBreakInTargetResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestBreakInTargetResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushRequestBreakIn {
+    // message fields
+    invader_steam_id: ::protobuf::SingularField<::std::string::String>,
+    invader_character_name: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushRequestBreakIn {
+    fn default() -> &'a PushRequestBreakIn {
+        <PushRequestBreakIn as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushRequestBreakIn {
+    pub fn new() -> PushRequestBreakIn {
+        ::std::default::Default::default()
+    }
+
+    // required string invader_steam_id = 1;
+
+
+    pub fn get_invader_steam_id(&self) -> &str {
+        match self.invader_steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_invader_steam_id(&mut self) {
+        self.invader_steam_id.clear();
+    }
+
+    pub fn has_invader_steam_id(&self) -> bool {
+        self.invader_steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invader_steam_id(&mut self, v: ::std::string::String) {
+        self.invader_steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_invader_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.invader_steam_id.is_none() {
+            self.invader_steam_id.set_default();
+        }
+        self.invader_steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_invader_steam_id(&mut self) -> ::std::string::String {
+        self.invader_steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string invader_character_name = 2;
+
+
+    pub fn get_invader_character_name(&self) -> &str {
+        match self.invader_character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_invader_character_name(&mut self) {
+        self.invader_character_name.clear();
+    }
+
+    pub fn has_invader_character_name(&self) -> bool {
+        self.invader_character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invader_character_name(&mut self, v: ::std::string::String) {
+        self.invader_character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_invader_character_name(&mut self) -> &mut ::std::string::String {
+        if self.invader_character_name.is_none() {
+            self.invader_character_name.set_default();
+        }
+        self.invader_character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_invader_character_name(&mut self) -> ::std::string::String {
+        self.invader_character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PushRequestBreakIn {
+    fn is_initialized(&self) -> bool {
+        if self.invader_steam_id.is_none() {
+            return false;
+        }
+        if self.invader_character_name.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.invader_steam_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.invader_character_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.invader_steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.invader_character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.invader_steam_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.invader_character_name.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushRequestBreakIn {
+        PushRequestBreakIn::new()
+    }
+
+    fn default_instance() -> &'static PushRequestBreakIn {
+        static instance: ::protobuf::rt::LazyV2<PushRequestBreakIn> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushRequestBreakIn::new)
+    }
+}
+
+impl ::protobuf::Clear for PushRequestBreakIn {
+    fn clear(&mut self) {
+        self.invader_steam_id.clear();
+        self.invader_character_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushRequestBreakIn {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRegisterCovenant {
+    // message fields
+    covenant: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRegisterCovenant {
+    fn default() -> &'a RequestRegisterCovenant {
+        <RequestRegisterCovenant as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRegisterCovenant {
+    pub fn new() -> RequestRegisterCovenant {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 covenant = 1;
+
+
+    pub fn get_covenant(&self) -> u32 {
+        self.covenant.unwrap_or(0)
+    }
+    pub fn clear_covenant(&mut self) {
+        self.covenant = ::std::option::Option::None;
+    }
+
+    pub fn has_covenant(&self) -> bool {
+        self.covenant.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_covenant(&mut self, v: u32) {
+        self.covenant = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestRegisterCovenant {
+    fn is_initialized(&self) -> bool {
+        if self.covenant.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.covenant = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.covenant {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.covenant {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRegisterCovenant {
+        RequestRegisterCovenant::new()
+    }
+
+    fn default_instance() -> &'static RequestRegisterCovenant {
+        static instance: ::protobuf::rt::LazyV2<RequestRegisterCovenant> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRegisterCovenant::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRegisterCovenant {
+    fn clear(&mut self) {
+        self.covenant = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRegisterCovenant {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRegisterCovenantResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRegisterCovenantResponse {
+    fn default() -> &'a RequestRegisterCovenantResponse {
+        <RequestRegisterCovenantResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRegisterCovenantResponse {
+    pub fn new() -> RequestRegisterCovenantResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestRegisterCovenantResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRegisterCovenantResponse {
+        RequestRegisterCovenantResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestRegisterCovenantResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestRegisterCovenantResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRegisterCovenantResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRegisterCovenantResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRegisterCovenantResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestUnregisterCovenant {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestUnregisterCovenant {
+    fn default() -> &'a RequestUnregisterCovenant {
+        <RequestUnregisterCovenant as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestUnregisterCovenant {
+    pub fn new() -> RequestUnregisterCovenant {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestUnregisterCovenant {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestUnregisterCovenant {
+        RequestUnregisterCovenant::new()
+    }
+
+    fn default_instance() -> &'static RequestUnregisterCovenant {
+        static instance: ::protobuf::rt::LazyV2<RequestUnregisterCovenant> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestUnregisterCovenant::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestUnregisterCovenant {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestUnregisterCovenant {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestUnregisterCovenantResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestUnregisterCovenantResponse {
+    fn default() -> &'a RequestUnregisterCovenantResponse {
+        <RequestUnregisterCovenantResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestUnregisterCovenantResponse {
+    pub fn new() -> RequestUnregisterCovenantResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestUnregisterCovenantResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestUnregisterCovenantResponse {
+        RequestUnregisterCovenantResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestUnregisterCovenantResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestUnregisterCovenantResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestUnregisterCovenantResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestUnregisterCovenantResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestUnregisterCovenantResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushRequestVisit {
+    // message fields
+    covenant: ::std::option::Option<u32>,
+    host_steam_id: ::protobuf::SingularField<::std::string::String>,
+    host_character_name: ::protobuf::SingularField<::std::string::String>,
+    online_area_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushRequestVisit {
+    fn default() -> &'a PushRequestVisit {
+        <PushRequestVisit as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushRequestVisit {
+    pub fn new() -> PushRequestVisit {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 covenant = 1;
+
+
+    pub fn get_covenant(&self) -> u32 {
+        self.covenant.unwrap_or(0)
+    }
+    pub fn clear_covenant(&mut self) {
+        self.covenant = ::std::option::Option::None;
+    }
+
+    pub fn has_covenant(&self) -> bool {
+        self.covenant.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_covenant(&mut self, v: u32) {
+        self.covenant = ::std::option::Option::Some(v);
+    }
+
+    // required string host_steam_id = 2;
+
+
+    pub fn get_host_steam_id(&self) -> &str {
+        match self.host_steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_host_steam_id(&mut self) {
+        self.host_steam_id.clear();
+    }
+
+    pub fn has_host_steam_id(&self) -> bool {
+        self.host_steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host_steam_id(&mut self, v: ::std::string::String) {
+        self.host_steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.host_steam_id.is_none() {
+            self.host_steam_id.set_default();
+        }
+        self.host_steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_host_steam_id(&mut self) -> ::std::string::String {
+        self.host_steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string host_character_name = 3;
+
+
+    pub fn get_host_character_name(&self) -> &str {
+        match self.host_character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_host_character_name(&mut self) {
+        self.host_character_name.clear();
+    }
+
+    pub fn has_host_character_name(&self) -> bool {
+        self.host_character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host_character_name(&mut self, v: ::std::string::String) {
+        self.host_character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host_character_name(&mut self) -> &mut ::std::string::String {
+        if self.host_character_name.is_none() {
+            self.host_character_name.set_default();
+        }
+        self.host_character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_host_character_name(&mut self) -> ::std::string::String {
+        self.host_character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 online_area_id = 4;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for PushRequestVisit {
+    fn is_initialized(&self) -> bool {
+        if self.covenant.is_none() {
+            return false;
+        }
+        if self.host_steam_id.is_none() {
+            return false;
+        }
+        if self.host_character_name.is_none() {
+            return false;
+        }
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.covenant = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.host_steam_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.host_character_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.covenant {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.host_steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.host_character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.covenant {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(ref v) = self.host_steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.host_character_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushRequestVisit {
+        PushRequestVisit::new()
+    }
+
+    fn default_instance() -> &'static PushRequestVisit {
+        static instance: ::protobuf::rt::LazyV2<PushRequestVisit> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushRequestVisit::new)
+    }
+}
+
+impl ::protobuf::Clear for PushRequestVisit {
+    fn clear(&mut self) {
+        self.covenant = ::std::option::Option::None;
+        self.host_steam_id.clear();
+        self.host_character_name.clear();
+        self.online_area_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushRequestVisit {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRegisterQuickMatch {
+    // message fields
+    mode: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRegisterQuickMatch {
+    fn default() -> &'a RequestRegisterQuickMatch {
+        <RequestRegisterQuickMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRegisterQuickMatch {
+    pub fn new() -> RequestRegisterQuickMatch {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 mode = 1;
+
+
+    pub fn get_mode(&self) -> u32 {
+        self.mode.unwrap_or(0)
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = ::std::option::Option::None;
+    }
+
+    pub fn has_mode(&self) -> bool {
+        self.mode.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: u32) {
+        self.mode = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 2;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 3;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+}
+
+impl ::protobuf::Message for RequestRegisterQuickMatch {
+    fn is_initialized(&self) -> bool {
+        if self.mode.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mode = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.mode {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.mode {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRegisterQuickMatch {
+        RequestRegisterQuickMatch::new()
+    }
+
+    fn default_instance() -> &'static RequestRegisterQuickMatch {
+        static instance: ::protobuf::rt::LazyV2<RequestRegisterQuickMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRegisterQuickMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRegisterQuickMatch {
+    fn clear(&mut self) {
+        self.mode = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRegisterQuickMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRegisterQuickMatchResponse {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRegisterQuickMatchResponse {
+    fn default() -> &'a RequestRegisterQuickMatchResponse {
+        <RequestRegisterQuickMatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRegisterQuickMatchResponse {
+    pub fn new() -> RequestRegisterQuickMatchResponse {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestRegisterQuickMatchResponse {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRegisterQuickMatchResponse {
+        RequestRegisterQuickMatchResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestRegisterQuickMatchResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestRegisterQuickMatchResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRegisterQuickMatchResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRegisterQuickMatchResponse {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRegisterQuickMatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestUnregisterQuickMatch {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestUnregisterQuickMatch {
+    fn default() -> &'a RequestUnregisterQuickMatch {
+        <RequestUnregisterQuickMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestUnregisterQuickMatch {
+    pub fn new() -> RequestUnregisterQuickMatch {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestUnregisterQuickMatch {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestUnregisterQuickMatch {
+        RequestUnregisterQuickMatch::new()
+    }
+
+    fn default_instance() -> &'static RequestUnregisterQuickMatch {
+        static instance: ::protobuf::rt::LazyV2<RequestUnregisterQuickMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestUnregisterQuickMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestUnregisterQuickMatch {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestUnregisterQuickMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestUnregisterQuickMatchResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestUnregisterQuickMatchResponse {
+    fn default() -> &'a RequestUnregisterQuickMatchResponse {
+        <RequestUnregisterQuickMatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestUnregisterQuickMatchResponse {
+    pub fn new() -> RequestUnregisterQuickMatchResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestUnregisterQuickMatchResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestUnregisterQuickMatchResponse {
+        RequestUnregisterQuickMatchResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestUnregisterQuickMatchResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestUnregisterQuickMatchResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestUnregisterQuickMatchResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestUnregisterQuickMatchResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestUnregisterQuickMatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestSearchQuickMatch {
+    // message fields
+    mode: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestSearchQuickMatch {
+    fn default() -> &'a RequestSearchQuickMatch {
+        <RequestSearchQuickMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestSearchQuickMatch {
+    pub fn new() -> RequestSearchQuickMatch {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 mode = 1;
+
+
+    pub fn get_mode(&self) -> u32 {
+        self.mode.unwrap_or(0)
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = ::std::option::Option::None;
+    }
+
+    pub fn has_mode(&self) -> bool {
+        self.mode.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: u32) {
+        self.mode = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 2;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 3;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+
+    // optional uint32 max_entries = 4;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestSearchQuickMatch {
+    fn is_initialized(&self) -> bool {
+        if self.mode.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mode = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.mode {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.mode {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.max_entries {
+            os.write_uint32(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestSearchQuickMatch {
+        RequestSearchQuickMatch::new()
+    }
+
+    fn default_instance() -> &'static RequestSearchQuickMatch {
+        static instance: ::protobuf::rt::LazyV2<RequestSearchQuickMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestSearchQuickMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestSearchQuickMatch {
+    fn clear(&mut self) {
+        self.mode = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestSearchQuickMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct QuickMatchData {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    host_steam_id: ::protobuf::SingularField<::std::string::String>,
+    host_character_name: ::protobuf::SingularField<::std::string::String>,
+    mode: ::std::option::Option<u32>,
+    map_id: ::std::option::Option<u32>,
+    players: ::std::option::Option<u32>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuickMatchData {
+    fn default() -> &'a QuickMatchData {
+        <QuickMatchData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuickMatchData {
+    pub fn new() -> QuickMatchData {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+
+    // required string host_steam_id = 2;
+
+
+    pub fn get_host_steam_id(&self) -> &str {
+        match self.host_steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_host_steam_id(&mut self) {
+        self.host_steam_id.clear();
+    }
+
+    pub fn has_host_steam_id(&self) -> bool {
+        self.host_steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host_steam_id(&mut self, v: ::std::string::String) {
+        self.host_steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.host_steam_id.is_none() {
+            self.host_steam_id.set_default();
+        }
+        self.host_steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_host_steam_id(&mut self) -> ::std::string::String {
+        self.host_steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string host_character_name = 3;
+
+
+    pub fn get_host_character_name(&self) -> &str {
+        match self.host_character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_host_character_name(&mut self) {
+        self.host_character_name.clear();
+    }
+
+    pub fn has_host_character_name(&self) -> bool {
+        self.host_character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_host_character_name(&mut self, v: ::std::string::String) {
+        self.host_character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_host_character_name(&mut self) -> &mut ::std::string::String {
+        if self.host_character_name.is_none() {
+            self.host_character_name.set_default();
+        }
+        self.host_character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_host_character_name(&mut self) -> ::std::string::String {
+        self.host_character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 mode = 4;
+
+
+    pub fn get_mode(&self) -> u32 {
+        self.mode.unwrap_or(0)
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = ::std::option::Option::None;
+    }
+
+    pub fn has_mode(&self) -> bool {
+        self.mode.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: u32) {
+        self.mode = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 map_id = 5;
+
+
+    pub fn get_map_id(&self) -> u32 {
+        self.map_id.unwrap_or(0)
+    }
+    pub fn clear_map_id(&mut self) {
+        self.map_id = ::std::option::Option::None;
+    }
+
+    pub fn has_map_id(&self) -> bool {
+        self.map_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_id(&mut self, v: u32) {
+        self.map_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 players = 6;
+
+
+    pub fn get_players(&self) -> u32 {
+        self.players.unwrap_or(0)
+    }
+    pub fn clear_players(&mut self) {
+        self.players = ::std::option::Option::None;
+    }
+
+    pub fn has_players(&self) -> bool {
+        self.players.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_players(&mut self, v: u32) {
+        self.players = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 7;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+}
+
+impl ::protobuf::Message for QuickMatchData {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        if self.host_steam_id.is_none() {
+            return false;
+        }
+        if self.host_character_name.is_none() {
+            return false;
+        }
+        if self.mode.is_none() {
+            return false;
+        }
+        if self.map_id.is_none() {
+            return false;
+        }
+        if self.players.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.host_steam_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.host_character_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mode = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.map_id = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.players = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.host_steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.host_character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.mode {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.map_id {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.players {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.host_steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.host_character_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.mode {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.map_id {
+            os.write_uint32(5, v)?;
+        }
+        if let Some(v) = self.players {
+            os.write_uint32(6, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QuickMatchData {
+        QuickMatchData::new()
+    }
+
+    fn default_instance() -> &'static QuickMatchData {
+        static instance: ::protobuf::rt::LazyV2<QuickMatchData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuickMatchData::new)
+    }
+}
+
+impl ::protobuf::Clear for QuickMatchData {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.host_steam_id.clear();
+        self.host_character_name.clear();
+        self.mode = ::std::option::Option::None;
+        self.map_id = ::std::option::Option::None;
+        self.players = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuickMatchData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestSearchQuickMatchResponse {
+    // message fields
+    pub matches: ::protobuf::RepeatedField<QuickMatchData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestSearchQuickMatchResponse {
+    fn default() -> &'a RequestSearchQuickMatchResponse {
+        <RequestSearchQuickMatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestSearchQuickMatchResponse {
+    pub fn new() -> RequestSearchQuickMatchResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .QuickMatchData matches = 1;
+
+
+    pub fn get_matches(&self) -> &[QuickMatchData] {
+        &self.matches
+    }
+    pub fn clear_matches(&mut self) {
+        self.matches.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matches(&mut self, v: ::protobuf::RepeatedField<QuickMatchData>) {
+        self.matches = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_matches(&mut self) -> &mut ::protobuf::RepeatedField<QuickMatchData> {
+        &mut self.matches
+    }
+
+    // Take field
+    pub fn take_matches(&mut self) -> ::protobuf::RepeatedField<QuickMatchData> {
+        ::std::mem::replace(&mut self.matches, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestSearchQuickMatchResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.matches {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.matches)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.matches {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.matches {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestSearchQuickMatchResponse {
+        RequestSearchQuickMatchResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestSearchQuickMatchResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestSearchQuickMatchResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestSearchQuickMatchResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestSearchQuickMatchResponse {
+    fn clear(&mut self) {
+        self.matches.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestSearchQuickMatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestJoinQuickMatch {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestJoinQuickMatch {
+    fn default() -> &'a RequestJoinQuickMatch {
+        <RequestJoinQuickMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestJoinQuickMatch {
+    pub fn new() -> RequestJoinQuickMatch {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+
+    // required .MatchingParameter matching_parameter = 2;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+}
+
+impl ::protobuf::Message for RequestJoinQuickMatch {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestJoinQuickMatch {
+        RequestJoinQuickMatch::new()
+    }
+
+    fn default_instance() -> &'static RequestJoinQuickMatch {
+        static instance: ::protobuf::rt::LazyV2<RequestJoinQuickMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestJoinQuickMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestJoinQuickMatch {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.matching_parameter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestJoinQuickMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestJoinQuickMatchResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestJoinQuickMatchResponse {
+    fn default() -> &'a RequestJoinQuickMatchResponse {
+        <RequestJoinQuickMatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestJoinQuickMatchResponse {
+    pub fn new() -> RequestJoinQuickMatchResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestJoinQuickMatchResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestJoinQuickMatchResponse {
+        RequestJoinQuickMatchResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestJoinQuickMatchResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestJoinQuickMatchResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestJoinQuickMatchResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestJoinQuickMatchResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestJoinQuickMatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestAcceptQuickMatch {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    accepted: ::std::option::Option<bool>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestAcceptQuickMatch {
+    fn default() -> &'a RequestAcceptQuickMatch {
+        <RequestAcceptQuickMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestAcceptQuickMatch {
+    pub fn new() -> RequestAcceptQuickMatch {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required bool accepted = 3;
+
+
+    pub fn get_accepted(&self) -> bool {
+        self.accepted.unwrap_or(false)
+    }
+    pub fn clear_accepted(&mut self) {
+        self.accepted = ::std::option::Option::None;
+    }
+
+    pub fn has_accepted(&self) -> bool {
+        self.accepted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accepted(&mut self, v: bool) {
+        self.accepted = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestAcceptQuickMatch {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.accepted.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.accepted = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.accepted {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.accepted {
+            os.write_bool(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestAcceptQuickMatch {
+        RequestAcceptQuickMatch::new()
+    }
+
+    fn default_instance() -> &'static RequestAcceptQuickMatch {
+        static instance: ::protobuf::rt::LazyV2<RequestAcceptQuickMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestAcceptQuickMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestAcceptQuickMatch {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.steam_id.clear();
+        self.accepted = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestAcceptQuickMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestAcceptQuickMatchResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestAcceptQuickMatchResponse {
+    fn default() -> &'a RequestAcceptQuickMatchResponse {
+        <RequestAcceptQuickMatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestAcceptQuickMatchResponse {
+    pub fn new() -> RequestAcceptQuickMatchResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestAcceptQuickMatchResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestAcceptQuickMatchResponse {
+        RequestAcceptQuickMatchResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestAcceptQuickMatchResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestAcceptQuickMatchResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestAcceptQuickMatchResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestAcceptQuickMatchResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestAcceptQuickMatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestStartQuickMatch {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestStartQuickMatch {
+    fn default() -> &'a RequestStartQuickMatch {
+        <RequestStartQuickMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestStartQuickMatch {
+    pub fn new() -> RequestStartQuickMatch {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestStartQuickMatch {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestStartQuickMatch {
+        RequestStartQuickMatch::new()
+    }
+
+    fn default_instance() -> &'static RequestStartQuickMatch {
+        static instance: ::protobuf::rt::LazyV2<RequestStartQuickMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestStartQuickMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestStartQuickMatch {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestStartQuickMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestStartQuickMatchResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestStartQuickMatchResponse {
+    fn default() -> &'a RequestStartQuickMatchResponse {
+        <RequestStartQuickMatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestStartQuickMatchResponse {
+    pub fn new() -> RequestStartQuickMatchResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestStartQuickMatchResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestStartQuickMatchResponse {
+        RequestStartQuickMatchResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestStartQuickMatchResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestStartQuickMatchResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestStartQuickMatchResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestStartQuickMatchResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestStartQuickMatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestSendQuickMatchResult {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    result: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestSendQuickMatchResult {
+    fn default() -> &'a RequestSendQuickMatchResult {
+        <RequestSendQuickMatchResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestSendQuickMatchResult {
+    pub fn new() -> RequestSendQuickMatchResult {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 result = 2;
+
+
+    pub fn get_result(&self) -> u32 {
+        self.result.unwrap_or(0)
+    }
+    pub fn clear_result(&mut self) {
+        self.result = ::std::option::Option::None;
+    }
+
+    pub fn has_result(&self) -> bool {
+        self.result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: u32) {
+        self.result = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestSendQuickMatchResult {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        if self.result.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.result = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.result {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.result {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestSendQuickMatchResult {
+        RequestSendQuickMatchResult::new()
+    }
+
+    fn default_instance() -> &'static RequestSendQuickMatchResult {
+        static instance: ::protobuf::rt::LazyV2<RequestSendQuickMatchResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestSendQuickMatchResult::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestSendQuickMatchResult {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.result = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestSendQuickMatchResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestSendQuickMatchResultResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestSendQuickMatchResultResponse {
+    fn default() -> &'a RequestSendQuickMatchResultResponse {
+        <RequestSendQuickMatchResultResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestSendQuickMatchResultResponse {
+    pub fn new() -> RequestSendQuickMatchResultResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestSendQuickMatchResultResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestSendQuickMatchResultResponse {
+        RequestSendQuickMatchResultResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestSendQuickMatchResultResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestSendQuickMatchResultResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestSendQuickMatchResultResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestSendQuickMatchResultResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestSendQuickMatchResultResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushRequestJoinQuickMatch {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    character_name: ::protobuf::SingularField<::std::string::String>,
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushRequestJoinQuickMatch {
+    fn default() -> &'a PushRequestJoinQuickMatch {
+        <PushRequestJoinQuickMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushRequestJoinQuickMatch {
+    pub fn new() -> PushRequestJoinQuickMatch {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string character_name = 3;
+
+
+    pub fn get_character_name(&self) -> &str {
+        match self.character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_character_name(&mut self) {
+        self.character_name.clear();
+    }
+
+    pub fn has_character_name(&self) -> bool {
+        self.character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_character_name(&mut self, v: ::std::string::String) {
+        self.character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_character_name(&mut self) -> &mut ::std::string::String {
+        if self.character_name.is_none() {
+            self.character_name.set_default();
+        }
+        self.character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_character_name(&mut self) -> ::std::string::String {
+        self.character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required .MatchingParameter matching_parameter = 4;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+}
+
+impl ::protobuf::Message for PushRequestJoinQuickMatch {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.character_name.is_none() {
+            return false;
+        }
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.character_name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushRequestJoinQuickMatch {
+        PushRequestJoinQuickMatch::new()
+    }
+
+    fn default_instance() -> &'static PushRequestJoinQuickMatch {
+        static instance: ::protobuf::rt::LazyV2<PushRequestJoinQuickMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushRequestJoinQuickMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for PushRequestJoinQuickMatch {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.steam_id.clear();
+        self.character_name.clear();
+        self.matching_parameter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushRequestJoinQuickMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushJoinQuickMatchResult {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    accepted: ::std::option::Option<bool>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushJoinQuickMatchResult {
+    fn default() -> &'a PushJoinQuickMatchResult {
+        <PushJoinQuickMatchResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushJoinQuickMatchResult {
+    pub fn new() -> PushJoinQuickMatchResult {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+
+    // required bool accepted = 2;
+
+
+    pub fn get_accepted(&self) -> bool {
+        self.accepted.unwrap_or(false)
+    }
+    pub fn clear_accepted(&mut self) {
+        self.accepted = ::std::option::Option::None;
+    }
+
+    pub fn has_accepted(&self) -> bool {
+        self.accepted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accepted(&mut self, v: bool) {
+        self.accepted = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for PushJoinQuickMatchResult {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        if self.accepted.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.accepted = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.accepted {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.accepted {
+            os.write_bool(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushJoinQuickMatchResult {
+        PushJoinQuickMatchResult::new()
+    }
+
+    fn default_instance() -> &'static PushJoinQuickMatchResult {
+        static instance: ::protobuf::rt::LazyV2<PushJoinQuickMatchResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushJoinQuickMatchResult::new)
+    }
+}
+
+impl ::protobuf::Clear for PushJoinQuickMatchResult {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.accepted = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushJoinQuickMatchResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushStartQuickMatch {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    pub steam_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushStartQuickMatch {
+    fn default() -> &'a PushStartQuickMatch {
+        <PushStartQuickMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushStartQuickMatch {
+    pub fn new() -> PushStartQuickMatch {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+
+    // repeated string steam_ids = 2;
+
+
+    pub fn get_steam_ids(&self) -> &[::std::string::String] {
+        &self.steam_ids
+    }
+    pub fn clear_steam_ids(&mut self) {
+        self.steam_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.steam_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_steam_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.steam_ids
+    }
+
+    // Take field
+    pub fn take_steam_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.steam_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PushStartQuickMatch {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.steam_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.steam_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        for v in &self.steam_ids {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushStartQuickMatch {
+        PushStartQuickMatch::new()
+    }
+
+    fn default_instance() -> &'static PushStartQuickMatch {
+        static instance: ::protobuf::rt::LazyV2<PushStartQuickMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushStartQuickMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for PushStartQuickMatch {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.steam_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushStartQuickMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushQuickMatchDissolved {
+    // message fields
+    match_id: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushQuickMatchDissolved {
+    fn default() -> &'a PushQuickMatchDissolved {
+        <PushQuickMatchDissolved as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushQuickMatchDissolved {
+    pub fn new() -> PushQuickMatchDissolved {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 match_id = 1;
+
+
+    pub fn get_match_id(&self) -> u64 {
+        self.match_id.unwrap_or(0)
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = ::std::option::Option::None;
+    }
+
+    pub fn has_match_id(&self) -> bool {
+        self.match_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u64) {
+        self.match_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for PushQuickMatchDissolved {
+    fn is_initialized(&self) -> bool {
+        if self.match_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.match_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.match_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.match_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PushQuickMatchDissolved {
+        PushQuickMatchDissolved::new()
+    }
+
+    fn default_instance() -> &'static PushQuickMatchDissolved {
+        static instance: ::protobuf::rt::LazyV2<PushQuickMatchDissolved> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushQuickMatchDissolved::new)
+    }
+}
+
+impl ::protobuf::Clear for PushQuickMatchDissolved {
+    fn clear(&mut self) {
+        self.match_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PushQuickMatchDissolved {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetArenaLadder {
+    // message fields
+    mode: ::std::option::Option<u32>,
+    offset: ::std::option::Option<u32>,
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetArenaLadder {
+    fn default() -> &'a RequestGetArenaLadder {
+        <RequestGetArenaLadder as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetArenaLadder {
+    pub fn new() -> RequestGetArenaLadder {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 mode = 1;
+
+
+    pub fn get_mode(&self) -> u32 {
+        self.mode.unwrap_or(0)
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = ::std::option::Option::None;
+    }
+
+    pub fn has_mode(&self) -> bool {
+        self.mode.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: u32) {
+        self.mode = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 offset = 2;
+
+
+    pub fn get_offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = ::std::option::Option::None;
+    }
+
+    pub fn has_offset(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u32) {
+        self.offset = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 max_entries = 3;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetArenaLadder {
+    fn is_initialized(&self) -> bool {
+        if self.mode.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mode = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.offset = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.mode {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.offset {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.mode {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.offset {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.max_entries {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetArenaLadder {
+        RequestGetArenaLadder::new()
+    }
+
+    fn default_instance() -> &'static RequestGetArenaLadder {
+        static instance: ::protobuf::rt::LazyV2<RequestGetArenaLadder> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetArenaLadder::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetArenaLadder {
+    fn clear(&mut self) {
+        self.mode = ::std::option::Option::None;
+        self.offset = ::std::option::Option::None;
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetArenaLadder {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ArenaLadderEntry {
+    // message fields
+    rank: ::std::option::Option<u32>,
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    character_name: ::protobuf::SingularField<::std::string::String>,
+    rating: ::std::option::Option<u32>,
+    wins: ::std::option::Option<u32>,
+    losses: ::std::option::Option<u32>,
+    draws: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ArenaLadderEntry {
+    fn default() -> &'a ArenaLadderEntry {
+        <ArenaLadderEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ArenaLadderEntry {
+    pub fn new() -> ArenaLadderEntry {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 rank = 1;
+
+
+    pub fn get_rank(&self) -> u32 {
+        self.rank.unwrap_or(0)
+    }
+    pub fn clear_rank(&mut self) {
+        self.rank = ::std::option::Option::None;
+    }
+
+    pub fn has_rank(&self) -> bool {
+        self.rank.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rank(&mut self, v: u32) {
+        self.rank = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string character_name = 3;
+
+
+    pub fn get_character_name(&self) -> &str {
+        match self.character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_character_name(&mut self) {
+        self.character_name.clear();
+    }
+
+    pub fn has_character_name(&self) -> bool {
+        self.character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_character_name(&mut self, v: ::std::string::String) {
+        self.character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_character_name(&mut self) -> &mut ::std::string::String {
+        if self.character_name.is_none() {
+            self.character_name.set_default();
+        }
+        self.character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_character_name(&mut self) -> ::std::string::String {
+        self.character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint32 rating = 4;
+
+
+    pub fn get_rating(&self) -> u32 {
+        self.rating.unwrap_or(0)
+    }
+    pub fn clear_rating(&mut self) {
+        self.rating = ::std::option::Option::None;
+    }
+
+    pub fn has_rating(&self) -> bool {
+        self.rating.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rating(&mut self, v: u32) {
+        self.rating = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 wins = 5;
+
+
+    pub fn get_wins(&self) -> u32 {
+        self.wins.unwrap_or(0)
+    }
+    pub fn clear_wins(&mut self) {
+        self.wins = ::std::option::Option::None;
+    }
+
+    pub fn has_wins(&self) -> bool {
+        self.wins.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wins(&mut self, v: u32) {
+        self.wins = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 losses = 6;
+
+
+    pub fn get_losses(&self) -> u32 {
+        self.losses.unwrap_or(0)
+    }
+    pub fn clear_losses(&mut self) {
+        self.losses = ::std::option::Option::None;
+    }
+
+    pub fn has_losses(&self) -> bool {
+        self.losses.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_losses(&mut self, v: u32) {
+        self.losses = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 draws = 7;
+
+
+    pub fn get_draws(&self) -> u32 {
+        self.draws.unwrap_or(0)
+    }
+    pub fn clear_draws(&mut self) {
+        self.draws = ::std::option::Option::None;
+    }
+
+    pub fn has_draws(&self) -> bool {
+        self.draws.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_draws(&mut self, v: u32) {
+        self.draws = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for ArenaLadderEntry {
+    fn is_initialized(&self) -> bool {
+        if self.rank.is_none() {
+            return false;
+        }
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.character_name.is_none() {
+            return false;
+        }
+        if self.rating.is_none() {
+            return false;
+        }
+        if self.wins.is_none() {
+            return false;
+        }
+        if self.losses.is_none() {
+            return false;
+        }
+        if self.draws.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rank = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.character_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rating = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.wins = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.losses = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.draws = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.rank {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.rating {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.wins {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.losses {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.draws {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.rank {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.rating {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.wins {
+            os.write_uint32(5, v)?;
+        }
+        if let Some(v) = self.losses {
+            os.write_uint32(6, v)?;
+        }
+        if let Some(v) = self.draws {
+            os.write_uint32(7, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ArenaLadderEntry {
+        ArenaLadderEntry::new()
+    }
+
+    fn default_instance() -> &'static ArenaLadderEntry {
+        static instance: ::protobuf::rt::LazyV2<ArenaLadderEntry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ArenaLadderEntry::new)
+    }
+}
+
+impl ::protobuf::Clear for ArenaLadderEntry {
+    fn clear(&mut self) {
+        self.rank = ::std::option::Option::None;
+        self.steam_id.clear();
+        self.character_name.clear();
+        self.rating = ::std::option::Option::None;
+        self.wins = ::std::option::Option::None;
+        self.losses = ::std::option::Option::None;
+        self.draws = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ArenaLadderEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetArenaLadderResponse {
+    // message fields
+    pub entries: ::protobuf::RepeatedField<ArenaLadderEntry>,
+    total: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetArenaLadderResponse {
+    fn default() -> &'a RequestGetArenaLadderResponse {
+        <RequestGetArenaLadderResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetArenaLadderResponse {
+    pub fn new() -> RequestGetArenaLadderResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ArenaLadderEntry entries = 1;
+
+
+    pub fn get_entries(&self) -> &[ArenaLadderEntry] {
+        &self.entries
+    }
+    pub fn clear_entries(&mut self) {
+        self.entries.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entries(&mut self, v: ::protobuf::RepeatedField<ArenaLadderEntry>) {
+        self.entries = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entries(&mut self) -> &mut ::protobuf::RepeatedField<ArenaLadderEntry> {
+        &mut self.entries
+    }
+
+    // Take field
+    pub fn take_entries(&mut self) -> ::protobuf::RepeatedField<ArenaLadderEntry> {
+        ::std::mem::replace(&mut self.entries, ::protobuf::RepeatedField::new())
+    }
+
+    // required uint32 total = 2;
+
+
+    pub fn get_total(&self) -> u32 {
+        self.total.unwrap_or(0)
+    }
+    pub fn clear_total(&mut self) {
+        self.total = ::std::option::Option::None;
+    }
+
+    pub fn has_total(&self) -> bool {
+        self.total.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u32) {
+        self.total = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetArenaLadderResponse {
+    fn is_initialized(&self) -> bool {
+        if self.total.is_none() {
+            return false;
+        }
+        for v in &self.entries {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entries)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.total = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.total {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.entries {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.total {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetArenaLadderResponse {
+        RequestGetArenaLadderResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetArenaLadderResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetArenaLadderResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetArenaLadderResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetArenaLadderResponse {
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.total = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetArenaLadderResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RankingData {
+    // message fields
+    rank: ::std::option::Option<u32>,
+    steam_id: ::protobuf::SingularField<::std::string::String>,
+    character_name: ::protobuf::SingularField<::std::string::String>,
+    score: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RankingData {
+    fn default() -> &'a RankingData {
+        <RankingData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RankingData {
+    pub fn new() -> RankingData {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 rank = 1;
+
+
+    pub fn get_rank(&self) -> u32 {
+        self.rank.unwrap_or(0)
+    }
+    pub fn clear_rank(&mut self) {
+        self.rank = ::std::option::Option::None;
+    }
+
+    pub fn has_rank(&self) -> bool {
+        self.rank.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rank(&mut self, v: u32) {
+        self.rank = ::std::option::Option::Some(v);
+    }
+
+    // required string steam_id = 2;
+
+
+    pub fn get_steam_id(&self) -> &str {
+        match self.steam_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_steam_id(&mut self) {
+        self.steam_id.clear();
+    }
+
+    pub fn has_steam_id(&self) -> bool {
+        self.steam_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_steam_id(&mut self, v: ::std::string::String) {
+        self.steam_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_steam_id(&mut self) -> &mut ::std::string::String {
+        if self.steam_id.is_none() {
+            self.steam_id.set_default();
+        }
+        self.steam_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_steam_id(&mut self) -> ::std::string::String {
+        self.steam_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required string character_name = 3;
+
+
+    pub fn get_character_name(&self) -> &str {
+        match self.character_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_character_name(&mut self) {
+        self.character_name.clear();
+    }
+
+    pub fn has_character_name(&self) -> bool {
+        self.character_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_character_name(&mut self, v: ::std::string::String) {
+        self.character_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_character_name(&mut self) -> &mut ::std::string::String {
+        if self.character_name.is_none() {
+            self.character_name.set_default();
+        }
+        self.character_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_character_name(&mut self) -> ::std::string::String {
+        self.character_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required uint64 score = 4;
+
+
+    pub fn get_score(&self) -> u64 {
+        self.score.unwrap_or(0)
+    }
+    pub fn clear_score(&mut self) {
+        self.score = ::std::option::Option::None;
+    }
+
+    pub fn has_score(&self) -> bool {
+        self.score.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_score(&mut self, v: u64) {
+        self.score = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RankingData {
+    fn is_initialized(&self) -> bool {
+        if self.rank.is_none() {
+            return false;
+        }
+        if self.steam_id.is_none() {
+            return false;
+        }
+        if self.character_name.is_none() {
+            return false;
+        }
+        if self.score.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rank = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.steam_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.character_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.score = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.rank {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.score {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.rank {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(ref v) = self.steam_id.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.character_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.score {
+            os.write_uint64(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RankingData {
+        RankingData::new()
+    }
+
+    fn default_instance() -> &'static RankingData {
+        static instance: ::protobuf::rt::LazyV2<RankingData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RankingData::new)
+    }
+}
+
+impl ::protobuf::Clear for RankingData {
+    fn clear(&mut self) {
+        self.rank = ::std::option::Option::None;
+        self.steam_id.clear();
+        self.character_name.clear();
+        self.score = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RankingData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRegisterRanking {
+    // message fields
+    board_id: ::std::option::Option<u32>,
+    score: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRegisterRanking {
+    fn default() -> &'a RequestRegisterRanking {
+        <RequestRegisterRanking as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRegisterRanking {
+    pub fn new() -> RequestRegisterRanking {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 board_id = 1;
+
+
+    pub fn get_board_id(&self) -> u32 {
+        self.board_id.unwrap_or(0)
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id = ::std::option::Option::None;
+    }
+
+    pub fn has_board_id(&self) -> bool {
+        self.board_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: u32) {
+        self.board_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint64 score = 2;
+
+
+    pub fn get_score(&self) -> u64 {
+        self.score.unwrap_or(0)
+    }
+    pub fn clear_score(&mut self) {
+        self.score = ::std::option::Option::None;
+    }
+
+    pub fn has_score(&self) -> bool {
+        self.score.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_score(&mut self, v: u64) {
+        self.score = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestRegisterRanking {
+    fn is_initialized(&self) -> bool {
+        if self.board_id.is_none() {
+            return false;
+        }
+        if self.score.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.board_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.score = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.board_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.score {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.board_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.score {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRegisterRanking {
+        RequestRegisterRanking::new()
+    }
+
+    fn default_instance() -> &'static RequestRegisterRanking {
+        static instance: ::protobuf::rt::LazyV2<RequestRegisterRanking> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRegisterRanking::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRegisterRanking {
+    fn clear(&mut self) {
+        self.board_id = ::std::option::Option::None;
+        self.score = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRegisterRanking {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestRegisterRankingResponse {
+    // message fields
+    score: ::std::option::Option<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestRegisterRankingResponse {
+    fn default() -> &'a RequestRegisterRankingResponse {
+        <RequestRegisterRankingResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestRegisterRankingResponse {
+    pub fn new() -> RequestRegisterRankingResponse {
+        ::std::default::Default::default()
+    }
+
+    // required uint64 score = 1;
+
+
+    pub fn get_score(&self) -> u64 {
+        self.score.unwrap_or(0)
+    }
+    pub fn clear_score(&mut self) {
+        self.score = ::std::option::Option::None;
+    }
+
+    pub fn has_score(&self) -> bool {
+        self.score.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_score(&mut self, v: u64) {
+        self.score = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestRegisterRankingResponse {
+    fn is_initialized(&self) -> bool {
+        if self.score.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.score = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.score {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.score {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestRegisterRankingResponse {
+        RequestRegisterRankingResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestRegisterRankingResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestRegisterRankingResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestRegisterRankingResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestRegisterRankingResponse {
+    fn clear(&mut self) {
+        self.score = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestRegisterRankingResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetCurrentRank {
+    // message fields
+    board_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetCurrentRank {
+    fn default() -> &'a RequestGetCurrentRank {
+        <RequestGetCurrentRank as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetCurrentRank {
+    pub fn new() -> RequestGetCurrentRank {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 board_id = 1;
+
+
+    pub fn get_board_id(&self) -> u32 {
+        self.board_id.unwrap_or(0)
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id = ::std::option::Option::None;
+    }
+
+    pub fn has_board_id(&self) -> bool {
+        self.board_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: u32) {
+        self.board_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetCurrentRank {
+    fn is_initialized(&self) -> bool {
+        if self.board_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.board_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.board_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.board_id {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetCurrentRank {
+        RequestGetCurrentRank::new()
+    }
+
+    fn default_instance() -> &'static RequestGetCurrentRank {
+        static instance: ::protobuf::rt::LazyV2<RequestGetCurrentRank> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetCurrentRank::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetCurrentRank {
+    fn clear(&mut self) {
+        self.board_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetCurrentRank {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetCurrentRankResponse {
+    // message fields
+    pub ranking: ::protobuf::SingularPtrField<RankingData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetCurrentRankResponse {
+    fn default() -> &'a RequestGetCurrentRankResponse {
+        <RequestGetCurrentRankResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetCurrentRankResponse {
+    pub fn new() -> RequestGetCurrentRankResponse {
+        ::std::default::Default::default()
+    }
+
+    // optional .RankingData ranking = 1;
+
+
+    pub fn get_ranking(&self) -> &RankingData {
+        self.ranking.as_ref().unwrap_or_else(|| <RankingData as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_ranking(&mut self) {
+        self.ranking.clear();
+    }
+
+    pub fn has_ranking(&self) -> bool {
+        self.ranking.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ranking(&mut self, v: RankingData) {
+        self.ranking = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ranking(&mut self) -> &mut RankingData {
+        if self.ranking.is_none() {
+            self.ranking.set_default();
+        }
+        self.ranking.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ranking(&mut self) -> RankingData {
+        self.ranking.take().unwrap_or_else(|| RankingData::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetCurrentRankResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ranking {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ranking)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.ranking.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.ranking.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetCurrentRankResponse {
+        RequestGetCurrentRankResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetCurrentRankResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetCurrentRankResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetCurrentRankResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetCurrentRankResponse {
+    fn clear(&mut self) {
+        self.ranking.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetCurrentRankResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCountRanking {
+    // message fields
+    board_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCountRanking {
+    fn default() -> &'a RequestCountRanking {
+        <RequestCountRanking as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCountRanking {
+    pub fn new() -> RequestCountRanking {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 board_id = 1;
+
+
+    pub fn get_board_id(&self) -> u32 {
+        self.board_id.unwrap_or(0)
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id = ::std::option::Option::None;
+    }
+
+    pub fn has_board_id(&self) -> bool {
+        self.board_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: u32) {
+        self.board_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestCountRanking {
+    fn is_initialized(&self) -> bool {
+        if self.board_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.board_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.board_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.board_id {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCountRanking {
+        RequestCountRanking::new()
+    }
+
+    fn default_instance() -> &'static RequestCountRanking {
+        static instance: ::protobuf::rt::LazyV2<RequestCountRanking> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCountRanking::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCountRanking {
+    fn clear(&mut self) {
+        self.board_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCountRanking {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCountRankingResponse {
+    // message fields
+    count: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCountRankingResponse {
+    fn default() -> &'a RequestCountRankingResponse {
+        <RequestCountRankingResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCountRankingResponse {
+    pub fn new() -> RequestCountRankingResponse {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 count = 1;
+
+
+    pub fn get_count(&self) -> u32 {
+        self.count.unwrap_or(0)
+    }
+    pub fn clear_count(&mut self) {
+        self.count = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestCountRankingResponse {
+    fn is_initialized(&self) -> bool {
+        if self.count.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.count {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.count {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCountRankingResponse {
+        RequestCountRankingResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestCountRankingResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestCountRankingResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCountRankingResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCountRankingResponse {
+    fn clear(&mut self) {
+        self.count = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCountRankingResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetRankingPage {
+    // message fields
+    board_id: ::std::option::Option<u32>,
+    offset: ::std::option::Option<u32>,
+    max_entries: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetRankingPage {
+    fn default() -> &'a RequestGetRankingPage {
+        <RequestGetRankingPage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetRankingPage {
+    pub fn new() -> RequestGetRankingPage {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 board_id = 1;
+
+
+    pub fn get_board_id(&self) -> u32 {
+        self.board_id.unwrap_or(0)
+    }
+    pub fn clear_board_id(&mut self) {
+        self.board_id = ::std::option::Option::None;
+    }
+
+    pub fn has_board_id(&self) -> bool {
+        self.board_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_board_id(&mut self, v: u32) {
+        self.board_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 offset = 2;
+
+
+    pub fn get_offset(&self) -> u32 {
+        self.offset.unwrap_or(0)
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = ::std::option::Option::None;
+    }
+
+    pub fn has_offset(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u32) {
+        self.offset = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 max_entries = 3;
+
+
+    pub fn get_max_entries(&self) -> u32 {
+        self.max_entries.unwrap_or(0)
+    }
+    pub fn clear_max_entries(&mut self) {
+        self.max_entries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_entries(&self) -> bool {
+        self.max_entries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_entries(&mut self, v: u32) {
+        self.max_entries = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestGetRankingPage {
+    fn is_initialized(&self) -> bool {
+        if self.board_id.is_none() {
+            return false;
+        }
+        if self.offset.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.board_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.offset = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_entries = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.board_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.offset {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_entries {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.board_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.offset {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.max_entries {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetRankingPage {
+        RequestGetRankingPage::new()
+    }
+
+    fn default_instance() -> &'static RequestGetRankingPage {
+        static instance: ::protobuf::rt::LazyV2<RequestGetRankingPage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetRankingPage::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetRankingPage {
+    fn clear(&mut self) {
+        self.board_id = ::std::option::Option::None;
+        self.offset = ::std::option::Option::None;
+        self.max_entries = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetRankingPage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetRankingPageResponse {
+    // message fields
+    pub rankings: ::protobuf::RepeatedField<RankingData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetRankingPageResponse {
+    fn default() -> &'a RequestGetRankingPageResponse {
+        <RequestGetRankingPageResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetRankingPageResponse {
+    pub fn new() -> RequestGetRankingPageResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .RankingData rankings = 1;
+
+
+    pub fn get_rankings(&self) -> &[RankingData] {
+        &self.rankings
+    }
+    pub fn clear_rankings(&mut self) {
+        self.rankings.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rankings(&mut self, v: ::protobuf::RepeatedField<RankingData>) {
+        self.rankings = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rankings(&mut self) -> &mut ::protobuf::RepeatedField<RankingData> {
+        &mut self.rankings
+    }
+
+    // Take field
+    pub fn take_rankings(&mut self) -> ::protobuf::RepeatedField<RankingData> {
+        ::std::mem::replace(&mut self.rankings, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetRankingPageResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rankings {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rankings)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.rankings {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.rankings {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetRankingPageResponse {
+        RequestGetRankingPageResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetRankingPageResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetRankingPageResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetRankingPageResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetRankingPageResponse {
+    fn clear(&mut self) {
+        self.rankings.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetRankingPageResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AreaPopulation {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    players: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AreaPopulation {
+    fn default() -> &'a AreaPopulation {
+        <AreaPopulation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AreaPopulation {
+    pub fn new() -> AreaPopulation {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 players = 2;
+
+
+    pub fn get_players(&self) -> u32 {
+        self.players.unwrap_or(0)
+    }
+    pub fn clear_players(&mut self) {
+        self.players = ::std::option::Option::None;
+    }
+
+    pub fn has_players(&self) -> bool {
+        self.players.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_players(&mut self, v: u32) {
+        self.players = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for AreaPopulation {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.players.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.players = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.players {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.players {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AreaPopulation {
+        AreaPopulation::new()
+    }
+
+    fn default_instance() -> &'static AreaPopulation {
+        static instance: ::protobuf::rt::LazyV2<AreaPopulation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AreaPopulation::new)
+    }
+}
+
+impl ::protobuf::Clear for AreaPopulation {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.players = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AreaPopulation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCountAreaPlayers {
+    // message fields
+    pub online_area_ids: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCountAreaPlayers {
+    fn default() -> &'a RequestCountAreaPlayers {
+        <RequestCountAreaPlayers as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCountAreaPlayers {
+    pub fn new() -> RequestCountAreaPlayers {
+        ::std::default::Default::default()
+    }
+
+    // repeated uint32 online_area_ids = 1;
+
+
+    pub fn get_online_area_ids(&self) -> &[u32] {
+        &self.online_area_ids
+    }
+    pub fn clear_online_area_ids(&mut self) {
+        self.online_area_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_ids(&mut self, v: ::std::vec::Vec<u32>) {
+        self.online_area_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_online_area_ids(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.online_area_ids
+    }
+
+    // Take field
+    pub fn take_online_area_ids(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.online_area_ids, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for RequestCountAreaPlayers {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.online_area_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.online_area_ids {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.online_area_ids {
+            os.write_uint32(1, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCountAreaPlayers {
+        RequestCountAreaPlayers::new()
+    }
+
+    fn default_instance() -> &'static RequestCountAreaPlayers {
+        static instance: ::protobuf::rt::LazyV2<RequestCountAreaPlayers> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCountAreaPlayers::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCountAreaPlayers {
+    fn clear(&mut self) {
+        self.online_area_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCountAreaPlayers {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestCountAreaPlayersResponse {
+    // message fields
+    pub areas: ::protobuf::RepeatedField<AreaPopulation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestCountAreaPlayersResponse {
+    fn default() -> &'a RequestCountAreaPlayersResponse {
+        <RequestCountAreaPlayersResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestCountAreaPlayersResponse {
+    pub fn new() -> RequestCountAreaPlayersResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .AreaPopulation areas = 1;
+
+
+    pub fn get_areas(&self) -> &[AreaPopulation] {
+        &self.areas
+    }
+    pub fn clear_areas(&mut self) {
+        self.areas.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_areas(&mut self, v: ::protobuf::RepeatedField<AreaPopulation>) {
+        self.areas = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_areas(&mut self) -> &mut ::protobuf::RepeatedField<AreaPopulation> {
+        &mut self.areas
+    }
+
+    // Take field
+    pub fn take_areas(&mut self) -> ::protobuf::RepeatedField<AreaPopulation> {
+        ::std::mem::replace(&mut self.areas, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestCountAreaPlayersResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.areas {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.areas)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.areas {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.areas {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestCountAreaPlayersResponse {
+        RequestCountAreaPlayersResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestCountAreaPlayersResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestCountAreaPlayersResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestCountAreaPlayersResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestCountAreaPlayersResponse {
+    fn clear(&mut self) {
+        self.areas.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestCountAreaPlayersResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetRightMatchingArea {
+    // message fields
+    pub matching_parameter: ::protobuf::SingularPtrField<MatchingParameter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetRightMatchingArea {
+    fn default() -> &'a RequestGetRightMatchingArea {
+        <RequestGetRightMatchingArea as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetRightMatchingArea {
+    pub fn new() -> RequestGetRightMatchingArea {
+        ::std::default::Default::default()
+    }
+
+    // required .MatchingParameter matching_parameter = 1;
+
+
+    pub fn get_matching_parameter(&self) -> &MatchingParameter {
+        self.matching_parameter.as_ref().unwrap_or_else(|| <MatchingParameter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_matching_parameter(&mut self) {
+        self.matching_parameter.clear();
+    }
+
+    pub fn has_matching_parameter(&self) -> bool {
+        self.matching_parameter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matching_parameter(&mut self, v: MatchingParameter) {
+        self.matching_parameter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_matching_parameter(&mut self) -> &mut MatchingParameter {
+        if self.matching_parameter.is_none() {
+            self.matching_parameter.set_default();
+        }
+        self.matching_parameter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_matching_parameter(&mut self) -> MatchingParameter {
+        self.matching_parameter.take().unwrap_or_else(|| MatchingParameter::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetRightMatchingArea {
+    fn is_initialized(&self) -> bool {
+        if self.matching_parameter.is_none() {
+            return false;
+        }
+        for v in &self.matching_parameter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.matching_parameter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.matching_parameter.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetRightMatchingArea {
+        RequestGetRightMatchingArea::new()
+    }
+
+    fn default_instance() -> &'static RequestGetRightMatchingArea {
+        static instance: ::protobuf::rt::LazyV2<RequestGetRightMatchingArea> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetRightMatchingArea::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetRightMatchingArea {
+    fn clear(&mut self) {
+        self.matching_parameter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetRightMatchingArea {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestGetRightMatchingAreaResponse {
+    // message fields
+    pub areas: ::protobuf::RepeatedField<AreaPopulation>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestGetRightMatchingAreaResponse {
+    fn default() -> &'a RequestGetRightMatchingAreaResponse {
+        <RequestGetRightMatchingAreaResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestGetRightMatchingAreaResponse {
+    pub fn new() -> RequestGetRightMatchingAreaResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .AreaPopulation areas = 1;
+
+
+    pub fn get_areas(&self) -> &[AreaPopulation] {
+        &self.areas
+    }
+    pub fn clear_areas(&mut self) {
+        self.areas.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_areas(&mut self, v: ::protobuf::RepeatedField<AreaPopulation>) {
+        self.areas = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_areas(&mut self) -> &mut ::protobuf::RepeatedField<AreaPopulation> {
+        &mut self.areas
+    }
+
+    // Take field
+    pub fn take_areas(&mut self) -> ::protobuf::RepeatedField<AreaPopulation> {
+        ::std::mem::replace(&mut self.areas, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RequestGetRightMatchingAreaResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.areas {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.areas)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.areas {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.areas {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestGetRightMatchingAreaResponse {
+        RequestGetRightMatchingAreaResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestGetRightMatchingAreaResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestGetRightMatchingAreaResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestGetRightMatchingAreaResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestGetRightMatchingAreaResponse {
+    fn clear(&mut self) {
+        self.areas.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestGetRightMatchingAreaResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyDeath {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyDeath {
+    fn default() -> &'a RequestNotifyDeath {
+        <RequestNotifyDeath as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyDeath {
+    pub fn new() -> RequestNotifyDeath {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyDeath {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyDeath {
+        RequestNotifyDeath::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyDeath {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyDeath> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyDeath::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyDeath {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyDeath {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyDeathResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyDeathResponse {
+    fn default() -> &'a RequestNotifyDeathResponse {
+        <RequestNotifyDeathResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyDeathResponse {
+    pub fn new() -> RequestNotifyDeathResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyDeathResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyDeathResponse {
+        RequestNotifyDeathResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyDeathResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyDeathResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyDeathResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyDeathResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyDeathResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyKillEnemy {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    enemy_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyKillEnemy {
+    fn default() -> &'a RequestNotifyKillEnemy {
+        <RequestNotifyKillEnemy as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyKillEnemy {
+    pub fn new() -> RequestNotifyKillEnemy {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 enemy_id = 2;
+
+
+    pub fn get_enemy_id(&self) -> u32 {
+        self.enemy_id.unwrap_or(0)
+    }
+    pub fn clear_enemy_id(&mut self) {
+        self.enemy_id = ::std::option::Option::None;
+    }
+
+    pub fn has_enemy_id(&self) -> bool {
+        self.enemy_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enemy_id(&mut self, v: u32) {
+        self.enemy_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyKillEnemy {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.enemy_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.enemy_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.enemy_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.enemy_id {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyKillEnemy {
+        RequestNotifyKillEnemy::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyKillEnemy {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyKillEnemy> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyKillEnemy::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyKillEnemy {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.enemy_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyKillEnemy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyKillEnemyResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyKillEnemyResponse {
+    fn default() -> &'a RequestNotifyKillEnemyResponse {
+        <RequestNotifyKillEnemyResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyKillEnemyResponse {
+    pub fn new() -> RequestNotifyKillEnemyResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyKillEnemyResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyKillEnemyResponse {
+        RequestNotifyKillEnemyResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyKillEnemyResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyKillEnemyResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyKillEnemyResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyKillEnemyResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyKillEnemyResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyKillBoss {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    boss_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyKillBoss {
+    fn default() -> &'a RequestNotifyKillBoss {
+        <RequestNotifyKillBoss as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyKillBoss {
+    pub fn new() -> RequestNotifyKillBoss {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 boss_id = 2;
+
+
+    pub fn get_boss_id(&self) -> u32 {
+        self.boss_id.unwrap_or(0)
+    }
+    pub fn clear_boss_id(&mut self) {
+        self.boss_id = ::std::option::Option::None;
+    }
+
+    pub fn has_boss_id(&self) -> bool {
+        self.boss_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_boss_id(&mut self, v: u32) {
+        self.boss_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyKillBoss {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.boss_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.boss_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.boss_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.boss_id {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyKillBoss {
+        RequestNotifyKillBoss::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyKillBoss {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyKillBoss> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyKillBoss::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyKillBoss {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.boss_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyKillBoss {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyKillBossResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyKillBossResponse {
+    fn default() -> &'a RequestNotifyKillBossResponse {
+        <RequestNotifyKillBossResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyKillBossResponse {
+    pub fn new() -> RequestNotifyKillBossResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyKillBossResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyKillBossResponse {
+        RequestNotifyKillBossResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyKillBossResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyKillBossResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyKillBossResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyKillBossResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyKillBossResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyKillPlayer {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyKillPlayer {
+    fn default() -> &'a RequestNotifyKillPlayer {
+        <RequestNotifyKillPlayer as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyKillPlayer {
+    pub fn new() -> RequestNotifyKillPlayer {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyKillPlayer {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyKillPlayer {
+        RequestNotifyKillPlayer::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyKillPlayer {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyKillPlayer> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyKillPlayer::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyKillPlayer {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyKillPlayer {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyKillPlayerResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyKillPlayerResponse {
+    fn default() -> &'a RequestNotifyKillPlayerResponse {
+        <RequestNotifyKillPlayerResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyKillPlayerResponse {
+    pub fn new() -> RequestNotifyKillPlayerResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyKillPlayerResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyKillPlayerResponse {
+        RequestNotifyKillPlayerResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyKillPlayerResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyKillPlayerResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyKillPlayerResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyKillPlayerResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyKillPlayerResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyBuyItem {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    item_id: ::std::option::Option<u32>,
+    quantity: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyBuyItem {
+    fn default() -> &'a RequestNotifyBuyItem {
+        <RequestNotifyBuyItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyBuyItem {
+    pub fn new() -> RequestNotifyBuyItem {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 item_id = 2;
+
+
+    pub fn get_item_id(&self) -> u32 {
+        self.item_id.unwrap_or(0)
+    }
+    pub fn clear_item_id(&mut self) {
+        self.item_id = ::std::option::Option::None;
+    }
+
+    pub fn has_item_id(&self) -> bool {
+        self.item_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_id(&mut self, v: u32) {
+        self.item_id = ::std::option::Option::Some(v);
+    }
+
+    // required uint32 quantity = 3;
+
+
+    pub fn get_quantity(&self) -> u32 {
+        self.quantity.unwrap_or(0)
+    }
+    pub fn clear_quantity(&mut self) {
+        self.quantity = ::std::option::Option::None;
+    }
+
+    pub fn has_quantity(&self) -> bool {
+        self.quantity.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quantity(&mut self, v: u32) {
+        self.quantity = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyBuyItem {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        if self.item_id.is_none() {
+            return false;
+        }
+        if self.quantity.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.item_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.quantity = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.item_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.quantity {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.item_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.quantity {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyBuyItem {
+        RequestNotifyBuyItem::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyBuyItem {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyBuyItem> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyBuyItem::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyBuyItem {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.item_id = ::std::option::Option::None;
+        self.quantity = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyBuyItem {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyBuyItemResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyBuyItemResponse {
+    fn default() -> &'a RequestNotifyBuyItemResponse {
+        <RequestNotifyBuyItemResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyBuyItemResponse {
+    pub fn new() -> RequestNotifyBuyItemResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyBuyItemResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyBuyItemResponse {
+        RequestNotifyBuyItemResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyBuyItemResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyBuyItemResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyBuyItemResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyBuyItemResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyBuyItemResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyJoinMultiplayer {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyJoinMultiplayer {
+    fn default() -> &'a RequestNotifyJoinMultiplayer {
+        <RequestNotifyJoinMultiplayer as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyJoinMultiplayer {
+    pub fn new() -> RequestNotifyJoinMultiplayer {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyJoinMultiplayer {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyJoinMultiplayer {
+        RequestNotifyJoinMultiplayer::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyJoinMultiplayer {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyJoinMultiplayer> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyJoinMultiplayer::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyJoinMultiplayer {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyJoinMultiplayer {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyJoinMultiplayerResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyJoinMultiplayerResponse {
+    fn default() -> &'a RequestNotifyJoinMultiplayerResponse {
+        <RequestNotifyJoinMultiplayerResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyJoinMultiplayerResponse {
+    pub fn new() -> RequestNotifyJoinMultiplayerResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyJoinMultiplayerResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyJoinMultiplayerResponse {
+        RequestNotifyJoinMultiplayerResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyJoinMultiplayerResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyJoinMultiplayerResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyJoinMultiplayerResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyJoinMultiplayerResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyJoinMultiplayerResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyLeaveMultiplayer {
+    // message fields
+    online_area_id: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyLeaveMultiplayer {
+    fn default() -> &'a RequestNotifyLeaveMultiplayer {
+        <RequestNotifyLeaveMultiplayer as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyLeaveMultiplayer {
+    pub fn new() -> RequestNotifyLeaveMultiplayer {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 online_area_id = 1;
+
+
+    pub fn get_online_area_id(&self) -> u32 {
+        self.online_area_id.unwrap_or(0)
+    }
+    pub fn clear_online_area_id(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+    }
+
+    pub fn has_online_area_id(&self) -> bool {
+        self.online_area_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_area_id(&mut self, v: u32) {
+        self.online_area_id = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyLeaveMultiplayer {
+    fn is_initialized(&self) -> bool {
+        if self.online_area_id.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.online_area_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.online_area_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.online_area_id {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyLeaveMultiplayer {
+        RequestNotifyLeaveMultiplayer::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyLeaveMultiplayer {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyLeaveMultiplayer> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyLeaveMultiplayer::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyLeaveMultiplayer {
+    fn clear(&mut self) {
+        self.online_area_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyLeaveMultiplayer {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestNotifyLeaveMultiplayerResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestNotifyLeaveMultiplayerResponse {
+    fn default() -> &'a RequestNotifyLeaveMultiplayerResponse {
+        <RequestNotifyLeaveMultiplayerResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestNotifyLeaveMultiplayerResponse {
+    pub fn new() -> RequestNotifyLeaveMultiplayerResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestNotifyLeaveMultiplayerResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestNotifyLeaveMultiplayerResponse {
+        RequestNotifyLeaveMultiplayerResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestNotifyLeaveMultiplayerResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestNotifyLeaveMultiplayerResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestNotifyLeaveMultiplayerResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestNotifyLeaveMultiplayerResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestNotifyLeaveMultiplayerResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestMeasureUploadBandwidth {
+    // message fields
+    data: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestMeasureUploadBandwidth {
+    fn default() -> &'a RequestMeasureUploadBandwidth {
+        <RequestMeasureUploadBandwidth as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestMeasureUploadBandwidth {
+    pub fn new() -> RequestMeasureUploadBandwidth {
+        ::std::default::Default::default()
+    }
+
+    // required bytes data = 1;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        match self.data.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    pub fn has_data(&self) -> bool {
+        self.data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.data.is_none() {
+            self.data.set_default();
+        }
+        self.data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for RequestMeasureUploadBandwidth {
+    fn is_initialized(&self) -> bool {
+        if self.data.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.data.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.data.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestMeasureUploadBandwidth {
+        RequestMeasureUploadBandwidth::new()
+    }
+
+    fn default_instance() -> &'static RequestMeasureUploadBandwidth {
+        static instance: ::protobuf::rt::LazyV2<RequestMeasureUploadBandwidth> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestMeasureUploadBandwidth::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestMeasureUploadBandwidth {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestMeasureUploadBandwidth {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestMeasureUploadBandwidthResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestMeasureUploadBandwidthResponse {
+    fn default() -> &'a RequestMeasureUploadBandwidthResponse {
+        <RequestMeasureUploadBandwidthResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestMeasureUploadBandwidthResponse {
+    pub fn new() -> RequestMeasureUploadBandwidthResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for RequestMeasureUploadBandwidthResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestMeasureUploadBandwidthResponse {
+        RequestMeasureUploadBandwidthResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestMeasureUploadBandwidthResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestMeasureUploadBandwidthResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestMeasureUploadBandwidthResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestMeasureUploadBandwidthResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestMeasureUploadBandwidthResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestMeasureDownloadBandwidth {
+    // message fields
+    size: ::std::option::Option<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestMeasureDownloadBandwidth {
+    fn default() -> &'a RequestMeasureDownloadBandwidth {
+        <RequestMeasureDownloadBandwidth as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestMeasureDownloadBandwidth {
+    pub fn new() -> RequestMeasureDownloadBandwidth {
+        ::std::default::Default::default()
+    }
+
+    // required uint32 size = 1;
+
+
+    pub fn get_size(&self) -> u32 {
+        self.size.unwrap_or(0)
+    }
+    pub fn clear_size(&mut self) {
+        self.size = ::std::option::Option::None;
+    }
+
+    pub fn has_size(&self) -> bool {
+        self.size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u32) {
+        self.size = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RequestMeasureDownloadBandwidth {
+    fn is_initialized(&self) -> bool {
+        if self.size.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.size = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.size {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.size {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestMeasureDownloadBandwidth {
+        RequestMeasureDownloadBandwidth::new()
+    }
+
+    fn default_instance() -> &'static RequestMeasureDownloadBandwidth {
+        static instance: ::protobuf::rt::LazyV2<RequestMeasureDownloadBandwidth> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestMeasureDownloadBandwidth::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestMeasureDownloadBandwidth {
+    fn clear(&mut self) {
+        self.size = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestMeasureDownloadBandwidth {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RequestMeasureDownloadBandwidthResponse {
+    // message fields
+    data: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestMeasureDownloadBandwidthResponse {
+    fn default() -> &'a RequestMeasureDownloadBandwidthResponse {
+        <RequestMeasureDownloadBandwidthResponse as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_unk01(&mut self, v: u32) {
-        self.unk01 = ::std::option::Option::Some(v);
+impl RequestMeasureDownloadBandwidthResponse {
+    pub fn new() -> RequestMeasureDownloadBandwidthResponse {
+        ::std::default::Default::default()
     }
 
-    // required uint32 unk02 = 2;
+    // required bytes data = 1;
 
 
-    pub fn get_unk02(&self) -> u32 {
-        self.unk02.unwrap_or(0)
+    pub fn get_data(&self) -> &[u8] {
+        match self.data.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
     }
-    pub fn clear_unk02(&mut self) {
-        self.unk02 = ::std::option::Option::None;
+    pub fn clear_data(&mut self) {
+        self.data.clear();
     }
 
-    pub fn has_unk02(&self) -> bool {
-        self.unk02.is_some()
+    pub fn has_data(&self) -> bool {
+        self.data.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_unk02(&mut self, v: u32) {
-        self.unk02 = ::std::option::Option::Some(v);
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = ::protobuf::SingularField::some(v);
     }
 
-    // required uint32 unk03 = 3;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.data.is_none() {
+            self.data.set_default();
+        }
+        self.data.as_mut().unwrap()
+    }
 
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
 
-    pub fn get_unk03(&self) -> u32 {
-        self.unk03.unwrap_or(0)
+impl ::protobuf::Message for RequestMeasureDownloadBandwidthResponse {
+    fn is_initialized(&self) -> bool {
+        if self.data.is_none() {
+            return false;
+        }
+        true
     }
-    pub fn clear_unk03(&mut self) {
-        self.unk03 = ::std::option::Option::None;
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    pub fn has_unk03(&self) -> bool {
-        self.unk03.is_some()
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.data.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Param is passed by value, moved
-    pub fn set_unk03(&mut self, v: u32) {
-        self.unk03 = ::std::option::Option::Some(v);
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.data.as_ref() {
+            os.write_bytes(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // required string header = 4;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestMeasureDownloadBandwidthResponse {
+        RequestMeasureDownloadBandwidthResponse::new()
+    }
+
+    fn default_instance() -> &'static RequestMeasureDownloadBandwidthResponse {
+        static instance: ::protobuf::rt::LazyV2<RequestMeasureDownloadBandwidthResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestMeasureDownloadBandwidthResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestMeasureDownloadBandwidthResponse {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestMeasureDownloadBandwidthResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PushBroadcastMessage {
+    // message fields
+    header: ::protobuf::SingularField<::std::string::String>,
+    message: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PushBroadcastMessage {
+    fn default() -> &'a PushBroadcastMessage {
+        <PushBroadcastMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PushBroadcastMessage {
+    pub fn new() -> PushBroadcastMessage {
+        ::std::default::Default::default()
+    }
+
+    // required string header = 1;
 
 
     pub fn get_header(&self) -> &str {
@@ -941,7 +21443,7 @@ impl AnnounceMessageData {
         self.header.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    // required string message = 5;
+    // required string message = 2;
 
 
     pub fn get_message(&self) -> &str {
@@ -976,66 +21478,16 @@ impl AnnounceMessageData {
     pub fn take_message(&mut self) -> ::std::string::String {
         self.message.take().unwrap_or_else(|| ::std::string::String::new())
     }
-
-    // required .DateTime date_time = 6;
-
-
-    pub fn get_date_time(&self) -> &super::common::DateTime {
-        self.date_time.as_ref().unwrap_or_else(|| <super::common::DateTime as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_date_time(&mut self) {
-        self.date_time.clear();
-    }
-
-    pub fn has_date_time(&self) -> bool {
-        self.date_time.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_date_time(&mut self, v: super::common::DateTime) {
-        self.date_time = ::protobuf::SingularPtrField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_date_time(&mut self) -> &mut super::common::DateTime {
-        if self.date_time.is_none() {
-            self.date_time.set_default();
-        }
-        self.date_time.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_date_time(&mut self) -> super::common::DateTime {
-        self.date_time.take().unwrap_or_else(|| super::common::DateTime::new())
-    }
 }
 
-impl ::protobuf::Message for AnnounceMessageData {
+impl ::protobuf::Message for PushBroadcastMessage {
     fn is_initialized(&self) -> bool {
-        if self.unk01.is_none() {
-            return false;
-        }
-        if self.unk02.is_none() {
-            return false;
-        }
-        if self.unk03.is_none() {
-            return false;
-        }
         if self.header.is_none() {
             return false;
         }
         if self.message.is_none() {
             return false;
         }
-        if self.date_time.is_none() {
-            return false;
-        }
-        for v in &self.date_time {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -1044,35 +21496,11 @@ impl ::protobuf::Message for AnnounceMessageData {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.unk01 = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.unk02 = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.unk03 = ::std::option::Option::Some(tmp);
-                },
-                4 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.header)?;
                 },
-                5 => {
+                2 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.message)?;
                 },
-                6 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.date_time)?;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1085,24 +21513,11 @@ impl ::protobuf::Message for AnnounceMessageData {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.unk01 {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.unk02 {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.unk03 {
-            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
-        }
         if let Some(ref v) = self.header.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
         if let Some(ref v) = self.message.as_ref() {
-            my_size += ::protobuf::rt::string_size(5, &v);
-        }
-        if let Some(ref v) = self.date_time.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+            my_size += ::protobuf::rt::string_size(2, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1110,25 +21525,11 @@ impl ::protobuf::Message for AnnounceMessageData {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.unk01 {
-            os.write_uint32(1, v)?;
-        }
-        if let Some(v) = self.unk02 {
-            os.write_uint32(2, v)?;
-        }
-        if let Some(v) = self.unk03 {
-            os.write_uint32(3, v)?;
-        }
         if let Some(ref v) = self.header.as_ref() {
-            os.write_string(4, &v)?;
+            os.write_string(1, &v)?;
         }
         if let Some(ref v) = self.message.as_ref() {
-            os.write_string(5, &v)?;
-        }
-        if let Some(ref v) = self.date_time.as_ref() {
-            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+            os.write_string(2, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1160,29 +21561,25 @@ impl ::protobuf::Message for AnnounceMessageData {
         Self::descriptor_static()
     }
 
-    fn new() -> AnnounceMessageData {
-        AnnounceMessageData::new()
+    fn new() -> PushBroadcastMessage {
+        PushBroadcastMessage::new()
     }
 
-    fn default_instance() -> &'static AnnounceMessageData {
-        static instance: ::protobuf::rt::LazyV2<AnnounceMessageData> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(AnnounceMessageData::new)
+    fn default_instance() -> &'static PushBroadcastMessage {
+        static instance: ::protobuf::rt::LazyV2<PushBroadcastMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PushBroadcastMessage::new)
     }
 }
 
-impl ::protobuf::Clear for AnnounceMessageData {
+impl ::protobuf::Clear for PushBroadcastMessage {
     fn clear(&mut self) {
-        self.unk01 = ::std::option::Option::None;
-        self.unk02 = ::std::option::Option::None;
-        self.unk03 = ::std::option::Option::None;
         self.header.clear();
         self.message.clear();
-        self.date_time.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AnnounceMessageData {
+impl ::protobuf::reflect::ProtobufValue for PushBroadcastMessage {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
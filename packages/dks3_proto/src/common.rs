@@ -380,3 +380,213 @@ impl ::protobuf::reflect::ProtobufValue for DateTime {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
+
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Vector3 {
+    // message fields
+    x: ::std::option::Option<f32>,
+    y: ::std::option::Option<f32>,
+    z: ::std::option::Option<f32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Vector3 {
+    fn default() -> &'a Vector3 {
+        <Vector3 as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Vector3 {
+    pub fn new() -> Vector3 {
+        ::std::default::Default::default()
+    }
+
+    // required float x = 1;
+
+
+    pub fn get_x(&self) -> f32 {
+        self.x.unwrap_or(0.)
+    }
+    pub fn clear_x(&mut self) {
+        self.x = ::std::option::Option::None;
+    }
+
+    pub fn has_x(&self) -> bool {
+        self.x.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_x(&mut self, v: f32) {
+        self.x = ::std::option::Option::Some(v);
+    }
+
+    // required float y = 2;
+
+
+    pub fn get_y(&self) -> f32 {
+        self.y.unwrap_or(0.)
+    }
+    pub fn clear_y(&mut self) {
+        self.y = ::std::option::Option::None;
+    }
+
+    pub fn has_y(&self) -> bool {
+        self.y.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_y(&mut self, v: f32) {
+        self.y = ::std::option::Option::Some(v);
+    }
+
+    // required float z = 3;
+
+
+    pub fn get_z(&self) -> f32 {
+        self.z.unwrap_or(0.)
+    }
+    pub fn clear_z(&mut self) {
+        self.z = ::std::option::Option::None;
+    }
+
+    pub fn has_z(&self) -> bool {
+        self.z.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_z(&mut self, v: f32) {
+        self.z = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for Vector3 {
+    fn is_initialized(&self) -> bool {
+        if self.x.is_none() {
+            return false;
+        }
+        if self.y.is_none() {
+            return false;
+        }
+        if self.z.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.x = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.y = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.z = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.x {
+            my_size += 5;
+        }
+        if let Some(v) = self.y {
+            my_size += 5;
+        }
+        if let Some(v) = self.z {
+            my_size += 5;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.x {
+            os.write_float(1, v)?;
+        }
+        if let Some(v) = self.y {
+            os.write_float(2, v)?;
+        }
+        if let Some(v) = self.z {
+            os.write_float(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Vector3 {
+        Vector3::new()
+    }
+
+    fn default_instance() -> &'static Vector3 {
+        static instance: ::protobuf::rt::LazyV2<Vector3> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Vector3::new)
+    }
+}
+
+impl ::protobuf::Clear for Vector3 {
+    fn clear(&mut self) {
+        self.x = ::std::option::Option::None;
+        self.y = ::std::option::Option::None;
+        self.z = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Vector3 {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
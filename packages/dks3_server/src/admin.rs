@@ -0,0 +1,194 @@
+//! An HTTP API for admins and community tools, serving JSON.
+//!
+//! It is disabled unless a port is configured. If a token is configured too, every request must
+//! present it as a bearer token in its `Authorization` header. Without a token it refuses to
+//! listen anywhere but a loopback address, since anyone who can reach it could broadcast to
+//! every player.
+//!
+//! - `GET /areas`: how many players are online, and how many are in each online area.
+//! - `GET /stats`: the gameplay statistics totals for each day there are any for.
+//! - `GET /stats/<date>`: the gameplay statistics for one day, e.g. `/stats/2020-11-01`, per
+//!   online area, boss and item.
+//! - `POST /broadcast`: push a message to the players online, e.g.
+//!   `{"header": "Maintenance", "message": "Servers restart in 10 minutes"}`. It can be narrowed
+//!   down with `online_area_ids`, `covenants` and `steam_ids` lists, and responds with how many
+//!   players it was sent to.
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+use hyper::server::conn::AddrIncoming;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{header, Body, Method, Request, Response, Server, StatusCode};
+use serde_derive::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
+use tracing::{info, warn};
+
+use dks3_config::Admin;
+
+use crate::broadcast::BroadcastFilter;
+use crate::{Result, ServerContext};
+
+#[derive(Debug, Serialize)]
+pub struct AreaCount {
+    pub online_area_id: u32,
+    pub players: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Population {
+    /// Everyone with a session, including players who haven't reported where they are yet.
+    pub players_online: usize,
+    pub areas: Vec<AreaCount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BroadcastRequest {
+    #[serde(default)]
+    pub header: String,
+    pub message: String,
+    #[serde(flatten)]
+    pub filter: BroadcastFilter,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadcastResult {
+    pub players: usize,
+}
+
+pub struct AdminServer {
+    context: ServerContext,
+    incoming: AddrIncoming,
+}
+
+impl AdminServer {
+    /// Listen on `addr`, which must be a loopback address unless a token is configured.
+    pub fn bind(context: ServerContext, addr: SocketAddr) -> Result<Self> {
+        if context.config().admin().token().is_none() && !addr.ip().is_loopback() {
+            return Err(format!(
+                "refusing to serve the admin API on {} without a token",
+                addr
+            )
+            .into());
+        }
+
+        let incoming = AddrIncoming::bind(&addr)?;
+
+        Ok(Self { context, incoming })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.incoming.local_addr()
+    }
+
+    pub async fn serve(self) -> Result<()> {
+        info!("Admin API listening on {}", self.local_addr());
+
+        let context = self.context;
+        let make_service = make_service_fn(move |_| {
+            let context = context.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| handle(context.clone(), request)))
+            }
+        });
+
+        Server::builder(self.incoming).serve(make_service).await?;
+        Ok(())
+    }
+}
+
+/// Serve the admin API on the configured port, if there is one.
+pub async fn listen(context: ServerContext) -> Result<()> {
+    let config = context.config().admin();
+    let addr = match config.port() {
+        Some(port) => format!("{}:{}", config.bind_addr(), port).parse()?,
+        None => return Ok(()),
+    };
+
+    AdminServer::bind(context, addr)?.serve().await
+}
+
+async fn handle(
+    context: ServerContext,
+    request: Request<Body>,
+) -> std::result::Result<Response<Body>, Infallible> {
+    if !is_authorized(context.config().admin(), &request) {
+        return Ok(status(StatusCode::UNAUTHORIZED));
+    }
+
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let response = match (&method, path.as_str()) {
+        (&Method::GET, "/areas") => json(&population(&context)),
+        (&Method::GET, "/stats") => json(&context.telemetry().totals()),
+        (&Method::GET, _) if path.starts_with("/stats/") => {
+            match context.telemetry().day(&path["/stats/".len()..]) {
+                Some(stats) => json(&stats),
+                None => Ok(status(StatusCode::NOT_FOUND)),
+            }
+        }
+        (&Method::POST, "/broadcast") => broadcast(&context, request).await,
+        _ => Ok(status(StatusCode::NOT_FOUND)),
+    };
+
+    Ok(response.unwrap_or_else(|e| {
+        warn!("Admin request failed: {}", e);
+        status(StatusCode::INTERNAL_SERVER_ERROR)
+    }))
+}
+
+fn is_authorized(config: &Admin, request: &Request<Body>) -> bool {
+    let token = match config.token() {
+        Some(token) => token,
+        None => return true,
+    };
+
+    // Compared in constant time, so the token can't be guessed a byte at a time.
+    let expected = format!("Bearer {}", token);
+    request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .iter()
+        .any(|value| bool::from(value.as_bytes().ct_eq(expected.as_bytes())))
+}
+
+pub fn population(context: &ServerContext) -> Population {
+    Population {
+        players_online: context.sessions().len(),
+        areas: context
+            .areas()
+            .counts()
+            .into_iter()
+            .map(|(online_area_id, players)| AreaCount {
+                online_area_id,
+                players,
+            })
+            .collect(),
+    }
+}
+
+async fn broadcast(context: &ServerContext, request: Request<Body>) -> Result<Response<Body>> {
+    let body = hyper::body::to_bytes(request.into_body()).await?;
+    let request: BroadcastRequest = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(_) => return Ok(status(StatusCode::BAD_REQUEST)),
+    };
+
+    let players = context.broadcast(&request.filter, &request.header, &request.message);
+    info!("Broadcast \"{}\" to {} players", request.message, players);
+
+    json(&BroadcastResult { players })
+}
+
+fn json<T: serde::Serialize>(body: &T) -> Result<Response<Body>> {
+    Ok(Response::builder()
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(serde_json::to_vec(body)?))?)
+}
+
+fn status(status: StatusCode) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .body(Body::empty())
+        .unwrap()
+}
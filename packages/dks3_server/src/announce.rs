@@ -0,0 +1,152 @@
+//! The news shown on the login screen, read from the announcements file named in the server
+//! configuration and reloaded whenever it changes.
+
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
+use std::time::SystemTime;
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use tracing::{info, warn};
+
+use dks3_config::{Announcement, AnnouncementList};
+use dks3_proto::common::DateTime as ProtoDateTime;
+use dks3_proto::Frpg2RequestMessage::{
+    AnnounceMessageData, AnnounceMessageDataList, RequestGetAnnounceMessageList,
+    RequestGetAnnounceMessageListResponse,
+};
+
+use crate::dispatch::RequestContext;
+use crate::{Result, ServerContext};
+
+#[derive(Debug, Default)]
+pub struct AnnouncementBoard {
+    path: Option<PathBuf>,
+    /// The modification time and length of the file when it was last read.
+    loaded: Mutex<Option<(SystemTime, u64)>>,
+    announcements: RwLock<Vec<Announcement>>,
+}
+
+impl AnnouncementBoard {
+    /// Create a board backed by the file at `path`. Nothing is loaded until the first call to
+    /// [`reload_if_changed`].
+    ///
+    /// [`reload_if_changed`]: AnnouncementBoard::reload_if_changed
+    pub fn new(path: Option<&Path>) -> Self {
+        Self {
+            path: path.map(Path::to_path_buf),
+            ..Self::default()
+        }
+    }
+
+    /// Replace the current announcements.
+    pub fn replace(&self, announcements: Vec<Announcement>) {
+        *self.announcements.write().unwrap() = announcements;
+    }
+
+    /// Reload the announcements file if it has been modified since it was last read, returning
+    /// whether it was. The file counts as modified when either its modification time or its
+    /// length changed, since an edit can land within the same mtime tick on some filesystems. If
+    /// the file can't be parsed the current announcements are kept.
+    pub fn reload_if_changed(&self) -> Result<bool> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(false),
+        };
+
+        let metadata = std::fs::metadata(path)?;
+        let stamp = (metadata.modified()?, metadata.len());
+        let mut loaded = self.loaded.lock().unwrap();
+        if *loaded == Some(stamp) {
+            return Ok(false);
+        }
+
+        let list = AnnouncementList::load(path)?;
+        *loaded = Some(stamp);
+        self.replace(list.into_announcements());
+
+        Ok(true)
+    }
+
+    /// The announcements visible at `now`, newest first.
+    pub fn visible_at(&self, now: DateTime<Utc>) -> Vec<Announcement> {
+        let mut visible: Vec<Announcement> = self
+            .announcements
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|announcement| announcement.is_visible_at(now))
+            .cloned()
+            .collect();
+
+        visible.sort_by_key(|announcement| Reverse(announcement.date()));
+        visible
+    }
+}
+
+/// Watch the announcements file for changes until the server shuts down.
+pub async fn watch(context: ServerContext) -> Result<()> {
+    let config = context.config().announcements();
+    let path = match config.path() {
+        Some(path) => path,
+        None => return Ok(()),
+    };
+
+    let mut interval = tokio::time::interval(config.reload_interval());
+    loop {
+        interval.tick().await;
+
+        match context.announcements().reload_if_changed() {
+            Ok(true) => info!("Loaded announcements from {}", path.display()),
+            Ok(false) => {}
+            Err(e) => warn!(
+                "Failed to load announcements from {}: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+}
+
+pub async fn get_announce_message_list(
+    request: RequestContext,
+    body: RequestGetAnnounceMessageList,
+) -> Result<RequestGetAnnounceMessageListResponse> {
+    let mut announcements = request.context().announcements().visible_at(Utc::now());
+    if body.has_max_entries() {
+        announcements.truncate(body.get_max_entries() as usize);
+    }
+
+    let mut data_list = AnnounceMessageDataList::new();
+    for announcement in &announcements {
+        data_list.mut_items().push(to_message_data(announcement));
+    }
+
+    let mut response = RequestGetAnnounceMessageListResponse::new();
+    response.set_data_list(data_list);
+
+    Ok(response)
+}
+
+fn to_message_data(announcement: &Announcement) -> AnnounceMessageData {
+    let mut data = AnnounceMessageData::new();
+    data.set_unk01(announcement.unk01());
+    data.set_unk02(announcement.unk02());
+    data.set_unk03(announcement.unk03());
+    data.set_header(announcement.header().to_string());
+    data.set_message(announcement.message().to_string());
+    data.set_date_time(to_proto_date_time(announcement.date()));
+    data
+}
+
+pub fn to_proto_date_time(date: DateTime<Utc>) -> ProtoDateTime {
+    let mut date_time = ProtoDateTime::new();
+    date_time.set_year(date.year() as u32);
+    date_time.set_month(date.month());
+    date_time.set_day(date.day());
+    date_time.set_hours(date.hour());
+    date_time.set_minutes(date.minute());
+    date_time.set_seconds(date.second());
+    date_time.set_tzdiff(0);
+    date_time
+}
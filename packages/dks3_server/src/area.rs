@@ -0,0 +1,159 @@
+//! How many players are in each online area, kept up to date from the status updates their
+//! clients send.
+//!
+//! Clients use the counts to show how busy an area is, and to ask where they're most likely to
+//! find someone to play with: the areas with the most players they could be matched with.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use dks3_config::Matchmaking;
+use dks3_proto::Frpg2RequestMessage::{
+    AreaPopulation, RequestCountAreaPlayers, RequestCountAreaPlayersResponse,
+    RequestGetRightMatchingArea, RequestGetRightMatchingAreaResponse,
+};
+
+use crate::dispatch::RequestContext;
+use crate::matchmaking::{self, Level, MultiplayerType};
+use crate::session::{CharacterSummary, SessionId};
+use crate::Result;
+
+#[derive(Debug, Default)]
+struct Population {
+    /// The area each player is in, and how they'd be matched.
+    by_session: HashMap<SessionId, (u32, Level)>,
+    counts: HashMap<u32, usize>,
+}
+
+impl Population {
+    fn remove(&mut self, session_id: SessionId) {
+        if let Some((online_area_id, _)) = self.by_session.remove(&session_id) {
+            if let Some(count) = self.counts.get_mut(&online_area_id) {
+                *count -= 1;
+                if *count == 0 {
+                    self.counts.remove(&online_area_id);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AreaTracker {
+    population: RwLock<Population>,
+}
+
+impl AreaTracker {
+    /// Move the player with `session_id` to the area their character last reported.
+    pub fn update(&self, session_id: SessionId, character: &CharacterSummary) {
+        let mut population = self.population.write().unwrap();
+        population.remove(session_id);
+
+        population
+            .by_session
+            .insert(session_id, (character.online_area_id, character.level()));
+        *population
+            .counts
+            .entry(character.online_area_id)
+            .or_insert(0) += 1;
+    }
+
+    /// Stop counting the player with `session_id`, who has logged off.
+    pub fn remove(&self, session_id: SessionId) {
+        self.population.write().unwrap().remove(session_id);
+    }
+
+    /// How many players are in the area with `online_area_id`.
+    pub fn count(&self, online_area_id: u32) -> usize {
+        self.population
+            .read()
+            .unwrap()
+            .counts
+            .get(&online_area_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// How many players are in each area that has anyone in it, ordered by area.
+    pub fn counts(&self) -> Vec<(u32, usize)> {
+        let mut counts: Vec<(u32, usize)> = self
+            .population
+            .read()
+            .unwrap()
+            .counts
+            .iter()
+            .map(|(online_area_id, count)| (*online_area_id, *count))
+            .collect();
+
+        counts.sort();
+        counts
+    }
+
+    /// How many players in each area the player at `level` could be matched with for `ty`,
+    /// busiest first, leaving out the player with `except`.
+    pub fn matching(
+        &self,
+        matchmaking: &Matchmaking,
+        ty: MultiplayerType,
+        level: &Level,
+        except: SessionId,
+    ) -> Vec<(u32, usize)> {
+        let mut counts: HashMap<u32, usize> = HashMap::new();
+        for (session_id, (online_area_id, other)) in &self.population.read().unwrap().by_session {
+            if *session_id != except && matchmaking::is_match(matchmaking, ty, level, other) {
+                *counts.entry(*online_area_id).or_insert(0) += 1;
+            }
+        }
+
+        let mut counts: Vec<(u32, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+}
+
+pub async fn count_area_players(
+    request: RequestContext,
+    body: RequestCountAreaPlayers,
+) -> Result<RequestCountAreaPlayersResponse> {
+    let areas = request.context().areas();
+
+    let mut response = RequestCountAreaPlayersResponse::new();
+    for online_area_id in body.get_online_area_ids() {
+        let count = areas.count(*online_area_id);
+        response
+            .mut_areas()
+            .push(to_area_population(*online_area_id, count));
+    }
+
+    Ok(response)
+}
+
+pub async fn get_right_matching_area(
+    request: RequestContext,
+    body: RequestGetRightMatchingArea,
+) -> Result<RequestGetRightMatchingAreaResponse> {
+    let context = request.context();
+    let level = matchmaking::from_matching_parameter(body.get_matching_parameter());
+    let counts = context.areas().matching(
+        context.config().matchmaking(),
+        MultiplayerType::Coop,
+        &level,
+        request.session_id(),
+    );
+
+    let mut response = RequestGetRightMatchingAreaResponse::new();
+    for (online_area_id, count) in counts {
+        response
+            .mut_areas()
+            .push(to_area_population(online_area_id, count));
+    }
+
+    Ok(response)
+}
+
+fn to_area_population(online_area_id: u32, count: usize) -> AreaPopulation {
+    let mut population = AreaPopulation::new();
+    population.set_online_area_id(online_area_id);
+    population.set_players(count as u32);
+    population
+}
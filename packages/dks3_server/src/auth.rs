@@ -0,0 +1,225 @@
+//! The auth server establishes the session key used for the rest of a client's conversation
+//! with us and hands out the token the game server accepts.
+//!
+//! The handshake runs as follows:
+//!
+//! 1. The client sends an RSA encrypted `RequestHandshake` carrying a CWC key. Every message
+//!    after this one, in both directions, is encrypted with that key.
+//! 2. The client asks for `GetServiceStatus`, which we echo back.
+//! 3. The client sends 8 bytes of `KeyMaterial`. We append 8 bytes of our own and reply with
+//!    the result, which becomes the key for the game server conversation.
+//! 4. The client sends its `SteamTicket`. If the configured [`TicketVerifier`] accepts it we
+//!    reply with `GameServerInfo`, containing the address of the game server and a single use
+//!    auth token to present to it. Otherwise the connection is closed.
+//!
+//! [`TicketVerifier`]: crate::steam::TicketVerifier
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use rsa::RSAPrivateKey;
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio_util::codec::Framed;
+use tracing::{info, info_span, warn};
+use tracing_futures::Instrument;
+
+use dks3_proto::Frpg2RequestMessage::{
+    GameServerInfo, GetServiceStatus, GetServiceStatusResponse, KeyMaterial, RequestHandshake,
+    RequestHandshakeResponse, SteamTicket,
+};
+
+use crate::crypto::{rsa_decrypt, CwcCipher, CWC_KEY_LEN};
+use crate::frame::{packet_codec, CwcFramed, Message, MessageType};
+use crate::steam::Verdict;
+use crate::{Result, ServerContext};
+
+/// How long an issued token remains valid if it is never presented to the game server.
+pub const TOKEN_LIFETIME: Duration = Duration::from_secs(5 * 60);
+
+const KEY_MATERIAL_LEN: usize = CWC_KEY_LEN / 2;
+
+/// A successful authentication, waiting to be redeemed by the game server.
+#[derive(Debug, Clone)]
+pub struct AuthTicket {
+    pub steam_id: String,
+    pub game_key: [u8; CWC_KEY_LEN],
+    pub issued: Instant,
+}
+
+#[derive(Debug, Default)]
+pub struct AuthTokens {
+    tokens: Mutex<HashMap<u64, AuthTicket>>,
+}
+
+impl AuthTokens {
+    /// Issue a new token for `steam_id`, to be redeemed along with `game_key`.
+    pub fn issue(&self, steam_id: String, game_key: [u8; CWC_KEY_LEN]) -> u64 {
+        let mut tokens = self.tokens.lock().unwrap();
+        tokens.retain(|_, ticket| ticket.issued.elapsed() < TOKEN_LIFETIME);
+
+        let ticket = AuthTicket {
+            steam_id,
+            game_key,
+            issued: Instant::now(),
+        };
+
+        loop {
+            let token = rand::random();
+            if token != 0 && !tokens.contains_key(&token) {
+                tokens.insert(token, ticket);
+                return token;
+            }
+        }
+    }
+
+    /// The ticket `token` was issued for, if it is still valid, without consuming it.
+    pub fn peek(&self, token: u64) -> Option<AuthTicket> {
+        self.tokens
+            .lock()
+            .unwrap()
+            .get(&token)
+            .filter(|ticket| ticket.issued.elapsed() < TOKEN_LIFETIME)
+            .cloned()
+    }
+
+    /// Consume `token`, returning the ticket it was issued for if it is still valid.
+    pub fn redeem(&self, token: u64) -> Option<AuthTicket> {
+        self.tokens
+            .lock()
+            .unwrap()
+            .remove(&token)
+            .filter(|ticket| ticket.issued.elapsed() < TOKEN_LIFETIME)
+    }
+}
+
+pub struct AuthServer {
+    context: ServerContext,
+    listener: TcpListener,
+    private_key: Arc<RSAPrivateKey>,
+}
+
+impl AuthServer {
+    pub async fn bind<A: ToSocketAddrs>(context: ServerContext, addr: A) -> Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let private_key = Arc::new(context.config().security().private_key());
+
+        Ok(Self {
+            context,
+            listener,
+            private_key,
+        })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    pub async fn serve(mut self) -> Result<()> {
+        info!("Auth server listening on {}", self.local_addr()?);
+
+        loop {
+            let (stream, peer) = self.listener.accept().await?;
+            let context = self.context.clone();
+            let private_key = self.private_key.clone();
+
+            tokio::spawn(
+                async move {
+                    if let Err(e) = handle_connection(context, &private_key, stream).await {
+                        warn!("Authentication failed: {}", e);
+                    }
+                }
+                .instrument(info_span!("auth", %peer)),
+            );
+        }
+    }
+}
+
+async fn handle_connection(
+    context: ServerContext,
+    private_key: &RSAPrivateKey,
+    stream: TcpStream,
+) -> Result<()> {
+    let mut framed = Framed::new(stream, packet_codec());
+    let packet = match framed.next().await {
+        Some(packet) => packet?,
+        None => return Ok(()),
+    };
+
+    let message = Message::parse(rsa_decrypt(private_key, &packet)?.into())?;
+    message.expect(MessageType::RequestHandshake)?;
+
+    let handshake: RequestHandshake = message.decode()?;
+    let mut conn = CwcFramed::new(framed, CwcCipher::new(handshake.get_aes_cwc_key())?);
+    conn.send(&Message::reply(
+        message.index(),
+        &RequestHandshakeResponse::new(),
+    )?)
+    .await?;
+
+    let message = conn.expect_message().await?;
+    message.expect(MessageType::GetServiceStatus)?;
+
+    let status: GetServiceStatus = message.decode()?;
+    let steam_id = status.get_steam_id().to_string();
+
+    let mut response = GetServiceStatusResponse::new();
+    response.set_f1(status.get_f1());
+    response.set_steam_id(steam_id.clone());
+    response.set_f3(0);
+    response.set_f4(status.get_f4());
+    conn.send(&Message::reply(message.index(), &response)?)
+        .await?;
+
+    let message = conn.expect_message().await?;
+    message.expect(MessageType::KeyMaterial)?;
+
+    let key_material: KeyMaterial = message.decode()?;
+    let client_half = key_material.get_key_material();
+    if client_half.len() != KEY_MATERIAL_LEN {
+        return Err(format!("expected {} bytes of key material", KEY_MATERIAL_LEN).into());
+    }
+
+    let server_half: [u8; KEY_MATERIAL_LEN] = rand::random();
+    let mut game_key = [0u8; CWC_KEY_LEN];
+    game_key[..KEY_MATERIAL_LEN].copy_from_slice(client_half);
+    game_key[KEY_MATERIAL_LEN..].copy_from_slice(&server_half);
+
+    let mut response = KeyMaterial::new();
+    response.set_key_material(game_key.to_vec());
+    conn.send(&Message::reply(message.index(), &response)?)
+        .await?;
+
+    let message = conn.expect_message().await?;
+    message.expect(MessageType::SteamTicket)?;
+
+    let ticket: SteamTicket = message.decode()?;
+    let verdict = context
+        .ticket_verifier()
+        .verify(&steam_id, ticket.get_ticket())
+        .await?;
+
+    if let Verdict::Rejected(reason) = verdict {
+        warn!(
+            steam_id = steam_id.as_str(),
+            "Steam ticket rejected: {}", reason
+        );
+        return Ok(());
+    }
+
+    let token = context.auth_tokens().issue(steam_id.clone(), game_key);
+
+    let server = context.config().server();
+    let mut response = GameServerInfo::new();
+    response.set_auth_token(token);
+    response.set_server_ip(server.hostname().to_string());
+    response.set_port(u32::from(server.game_port()));
+    conn.send(&Message::reply(message.index(), &response)?)
+        .await?;
+
+    info!(steam_id = steam_id.as_str(), "Client authenticated");
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! Measurement of how fast each player's connection is, so that matchmaking can offer players
+//! on very slow connections to others last.
+//!
+//! Clients measure their bandwidth before playing online by sending a run of upload requests
+//! carrying a payload, and a run of download requests asking for one. Each request in a run is
+//! only sent once the previous one has been answered, so the time between answering one request
+//! and receiving the next is roughly how long the payload took to travel.
+
+use std::time::{Duration, Instant};
+
+use dks3_proto::Frpg2RequestMessage::{
+    RequestMeasureDownloadBandwidth, RequestMeasureDownloadBandwidthResponse,
+    RequestMeasureUploadBandwidth, RequestMeasureUploadBandwidthResponse,
+};
+
+use crate::dispatch::{RequestContext, RequestError};
+use crate::session::SessionId;
+use crate::{Result, ServerContext};
+
+/// A payload we are timing the next request against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Probe {
+    answered: Instant,
+    bytes: usize,
+}
+
+/// A player's measured connection speeds, each averaged over their measurements.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bandwidth {
+    pub upload_kbps: Option<u32>,
+    pub download_kbps: Option<u32>,
+    upload_probe: Option<Probe>,
+    download_probe: Option<Probe>,
+}
+
+impl Bandwidth {
+    /// Whether either speed has been measured below `threshold_kbps`.
+    pub fn is_poor(&self, threshold_kbps: u32) -> bool {
+        self.upload_kbps
+            .iter()
+            .chain(self.download_kbps.iter())
+            .any(|kbps| *kbps < threshold_kbps)
+    }
+
+    /// Time an upload of `bytes` answered at `now` against the previous one, if it was answered
+    /// no more than `timeout` ago.
+    pub fn upload_received(&mut self, bytes: usize, now: Instant, timeout: Duration) {
+        let measured = self
+            .upload_probe
+            .and_then(|probe| measure(probe, bytes, now, timeout));
+        if let Some(kbps) = measured {
+            self.upload_kbps = Some(average(self.upload_kbps, kbps));
+        }
+
+        self.upload_probe = Some(Probe {
+            answered: now,
+            bytes,
+        });
+    }
+
+    /// Time the download answered previously against a request for another one arriving at
+    /// `now`, then start timing the `bytes` we're about to send.
+    pub fn download_requested(&mut self, bytes: usize, now: Instant, timeout: Duration) {
+        let measured = self
+            .download_probe
+            .and_then(|probe| measure(probe, probe.bytes, now, timeout));
+        if let Some(kbps) = measured {
+            self.download_kbps = Some(average(self.download_kbps, kbps));
+        }
+
+        self.download_probe = Some(Probe {
+            answered: now,
+            bytes,
+        });
+    }
+}
+
+/// The speed in kilobits per second of `bytes` that arrived at `now`, timed from when `probe`
+/// was answered.
+fn measure(probe: Probe, bytes: usize, now: Instant, timeout: Duration) -> Option<u32> {
+    let elapsed = now.checked_duration_since(probe.answered)?;
+    if elapsed > timeout || elapsed == Duration::from_secs(0) {
+        return None;
+    }
+
+    let kbps = (bytes * 8) as f64 / elapsed.as_secs_f64() / 1000.0;
+    Some(kbps.min(f64::from(u32::MAX)) as u32)
+}
+
+fn average(previous: Option<u32>, kbps: u32) -> u32 {
+    match previous {
+        Some(previous) => ((u64::from(previous) + u64::from(kbps)) / 2) as u32,
+        None => kbps,
+    }
+}
+
+/// Whether the player with `session_id` has measured a connection slow enough that other
+/// players should be offered them last.
+pub fn is_poor_connection(context: &ServerContext, session_id: SessionId) -> bool {
+    let threshold = context.config().bandwidth().poor_connection_kbps();
+
+    context
+        .sessions()
+        .get(session_id)
+        .iter()
+        .any(|session| session.bandwidth.is_poor(threshold))
+}
+
+pub async fn measure_upload_bandwidth(
+    request: RequestContext,
+    body: RequestMeasureUploadBandwidth,
+) -> Result<RequestMeasureUploadBandwidthResponse> {
+    let context = request.context();
+    let config = context.config().bandwidth();
+    let bytes = body.get_data().len();
+    if bytes > config.max_payload_bytes() {
+        return Err(RequestError::invalid("measurement payload is too large").into());
+    }
+
+    let now = Instant::now();
+    context.sessions().update(request.session_id(), |session| {
+        session
+            .bandwidth
+            .upload_received(bytes, now, config.probe_timeout())
+    });
+
+    Ok(RequestMeasureUploadBandwidthResponse::new())
+}
+
+pub async fn measure_download_bandwidth(
+    request: RequestContext,
+    body: RequestMeasureDownloadBandwidth,
+) -> Result<RequestMeasureDownloadBandwidthResponse> {
+    let context = request.context();
+    let config = context.config().bandwidth();
+    let bytes = (body.get_size() as usize).min(config.max_payload_bytes());
+
+    let now = Instant::now();
+    context.sessions().update(request.session_id(), |session| {
+        session
+            .bandwidth
+            .download_requested(bytes, now, config.probe_timeout())
+    });
+
+    let mut response = RequestMeasureDownloadBandwidthResponse::new();
+    response.set_data(vec![0; bytes]);
+
+    Ok(response)
+}
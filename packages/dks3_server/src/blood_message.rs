@@ -0,0 +1,493 @@
+//! Blood messages: the notes players write with a soapstone for others to find in their worlds.
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use rand::Rng;
+use serde_derive::{Deserialize, Serialize};
+use tracing::debug;
+
+use dks3_proto::Frpg2RequestMessage::{
+    BloodMessageData, BloodMessageText, PushEvaluateBloodMessage, RequestCreateBloodMessage,
+    RequestCreateBloodMessageResponse, RequestEvaluateBloodMessage,
+    RequestEvaluateBloodMessageResponse, RequestGetBloodMessageList,
+    RequestGetBloodMessageListResponse, RequestReentryBloodMessage,
+    RequestReentryBloodMessageResponse, RequestRemoveBloodMessage,
+    RequestRemoveBloodMessageResponse,
+};
+
+use crate::dispatch::{RequestContext, RequestError};
+use crate::frame::MessageType;
+use crate::location::{self, Location};
+use crate::storage::{DirtyFlag, Storage};
+use crate::Result;
+
+const STORAGE_NAME: &str = "blood_messages";
+
+pub type BloodMessageId = u64;
+
+/// The phrase a message is made of: a template filled in with a word, optionally joined to a
+/// second template and word by a conjunction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MessageText {
+    pub template_id: u32,
+    pub word_id: u32,
+    pub conjunction_id: Option<u32>,
+    pub second_template_id: Option<u32>,
+    pub second_word_id: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BloodMessage {
+    pub id: BloodMessageId,
+    pub steam_id: String,
+    pub character_name: String,
+    pub location: Location,
+    pub text: MessageText,
+    pub gesture_id: u32,
+    pub created: SystemTime,
+    pub rating_good: u32,
+    pub rating_poor: u32,
+}
+
+impl BloodMessage {
+    /// How likely the message is to be picked when sampling an area, relative to the others.
+    fn weight(&self) -> f64 {
+        f64::from(1 + self.rating_good) / f64::from(1 + self.rating_poor)
+    }
+
+    /// The message's overall rating, used to order listings.
+    pub fn score(&self) -> i64 {
+        i64::from(self.rating_good) - i64::from(self.rating_poor)
+    }
+}
+
+/// The result of a player appraising a message.
+#[derive(Debug, PartialEq)]
+pub enum Evaluation {
+    /// The vote was counted. Holds the message with its updated ratings.
+    Recorded(BloodMessage),
+    AlreadyEvaluated,
+    OwnMessage,
+    NotFound,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Snapshot {
+    next_id: BloodMessageId,
+    messages: Vec<BloodMessage>,
+    voters: HashMap<BloodMessageId, HashSet<String>>,
+}
+
+#[derive(Debug, Default)]
+struct Messages {
+    next_id: BloodMessageId,
+    by_id: HashMap<BloodMessageId, BloodMessage>,
+    by_area: HashMap<u32, HashSet<BloodMessageId>>,
+    voters: HashMap<BloodMessageId, HashSet<String>>,
+}
+
+impl Messages {
+    fn from_snapshot(snapshot: Snapshot) -> Self {
+        let mut messages = Self {
+            next_id: snapshot.next_id,
+            voters: snapshot.voters,
+            ..Self::default()
+        };
+
+        for message in snapshot.messages {
+            messages.add(message);
+        }
+
+        messages
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            next_id: self.next_id,
+            messages: self.by_id.values().cloned().collect(),
+            voters: self.voters.clone(),
+        }
+    }
+
+    fn add(&mut self, message: BloodMessage) {
+        self.by_area
+            .entry(message.location.online_area_id)
+            .or_default()
+            .insert(message.id);
+        self.by_id.insert(message.id, message);
+    }
+
+    fn remove(&mut self, id: BloodMessageId) -> Option<BloodMessage> {
+        let message = self.by_id.remove(&id)?;
+        let area = message.location.online_area_id;
+        self.voters.remove(&id);
+
+        if let Some(ids) = self.by_area.get_mut(&area) {
+            ids.remove(&id);
+            if ids.is_empty() {
+                self.by_area.remove(&area);
+            }
+        }
+
+        Some(message)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BloodMessageStore {
+    messages: RwLock<Messages>,
+    dirty: DirtyFlag,
+}
+
+impl BloodMessageStore {
+    /// Load the messages saved in `storage`.
+    pub fn load(storage: &Storage) -> Result<Self> {
+        let messages = storage
+            .load(STORAGE_NAME)?
+            .map(Messages::from_snapshot)
+            .unwrap_or_default();
+
+        Ok(Self {
+            messages: RwLock::new(messages),
+            dirty: DirtyFlag::default(),
+        })
+    }
+
+    /// Save the messages to `storage` if they have changed since they were last saved.
+    pub fn save(&self, storage: &Storage) -> Result<()> {
+        if !self.dirty.take() {
+            return Ok(());
+        }
+
+        let snapshot = self.messages.read().unwrap().snapshot();
+        let result = storage.save(STORAGE_NAME, &snapshot);
+        if result.is_err() {
+            self.dirty.mark();
+        }
+
+        result
+    }
+
+    /// Store `message` under a newly assigned ID, which is returned. If its author already has
+    /// `max_per_player` messages, their oldest are removed to make room.
+    pub fn insert(&self, mut message: BloodMessage, max_per_player: usize) -> BloodMessageId {
+        let mut messages = self.messages.write().unwrap();
+
+        let mut owned: Vec<(SystemTime, BloodMessageId)> = messages
+            .by_id
+            .values()
+            .filter(|existing| existing.steam_id == message.steam_id)
+            .map(|existing| (existing.created, existing.id))
+            .collect();
+        owned.sort();
+
+        let excess = (owned.len() + 1).saturating_sub(max_per_player.max(1));
+        for (_, id) in owned.into_iter().take(excess) {
+            messages.remove(id);
+        }
+
+        messages.next_id += 1;
+        message.id = messages.next_id;
+
+        let id = message.id;
+        messages.add(message);
+        self.dirty.mark();
+
+        id
+    }
+
+    pub fn get(&self, id: BloodMessageId) -> Option<BloodMessage> {
+        self.messages.read().unwrap().by_id.get(&id).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.read().unwrap().by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove a message, but only if it was written by `steam_id`.
+    pub fn remove_owned(&self, id: BloodMessageId, steam_id: &str) -> Option<BloodMessage> {
+        let mut messages = self.messages.write().unwrap();
+        let removed = match messages.by_id.get(&id) {
+            Some(message) if message.steam_id == steam_id => messages.remove(id),
+            _ => None,
+        };
+
+        if removed.is_some() {
+            self.dirty.mark();
+        }
+
+        removed
+    }
+
+    /// Record `voter`'s appraisal of a message. Each player may appraise a message once, and
+    /// never their own.
+    pub fn evaluate(&self, id: BloodMessageId, voter: &str, good: bool) -> Evaluation {
+        let mut messages = self.messages.write().unwrap();
+        let messages = &mut *messages;
+
+        let message = match messages.by_id.get_mut(&id) {
+            Some(message) => message,
+            None => return Evaluation::NotFound,
+        };
+
+        if message.steam_id == voter {
+            return Evaluation::OwnMessage;
+        }
+
+        if !messages
+            .voters
+            .entry(id)
+            .or_default()
+            .insert(voter.to_string())
+        {
+            return Evaluation::AlreadyEvaluated;
+        }
+
+        if good {
+            message.rating_good += 1;
+        } else {
+            message.rating_poor += 1;
+        }
+
+        self.dirty.mark();
+        Evaluation::Recorded(message.clone())
+    }
+
+    /// Every message written by `steam_id`.
+    pub fn owned_by(&self, steam_id: &str) -> Vec<BloodMessage> {
+        self.messages
+            .read()
+            .unwrap()
+            .by_id
+            .values()
+            .filter(|message| message.steam_id == steam_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Pick up to `count` messages from `online_area_id` that weren't written by `steam_id`,
+    /// favouring the better rated ones, and return them best rated first.
+    pub fn sample<R: Rng>(
+        &self,
+        online_area_id: u32,
+        steam_id: &str,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<BloodMessage> {
+        let messages = self.messages.read().unwrap();
+        let ids = match messages.by_area.get(&online_area_id) {
+            Some(ids) => ids,
+            None => return vec![],
+        };
+
+        // Weighted sampling without replacement: give each message a random key skewed by its
+        // weight and keep those with the largest keys.
+        let mut keyed: Vec<(f64, &BloodMessage)> = ids
+            .iter()
+            .filter_map(|id| messages.by_id.get(id))
+            .filter(|message| message.steam_id != steam_id)
+            .map(|message| (rng.gen::<f64>().powf(1.0 / message.weight()), message))
+            .collect();
+
+        keyed.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+        let mut sample: Vec<BloodMessage> = keyed
+            .into_iter()
+            .take(count)
+            .map(|(_, message)| message.clone())
+            .collect();
+
+        sample.sort_by_key(|message| Reverse(message.score()));
+        sample
+    }
+}
+
+pub async fn create_blood_message(
+    request: RequestContext,
+    body: RequestCreateBloodMessage,
+) -> Result<RequestCreateBloodMessageResponse> {
+    let character_name = request
+        .session()
+        .and_then(|session| session.character)
+        .map(|character| character.name)
+        .unwrap_or_default();
+
+    let message = BloodMessage {
+        id: 0,
+        steam_id: request.steam_id().to_string(),
+        character_name,
+        location: Location::from_request(
+            body.get_online_area_id(),
+            body.get_map_id(),
+            body.get_position(),
+            body.get_angle(),
+        )?,
+        text: from_proto_text(body.get_text()),
+        gesture_id: body.get_gesture_id(),
+        created: SystemTime::now(),
+        rating_good: 0,
+        rating_poor: 0,
+    };
+
+    let context = request.context();
+    let max_per_player = context.config().blood_messages().max_per_player();
+    let id = context.blood_messages().insert(message, max_per_player);
+
+    let mut response = RequestCreateBloodMessageResponse::new();
+    response.set_message_id(id);
+
+    Ok(response)
+}
+
+pub async fn get_blood_message_list(
+    request: RequestContext,
+    body: RequestGetBloodMessageList,
+) -> Result<RequestGetBloodMessageListResponse> {
+    let context = request.context();
+    let mut count = context.config().blood_messages().max_list_entries();
+    if body.has_max_entries() {
+        count = count.min(body.get_max_entries() as usize);
+    }
+
+    let messages = context.blood_messages().sample(
+        body.get_online_area_id(),
+        request.steam_id(),
+        count,
+        &mut rand::thread_rng(),
+    );
+
+    let mut response = RequestGetBloodMessageListResponse::new();
+    for message in &messages {
+        response.mut_messages().push(to_message_data(message));
+    }
+
+    Ok(response)
+}
+
+/// Called when a player logs back in with the IDs of the messages they placed previously. We
+/// answer with the ones that still exist, so the client can forget about the rest.
+pub async fn reentry_blood_message(
+    request: RequestContext,
+    body: RequestReentryBloodMessage,
+) -> Result<RequestReentryBloodMessageResponse> {
+    let owned: HashSet<BloodMessageId> = request
+        .context()
+        .blood_messages()
+        .owned_by(request.steam_id())
+        .into_iter()
+        .map(|message| message.id)
+        .collect();
+
+    let mut response = RequestReentryBloodMessageResponse::new();
+    for id in body.get_message_ids() {
+        if owned.contains(id) {
+            response.mut_message_ids().push(*id);
+        }
+    }
+
+    Ok(response)
+}
+
+pub async fn remove_blood_message(
+    request: RequestContext,
+    body: RequestRemoveBloodMessage,
+) -> Result<RequestRemoveBloodMessageResponse> {
+    request
+        .context()
+        .blood_messages()
+        .remove_owned(body.get_message_id(), request.steam_id())
+        .ok_or_else(|| RequestError::invalid("no such message"))?;
+
+    Ok(RequestRemoveBloodMessageResponse::new())
+}
+
+pub async fn evaluate_blood_message(
+    request: RequestContext,
+    body: RequestEvaluateBloodMessage,
+) -> Result<RequestEvaluateBloodMessageResponse> {
+    let context = request.context();
+    let message = match context.blood_messages().evaluate(
+        body.get_message_id(),
+        request.steam_id(),
+        body.get_good(),
+    ) {
+        Evaluation::Recorded(message) => message,
+        Evaluation::AlreadyEvaluated => {
+            return Err(RequestError::invalid("message has already been evaluated").into())
+        }
+        Evaluation::OwnMessage => {
+            return Err(RequestError::invalid("cannot evaluate own message").into())
+        }
+        Evaluation::NotFound => return Err(RequestError::invalid("no such message").into()),
+    };
+
+    // Let the author know, if they're around, so their client can heal them.
+    if let Some(author) = context.sessions().by_steam_id(&message.steam_id) {
+        let mut push = PushEvaluateBloodMessage::new();
+        push.set_message_id(message.id);
+        push.set_good(body.get_good());
+        push.set_rating_good(message.rating_good);
+        push.set_rating_poor(message.rating_poor);
+
+        if let Err(e) = author.push(MessageType::PushEvaluateBloodMessage, &push) {
+            debug!("Failed to notify author of evaluation: {}", e);
+        }
+    }
+
+    Ok(RequestEvaluateBloodMessageResponse::new())
+}
+
+fn from_proto_text(text: &BloodMessageText) -> MessageText {
+    let optional = |present: bool, value: u32| if present { Some(value) } else { None };
+
+    MessageText {
+        template_id: text.get_template_id(),
+        word_id: text.get_word_id(),
+        conjunction_id: optional(text.has_conjunction_id(), text.get_conjunction_id()),
+        second_template_id: optional(text.has_second_template_id(), text.get_second_template_id()),
+        second_word_id: optional(text.has_second_word_id(), text.get_second_word_id()),
+    }
+}
+
+fn to_proto_text(text: &MessageText) -> BloodMessageText {
+    let mut proto = BloodMessageText::new();
+    proto.set_template_id(text.template_id);
+    proto.set_word_id(text.word_id);
+
+    if let Some(conjunction_id) = text.conjunction_id {
+        proto.set_conjunction_id(conjunction_id);
+    }
+
+    if let Some(second_template_id) = text.second_template_id {
+        proto.set_second_template_id(second_template_id);
+    }
+
+    if let Some(second_word_id) = text.second_word_id {
+        proto.set_second_word_id(second_word_id);
+    }
+
+    proto
+}
+
+fn to_message_data(message: &BloodMessage) -> BloodMessageData {
+    let mut data = BloodMessageData::new();
+    data.set_message_id(message.id);
+    data.set_steam_id(message.steam_id.clone());
+    data.set_character_name(message.character_name.clone());
+    data.set_online_area_id(message.location.online_area_id);
+    data.set_map_id(message.location.map_id);
+    data.set_position(location::to_vector(message.location.position));
+    data.set_angle(message.location.angle);
+    data.set_text(to_proto_text(&message.text));
+    data.set_gesture_id(message.gesture_id);
+    data.set_rating_good(message.rating_good);
+    data.set_rating_poor(message.rating_poor);
+    data
+}
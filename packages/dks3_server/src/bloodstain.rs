@@ -0,0 +1,384 @@
+//! Bloodstains: left where a player died, holding a replay of their last moments that other
+//! players can watch by touching it.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde_derive::{Deserialize, Serialize};
+
+use dks3_proto::Frpg2RequestMessage::{
+    BloodstainData, RequestCreateBloodstain, RequestCreateBloodstainResponse,
+    RequestGetBloodstainList, RequestGetBloodstainListResponse, RequestGetBloodstainReplay,
+    RequestGetBloodstainReplayResponse,
+};
+
+use crate::dispatch::{RequestContext, RequestError};
+use crate::location::{self, Location};
+use crate::storage::{DirtyFlag, Storage};
+use crate::Result;
+
+const STORAGE_NAME: &str = "bloodstains";
+
+pub type BloodstainId = u64;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Bloodstain {
+    pub id: BloodstainId,
+    pub steam_id: String,
+    pub location: Location,
+    pub created: SystemTime,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Snapshot {
+    next_id: BloodstainId,
+    bloodstains: Vec<Bloodstain>,
+}
+
+#[derive(Debug, Default)]
+struct Bloodstains {
+    next_id: BloodstainId,
+    by_id: HashMap<BloodstainId, Bloodstain>,
+    by_area: HashMap<u32, HashSet<BloodstainId>>,
+    // Kept apart from the bloodstains themselves so listing an area doesn't copy every replay.
+    replays: HashMap<BloodstainId, Vec<u8>>,
+    /// Replays that haven't been written to storage yet.
+    unsaved: HashSet<BloodstainId>,
+    /// Replays that have been removed since the bloodstains were last saved.
+    removed: HashSet<BloodstainId>,
+}
+
+impl Bloodstains {
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            next_id: self.next_id,
+            bloodstains: self.by_id.values().cloned().collect(),
+        }
+    }
+
+    fn add(&mut self, bloodstain: Bloodstain) {
+        self.by_area
+            .entry(bloodstain.location.online_area_id)
+            .or_default()
+            .insert(bloodstain.id);
+        self.by_id.insert(bloodstain.id, bloodstain);
+    }
+
+    fn remove(&mut self, id: BloodstainId) -> Option<Bloodstain> {
+        let bloodstain = self.by_id.remove(&id)?;
+        let area = bloodstain.location.online_area_id;
+        self.replays.remove(&id);
+        if !self.unsaved.remove(&id) {
+            self.removed.insert(id);
+        }
+
+        if let Some(ids) = self.by_area.get_mut(&area) {
+            ids.remove(&id);
+            if ids.is_empty() {
+                self.by_area.remove(&area);
+            }
+        }
+
+        Some(bloodstain)
+    }
+
+    /// Remove the oldest bloodstains matching `predicate` until there are fewer than `max` left.
+    fn remove_oldest<P>(&mut self, max: usize, predicate: P)
+    where
+        P: Fn(&Bloodstain) -> bool,
+    {
+        let mut matching: Vec<(SystemTime, BloodstainId)> = self
+            .by_id
+            .values()
+            .filter(|bloodstain| predicate(bloodstain))
+            .map(|bloodstain| (bloodstain.created, bloodstain.id))
+            .collect();
+        matching.sort();
+
+        let excess = (matching.len() + 1).saturating_sub(max.max(1));
+        for (_, id) in matching.into_iter().take(excess) {
+            self.remove(id);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BloodstainStore {
+    bloodstains: RwLock<Bloodstains>,
+    dirty: DirtyFlag,
+}
+
+impl BloodstainStore {
+    /// Load the bloodstains saved in `storage`, along with their replays. Bloodstains whose
+    /// replay is missing are dropped, since there'd be nothing to watch.
+    pub fn load(storage: &Storage) -> Result<Self> {
+        let snapshot: Snapshot = storage.load(STORAGE_NAME)?.unwrap_or_default();
+        let mut bloodstains = Bloodstains {
+            next_id: snapshot.next_id,
+            ..Bloodstains::default()
+        };
+
+        for bloodstain in snapshot.bloodstains {
+            if let Some(replay) = storage.load_blob(STORAGE_NAME, &bloodstain.id.to_string())? {
+                bloodstains.replays.insert(bloodstain.id, replay);
+                bloodstains.add(bloodstain);
+            }
+        }
+
+        Ok(Self {
+            bloodstains: RwLock::new(bloodstains),
+            dirty: DirtyFlag::default(),
+        })
+    }
+
+    /// Save the bloodstains to `storage` if they have changed since they were last saved. Only
+    /// the replays uploaded since then are written.
+    pub fn save(&self, storage: &Storage) -> Result<()> {
+        if !self.dirty.take() {
+            return Ok(());
+        }
+
+        let (snapshot, unsaved, removed) = {
+            let mut guard = self.bloodstains.write().unwrap();
+            let bloodstains = &mut *guard;
+            let replays = &bloodstains.replays;
+            let unsaved: Vec<(BloodstainId, Vec<u8>)> = bloodstains
+                .unsaved
+                .drain()
+                .filter_map(|id| replays.get(&id).map(|replay| (id, replay.clone())))
+                .collect();
+            let removed = std::mem::take(&mut bloodstains.removed);
+
+            (bloodstains.snapshot(), unsaved, removed)
+        };
+
+        let result = save_all(storage, &snapshot, &unsaved, &removed);
+        if result.is_err() {
+            let mut bloodstains = self.bloodstains.write().unwrap();
+            bloodstains
+                .unsaved
+                .extend(unsaved.iter().map(|(id, _)| *id));
+            bloodstains.removed.extend(removed);
+            self.dirty.mark();
+        }
+
+        result
+    }
+
+    /// Store `bloodstain` and its replay under a newly assigned ID, which is returned. If its
+    /// player already has `max_per_player` bloodstains, or its area `max_per_area`, the oldest
+    /// are removed to make room.
+    pub fn insert(
+        &self,
+        mut bloodstain: Bloodstain,
+        replay: Vec<u8>,
+        max_per_player: usize,
+        max_per_area: usize,
+    ) -> BloodstainId {
+        let mut bloodstains = self.bloodstains.write().unwrap();
+        bloodstains.remove_oldest(max_per_player, |existing| {
+            existing.steam_id == bloodstain.steam_id
+        });
+        bloodstains.remove_oldest(max_per_area, |existing| {
+            existing.location.online_area_id == bloodstain.location.online_area_id
+        });
+
+        bloodstains.next_id += 1;
+        bloodstain.id = bloodstains.next_id;
+
+        let id = bloodstain.id;
+        bloodstains.add(bloodstain);
+        bloodstains.replays.insert(id, replay);
+        bloodstains.unsaved.insert(id);
+        self.dirty.mark();
+
+        id
+    }
+
+    pub fn get(&self, id: BloodstainId) -> Option<Bloodstain> {
+        self.bloodstains.read().unwrap().by_id.get(&id).cloned()
+    }
+
+    /// The replay uploaded with a bloodstain.
+    pub fn replay(&self, id: BloodstainId) -> Option<Vec<u8>> {
+        self.bloodstains.read().unwrap().replays.get(&id).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bloodstains.read().unwrap().by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove every bloodstain older than `max_age`, returning how many there were.
+    pub fn expire(&self, max_age: Duration) -> usize {
+        let now = SystemTime::now();
+        let mut bloodstains = self.bloodstains.write().unwrap();
+
+        let expired: Vec<BloodstainId> = bloodstains
+            .by_id
+            .values()
+            .filter(|bloodstain| {
+                matches!(now.duration_since(bloodstain.created), Ok(age) if age > max_age)
+            })
+            .map(|bloodstain| bloodstain.id)
+            .collect();
+
+        for id in &expired {
+            bloodstains.remove(*id);
+        }
+
+        if !expired.is_empty() {
+            self.dirty.mark();
+        }
+
+        expired.len()
+    }
+
+    /// Pick up to `count` bloodstains at random from `online_area_id` that weren't left by
+    /// `steam_id`.
+    pub fn sample<R: Rng>(
+        &self,
+        online_area_id: u32,
+        steam_id: &str,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<Bloodstain> {
+        let bloodstains = self.bloodstains.read().unwrap();
+        let ids = match bloodstains.by_area.get(&online_area_id) {
+            Some(ids) => ids,
+            None => return vec![],
+        };
+
+        let candidates: Vec<&Bloodstain> = ids
+            .iter()
+            .filter_map(|id| bloodstains.by_id.get(id))
+            .filter(|bloodstain| bloodstain.steam_id != steam_id)
+            .collect();
+
+        candidates
+            .choose_multiple(rng, count)
+            .map(|bloodstain| (*bloodstain).clone())
+            .collect()
+    }
+}
+
+/// Write the replays in `unsaved` before the snapshot that refers to them, and remove the ones in
+/// `removed` only once it no longer does.
+fn save_all(
+    storage: &Storage,
+    snapshot: &Snapshot,
+    unsaved: &[(BloodstainId, Vec<u8>)],
+    removed: &HashSet<BloodstainId>,
+) -> Result<()> {
+    for (id, replay) in unsaved {
+        storage.save_blob(STORAGE_NAME, &id.to_string(), replay)?;
+    }
+
+    storage.save(STORAGE_NAME, snapshot)?;
+
+    for id in removed {
+        storage.remove_blob(STORAGE_NAME, &id.to_string())?;
+    }
+
+    Ok(())
+}
+
+pub async fn create_bloodstain(
+    request: RequestContext,
+    mut body: RequestCreateBloodstain,
+) -> Result<RequestCreateBloodstainResponse> {
+    let context = request.context();
+    let config = context.config().bloodstains();
+    if body.get_replay_data().len() > config.max_replay_bytes() {
+        return Err(RequestError::invalid(format!(
+            "replay data is larger than {} bytes",
+            config.max_replay_bytes()
+        ))
+        .into());
+    }
+
+    let bloodstain = Bloodstain {
+        id: 0,
+        steam_id: request.steam_id().to_string(),
+        location: Location::from_request(
+            body.get_online_area_id(),
+            body.get_map_id(),
+            body.get_position(),
+            body.get_angle(),
+        )?,
+        created: SystemTime::now(),
+    };
+
+    let id = context.bloodstains().insert(
+        bloodstain,
+        body.take_replay_data(),
+        config.max_per_player(),
+        config.max_per_area(),
+    );
+
+    let mut response = RequestCreateBloodstainResponse::new();
+    response.set_bloodstain_id(id);
+
+    Ok(response)
+}
+
+pub async fn get_bloodstain_list(
+    request: RequestContext,
+    body: RequestGetBloodstainList,
+) -> Result<RequestGetBloodstainListResponse> {
+    let context = request.context();
+    let mut count = context.config().bloodstains().max_list_entries();
+    if body.has_max_entries() {
+        count = count.min(body.get_max_entries() as usize);
+    }
+
+    let bloodstains = context.bloodstains().sample(
+        body.get_online_area_id(),
+        request.steam_id(),
+        count,
+        &mut rand::thread_rng(),
+    );
+
+    let mut response = RequestGetBloodstainListResponse::new();
+    for bloodstain in &bloodstains {
+        response
+            .mut_bloodstains()
+            .push(to_bloodstain_data(bloodstain));
+    }
+
+    Ok(response)
+}
+
+pub async fn get_bloodstain_replay(
+    request: RequestContext,
+    body: RequestGetBloodstainReplay,
+) -> Result<RequestGetBloodstainReplayResponse> {
+    let replay = request
+        .context()
+        .bloodstains()
+        .replay(body.get_bloodstain_id())
+        .ok_or_else(|| RequestError::invalid("no such bloodstain"))?;
+
+    let mut response = RequestGetBloodstainReplayResponse::new();
+    response.set_bloodstain_id(body.get_bloodstain_id());
+    response.set_replay_data(replay);
+
+    Ok(response)
+}
+
+fn to_bloodstain_data(bloodstain: &Bloodstain) -> BloodstainData {
+    let mut data = BloodstainData::new();
+    data.set_bloodstain_id(bloodstain.id);
+    data.set_steam_id(bloodstain.steam_id.clone());
+    data.set_online_area_id(bloodstain.location.online_area_id);
+    data.set_map_id(bloodstain.location.map_id);
+    data.set_position(location::to_vector(bloodstain.location.position));
+    data.set_angle(bloodstain.location.angle);
+    data
+}
@@ -0,0 +1,65 @@
+//! Messages from admins shown to players while they are in-game, such as maintenance warnings
+//! and event notices, sent to everyone online or only to some of them.
+
+use serde_derive::Deserialize;
+use tracing::debug;
+
+use dks3_config::Covenant;
+use dks3_proto::Frpg2RequestMessage::PushBroadcastMessage;
+
+use crate::frame::MessageType;
+use crate::session::Session;
+use crate::ServerContext;
+
+/// Which players a broadcast is sent to. Each list that isn't empty narrows it down further, so
+/// the default filter matches everyone online.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct BroadcastFilter {
+    /// Only players whose character is in one of these online areas.
+    pub online_area_ids: Vec<u32>,
+    /// Only players whose character has one of these covenants equipped.
+    pub covenants: Vec<Covenant>,
+    /// Only the players with these Steam IDs.
+    pub steam_ids: Vec<String>,
+}
+
+impl BroadcastFilter {
+    pub fn matches(&self, session: &Session) -> bool {
+        let character = session.character.as_ref();
+
+        (self.online_area_ids.is_empty()
+            || character
+                .iter()
+                .any(|character| self.online_area_ids.contains(&character.online_area_id)))
+            && (self.covenants.is_empty()
+                || character
+                    .and_then(|character| character.covenant)
+                    .iter()
+                    .any(|covenant| self.covenants.contains(covenant)))
+            && (self.steam_ids.is_empty() || self.steam_ids.contains(&session.steam_id))
+    }
+}
+
+/// Push a message with `header` and `message` to every player matching `filter`, returning how
+/// many it was sent to.
+pub fn broadcast(
+    context: &ServerContext,
+    filter: &BroadcastFilter,
+    header: &str,
+    message: &str,
+) -> usize {
+    let mut push = PushBroadcastMessage::new();
+    push.set_header(header.to_string());
+    push.set_message(message.to_string());
+
+    let mut sent = 0;
+    for session in context.sessions().filter(|session| filter.matches(session)) {
+        match session.push(MessageType::PushBroadcastMessage, &push) {
+            Ok(()) => sent += 1,
+            Err(e) => debug!("Failed to push broadcast to {}: {}", session.steam_id, e),
+        }
+    }
+
+    sent
+}
@@ -0,0 +1,195 @@
+//! Covenants that summon their members into other players' worlds.
+//!
+//! Players tell the server which covenant they've joined when they equip its item, and wait in
+//! that covenant's queue. When a host is invaded, every visit policy that applies to them picks
+//! the members who have waited longest and are a match for the host, and pushes the visit to
+//! them. Members leave the queue once they've been sent somewhere, and register again when
+//! they're ready for another visit.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::sync::Mutex;
+
+use dks3_config::Covenant;
+use dks3_proto::Frpg2RequestMessage::{
+    PushRequestVisit, RequestRegisterCovenant, RequestRegisterCovenantResponse,
+    RequestUnregisterCovenant, RequestUnregisterCovenantResponse,
+};
+use tracing::info;
+
+use crate::dispatch::{RequestContext, RequestError};
+use crate::frame::MessageType;
+use crate::matchmaking::{self, MultiplayerType};
+use crate::session::{Session, SessionId};
+use crate::{Result, ServerContext};
+
+/// The covenant each player is in, and the order they joined each covenant's queue in.
+#[derive(Debug, Default)]
+pub struct CovenantRegistry {
+    members: Mutex<Members>,
+}
+
+#[derive(Debug, Default)]
+struct Members {
+    by_session: HashMap<SessionId, Covenant>,
+    queues: HashMap<Covenant, VecDeque<SessionId>>,
+}
+
+impl Members {
+    fn remove(&mut self, session_id: SessionId) -> Option<Covenant> {
+        let covenant = self.by_session.remove(&session_id)?;
+        if let Some(queue) = self.queues.get_mut(&covenant) {
+            queue.retain(|id| *id != session_id);
+        }
+
+        Some(covenant)
+    }
+}
+
+impl CovenantRegistry {
+    /// Put the player with `session_id` at the back of `covenant`'s queue, taking them out of
+    /// any other covenant they were in.
+    pub fn register(&self, session_id: SessionId, covenant: Covenant) {
+        let mut members = self.members.lock().unwrap();
+        members.remove(session_id);
+        members.by_session.insert(session_id, covenant);
+        members
+            .queues
+            .entry(covenant)
+            .or_default()
+            .push_back(session_id);
+    }
+
+    /// Take the player with `session_id` out of their covenant, returning which one it was.
+    pub fn unregister(&self, session_id: SessionId) -> Option<Covenant> {
+        self.members.lock().unwrap().remove(session_id)
+    }
+
+    pub fn covenant_of(&self, session_id: SessionId) -> Option<Covenant> {
+        self.members
+            .lock()
+            .unwrap()
+            .by_session
+            .get(&session_id)
+            .copied()
+    }
+
+    /// The members waiting in `covenant`'s queue, longest waiting first.
+    pub fn queued(&self, covenant: Covenant) -> Vec<SessionId> {
+        self.members
+            .lock()
+            .unwrap()
+            .queues
+            .get(&covenant)
+            .map(|queue| queue.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Take the player with `session_id` out of `covenant`'s queue to send them on a visit.
+    /// Returns `false` if they're no longer waiting in it.
+    pub fn take(&self, session_id: SessionId, covenant: Covenant) -> bool {
+        let mut members = self.members.lock().unwrap();
+        if members.by_session.get(&session_id) != Some(&covenant) {
+            return false;
+        }
+
+        members.remove(session_id);
+        true
+    }
+}
+
+/// Summon covenant members to defend `host`, who has just been invaded by the player with
+/// `invader`, according to the configured visit policies. Returns the sessions of the members
+/// who were sent.
+pub fn host_invaded(context: &ServerContext, host: &Session, invader: SessionId) -> Vec<SessionId> {
+    let character = match &host.character {
+        Some(character) => character,
+        None => return vec![],
+    };
+
+    // The covenant the host's client last reported, or failing that the one they're waiting
+    // in the queue of.
+    let host_covenant = character
+        .covenant
+        .or_else(|| context.covenants().covenant_of(host.id));
+    let host_level = character.level();
+    let mut visitors = vec![];
+
+    for policy in context.config().covenants().visit_policies() {
+        if !policy.applies_to(character.online_area_id, host_covenant) {
+            continue;
+        }
+
+        let mut push = PushRequestVisit::new();
+        push.set_covenant(policy.covenant().id());
+        push.set_host_steam_id(host.steam_id.clone());
+        push.set_host_character_name(character.name.clone());
+        push.set_online_area_id(character.online_area_id);
+
+        let mut sent = 0;
+        for session_id in context.covenants().queued(policy.covenant()) {
+            if sent == policy.max_visitors() {
+                break;
+            }
+
+            let visitor = match context.sessions().get(session_id) {
+                Some(visitor) if visitor.id != host.id && visitor.id != invader => visitor,
+                _ => continue,
+            };
+
+            let is_match = visitor.character.iter().any(|visitor| {
+                matchmaking::is_match(
+                    context.config().matchmaking(),
+                    MultiplayerType::Covenant,
+                    &visitor.level(),
+                    &host_level,
+                )
+            });
+
+            if !is_match || !context.covenants().take(session_id, policy.covenant()) {
+                continue;
+            }
+
+            if visitor.push(MessageType::PushRequestVisit, &push).is_ok() {
+                info!(
+                    host = host.steam_id.as_str(),
+                    visitor = visitor.steam_id.as_str(),
+                    "Summoning {:?} member",
+                    policy.covenant()
+                );
+
+                visitors.push(session_id);
+                sent += 1;
+            }
+        }
+    }
+
+    visitors
+}
+
+pub async fn register_covenant(
+    request: RequestContext,
+    body: RequestRegisterCovenant,
+) -> Result<RequestRegisterCovenantResponse> {
+    let covenant = Covenant::try_from(body.get_covenant())
+        .map_err(|id| RequestError::invalid(format!("unknown covenant {}", id)))?;
+
+    request
+        .context()
+        .covenants()
+        .register(request.session_id(), covenant);
+
+    Ok(RequestRegisterCovenantResponse::new())
+}
+
+pub async fn unregister_covenant(
+    request: RequestContext,
+    _body: RequestUnregisterCovenant,
+) -> Result<RequestUnregisterCovenantResponse> {
+    request
+        .context()
+        .covenants()
+        .unregister(request.session_id());
+
+    Ok(RequestUnregisterCovenantResponse::new())
+}
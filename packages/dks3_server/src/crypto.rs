@@ -0,0 +1,124 @@
+//! Encryption used by the login and auth handshakes.
+//!
+//! The client encrypts its first message to each server with the RSA public key the launcher
+//! patched into the game, using OAEP with SHA-1. Messages longer than a single RSA block are
+//! split into independently encrypted blocks the size of the key modulus.
+//!
+//! Once a session key has been exchanged the rest of the conversation is encrypted with
+//! AES-128 in CWC mode. Each packet is laid out as `nonce || tag || ciphertext`.
+
+use aead::generic_array::GenericArray;
+use aead::{AeadInPlace, NewAead};
+use cwc::Aes128Cwc;
+use rand::Rng;
+use rsa::{PaddingScheme, PublicKey, PublicKeyParts, RSAPrivateKey, RSAPublicKey};
+
+use crate::Result;
+
+pub const CWC_KEY_LEN: usize = 16;
+pub const CWC_NONCE_LEN: usize = 11;
+pub const CWC_TAG_LEN: usize = 16;
+
+/// The overhead OAEP padding with SHA-1 adds to each block.
+const OAEP_SHA1_OVERHEAD: usize = 2 * 20 + 2;
+
+fn padding() -> PaddingScheme {
+    PaddingScheme::new_oaep::<sha1::Sha1>()
+}
+
+pub fn rsa_decrypt(key: &RSAPrivateKey, data: &[u8]) -> Result<Vec<u8>> {
+    let block_size = key.size();
+    let blocks = data.chunks_exact(block_size);
+    if data.is_empty() || !blocks.remainder().is_empty() {
+        return Err(format!(
+            "RSA ciphertext length {} is not a multiple of the key size {}",
+            data.len(),
+            block_size
+        )
+        .into());
+    }
+
+    let mut plaintext = Vec::with_capacity(data.len());
+    for block in blocks {
+        plaintext.extend(key.decrypt(padding(), block)?);
+    }
+
+    Ok(plaintext)
+}
+
+pub fn rsa_encrypt<R: Rng>(rng: &mut R, key: &RSAPublicKey, data: &[u8]) -> Result<Vec<u8>> {
+    let block_size = key.size() - OAEP_SHA1_OVERHEAD;
+
+    let mut ciphertext = Vec::with_capacity(data.len() / block_size * key.size() + key.size());
+    for block in data.chunks(block_size) {
+        ciphertext.extend(key.encrypt(rng, padding(), block)?);
+    }
+
+    Ok(ciphertext)
+}
+
+#[derive(Clone)]
+pub struct CwcCipher {
+    cipher: Aes128Cwc,
+}
+
+impl CwcCipher {
+    pub fn new(key: &[u8]) -> Result<Self> {
+        if key.len() != CWC_KEY_LEN {
+            return Err(format!("CWC key must be {} bytes, got {}", CWC_KEY_LEN, key.len()).into());
+        }
+
+        Ok(Self {
+            cipher: Aes128Cwc::new(GenericArray::from_slice(key)),
+        })
+    }
+
+    pub fn encrypt<R: Rng>(&self, rng: &mut R, plaintext: &[u8]) -> Result<Vec<u8>> {
+        self.seal(rng, &[], plaintext)
+    }
+
+    pub fn decrypt(&self, packet: &[u8]) -> Result<Vec<u8>> {
+        self.open(&[], packet)
+    }
+
+    /// Encrypt `plaintext`, authenticating it along with the unencrypted `aad`.
+    pub fn seal<R: Rng>(&self, rng: &mut R, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0u8; CWC_NONCE_LEN];
+        rng.fill(&mut nonce[..]);
+
+        let mut buffer = plaintext.to_vec();
+        let tag = self
+            .cipher
+            .encrypt_in_place_detached(GenericArray::from_slice(&nonce), aad, &mut buffer)
+            .map_err(|_| "CWC encryption failed")?;
+
+        let mut packet = Vec::with_capacity(CWC_NONCE_LEN + CWC_TAG_LEN + buffer.len());
+        packet.extend_from_slice(&nonce);
+        packet.extend_from_slice(&tag);
+        packet.extend(buffer);
+
+        Ok(packet)
+    }
+
+    /// Decrypt `packet`, verifying that it was sealed along with `aad`.
+    pub fn open(&self, aad: &[u8], packet: &[u8]) -> Result<Vec<u8>> {
+        if packet.len() < CWC_NONCE_LEN + CWC_TAG_LEN {
+            return Err(format!("CWC packet is too short ({} bytes)", packet.len()).into());
+        }
+
+        let (nonce, rest) = packet.split_at(CWC_NONCE_LEN);
+        let (tag, ciphertext) = rest.split_at(CWC_TAG_LEN);
+
+        let mut buffer = ciphertext.to_vec();
+        self.cipher
+            .decrypt_in_place_detached(
+                GenericArray::from_slice(nonce),
+                aad,
+                &mut buffer,
+                GenericArray::from_slice(tag),
+            )
+            .map_err(|_| "CWC packet failed authentication")?;
+
+        Ok(buffer)
+    }
+}
@@ -0,0 +1,524 @@
+//! Routing of game server requests to the handlers that answer them.
+//!
+//! A [`Dispatcher`] maps each [`MessageType`] to a [`Handler`] for the protobuf message that
+//! type carries. Requests pass through a stack of [`Middleware`] on the way, and any request that
+//! fails, including those nothing is registered for, is answered with a `RequestErrorResponse`
+//! instead of a reply.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tracing::{debug, info_span, warn};
+use tracing_futures::Instrument;
+
+use dks3_config::Sessions;
+use dks3_proto::Frpg2RequestMessage::RequestErrorResponse;
+
+use crate::frame::{Message, MessageType};
+use crate::session::{Session, SessionId};
+use crate::{Result, ServerContext};
+
+/// Why a request failed, sent to the client in a `RequestErrorResponse`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    UnknownRequest = 1,
+    InvalidRequest = 2,
+    Unauthorized = 3,
+    RateLimited = 4,
+    Internal = 5,
+}
+
+impl ErrorCode {
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+}
+
+impl TryFrom<u32> for ErrorCode {
+    type Error = u32;
+
+    fn try_from(id: u32) -> std::result::Result<Self, Self::Error> {
+        Ok(match id {
+            1 => ErrorCode::UnknownRequest,
+            2 => ErrorCode::InvalidRequest,
+            3 => ErrorCode::Unauthorized,
+            4 => ErrorCode::RateLimited,
+            5 => ErrorCode::Internal,
+            _ => return Err(id),
+        })
+    }
+}
+
+/// An error that should be reported to the client as-is. Any other error a handler returns is
+/// reported as [`ErrorCode::Internal`] without any further detail.
+#[derive(Debug)]
+pub struct RequestError {
+    code: ErrorCode,
+    reason: String,
+}
+
+impl RequestError {
+    pub fn new<S: Into<String>>(code: ErrorCode, reason: S) -> Self {
+        Self {
+            code,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn invalid<S: Into<String>>(reason: S) -> Self {
+        Self::new(ErrorCode::InvalidRequest, reason)
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.reason)
+    }
+}
+
+impl std::error::Error for RequestError {}
+
+/// The session a request arrived on.
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    context: ServerContext,
+    session_id: SessionId,
+    steam_id: String,
+    peer_addr: SocketAddr,
+}
+
+impl RequestContext {
+    pub fn new(
+        context: ServerContext,
+        session_id: SessionId,
+        steam_id: String,
+        peer_addr: SocketAddr,
+    ) -> Self {
+        Self {
+            context,
+            session_id,
+            steam_id,
+            peer_addr,
+        }
+    }
+
+    pub fn context(&self) -> &ServerContext {
+        &self.context
+    }
+
+    pub fn session_id(&self) -> SessionId {
+        self.session_id
+    }
+
+    pub fn steam_id(&self) -> &str {
+        &self.steam_id
+    }
+
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer_addr
+    }
+
+    /// A snapshot of the session, or `None` if it has since been evicted.
+    pub fn session(&self) -> Option<Session> {
+        self.context.sessions().get(self.session_id)
+    }
+}
+
+/// Answers requests carrying a `Req`.
+///
+/// This is implemented for any `async fn(RequestContext, Req) -> Result<Response>`, so simple
+/// handlers can be plain functions.
+#[async_trait]
+pub trait Handler<Req>: Send + Sync + 'static {
+    type Response: protobuf::Message;
+
+    async fn handle(&self, request: &RequestContext, body: Req) -> Result<Self::Response>;
+}
+
+#[async_trait]
+impl<F, Fut, Req, Resp> Handler<Req> for F
+where
+    F: Fn(RequestContext, Req) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Resp>> + Send,
+    Req: Send + 'static,
+    Resp: protobuf::Message,
+{
+    type Response = Resp;
+
+    async fn handle(&self, request: &RequestContext, body: Req) -> Result<Resp> {
+        self(request.clone(), body).await
+    }
+}
+
+/// Wraps every request passing through a [`Dispatcher`], whether or not it has a handler.
+#[async_trait]
+pub trait Middleware: Send + Sync + 'static {
+    async fn handle(
+        &self,
+        request: &RequestContext,
+        message: &Message,
+        next: Next<'_>,
+    ) -> Result<Message>;
+}
+
+/// The remainder of the middleware stack, ending with the request's handler.
+pub struct Next<'a> {
+    endpoint: &'a dyn Endpoint,
+    middleware: &'a [Box<dyn Middleware>],
+}
+
+impl Next<'_> {
+    pub async fn run(self, request: &RequestContext, message: &Message) -> Result<Message> {
+        match self.middleware.split_first() {
+            Some((current, middleware)) => {
+                let next = Next {
+                    endpoint: self.endpoint,
+                    middleware,
+                };
+
+                current.handle(request, message, next).await
+            }
+            None => self.endpoint.call(request, message).await,
+        }
+    }
+}
+
+#[async_trait]
+trait Endpoint: Send + Sync {
+    async fn call(&self, request: &RequestContext, message: &Message) -> Result<Message>;
+}
+
+struct Route<Req, H> {
+    handler: H,
+    _request: PhantomData<fn() -> Req>,
+}
+
+#[async_trait]
+impl<Req, H> Endpoint for Route<Req, H>
+where
+    Req: protobuf::Message,
+    H: Handler<Req>,
+{
+    async fn call(&self, request: &RequestContext, message: &Message) -> Result<Message> {
+        let body = message
+            .decode::<Req>()
+            .map_err(|e| RequestError::invalid(e.to_string()))?;
+        let response = self.handler.handle(request, body).await?;
+
+        Message::reply(message.index(), &response)
+    }
+}
+
+struct Unhandled;
+
+#[async_trait]
+impl Endpoint for Unhandled {
+    async fn call(&self, _request: &RequestContext, message: &Message) -> Result<Message> {
+        Err(RequestError::new(
+            ErrorCode::UnknownRequest,
+            format!(
+                "no handler for message type {:#x}",
+                message.raw_message_type()
+            ),
+        )
+        .into())
+    }
+}
+
+#[derive(Default)]
+pub struct Dispatcher {
+    routes: HashMap<u32, Box<dyn Endpoint>>,
+    middleware: Vec<Box<dyn Middleware>>,
+}
+
+impl Dispatcher {
+    /// Route requests of `message_type` to `handler`, replacing any previous handler.
+    pub fn register<Req, H>(&mut self, message_type: MessageType, handler: H) -> &mut Self
+    where
+        Req: protobuf::Message,
+        H: Handler<Req>,
+    {
+        let route = Route {
+            handler,
+            _request: PhantomData,
+        };
+
+        self.routes.insert(message_type.id(), Box::new(route));
+        self
+    }
+
+    /// Add `middleware` to the stack. Middleware added first sees requests first.
+    pub fn wrap<M: Middleware>(&mut self, middleware: M) -> &mut Self {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+
+    pub fn handles(&self, message_type: MessageType) -> bool {
+        self.routes.contains_key(&message_type.id())
+    }
+
+    /// Handle `message`, returning the reply or error to send back to the client.
+    pub async fn dispatch(&self, request: &RequestContext, message: &Message) -> Result<Message> {
+        let endpoint = self
+            .routes
+            .get(&message.raw_message_type())
+            .map_or(&Unhandled as &dyn Endpoint, |route| route.as_ref());
+
+        let next = Next {
+            endpoint,
+            middleware: &self.middleware,
+        };
+
+        match next.run(request, message).await {
+            Ok(reply) => Ok(reply),
+            Err(e) => error_reply(message, &*e),
+        }
+    }
+}
+
+impl fmt::Debug for Dispatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispatcher")
+            .field("routes", &self.routes.keys().collect::<Vec<_>>())
+            .field("middleware", &self.middleware.len())
+            .finish()
+    }
+}
+
+fn error_reply(message: &Message, error: &(dyn std::error::Error + 'static)) -> Result<Message> {
+    let mut response = RequestErrorResponse::new();
+    response.set_message_type(message.raw_message_type());
+
+    match error.downcast_ref::<RequestError>() {
+        Some(error) => {
+            response.set_code(error.code().id());
+            response.set_reason(error.reason().to_string());
+        }
+        None => {
+            warn!(
+                "Request of type {:#x} failed: {}",
+                message.raw_message_type(),
+                error
+            );
+            response.set_code(ErrorCode::Internal.id());
+        }
+    }
+
+    Message::new(MessageType::ErrorReply, message.index(), &response)
+}
+
+/// Rejects requests from sessions that have been evicted from the session registry.
+#[derive(Debug, Default)]
+pub struct RequireSession;
+
+#[async_trait]
+impl Middleware for RequireSession {
+    async fn handle(
+        &self,
+        request: &RequestContext,
+        message: &Message,
+        next: Next<'_>,
+    ) -> Result<Message> {
+        if !request.context().sessions().contains(request.session_id()) {
+            return Err(RequestError::new(ErrorCode::Unauthorized, "session has expired").into());
+        }
+
+        next.run(request, message).await
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Limits each session to a sustained rate of requests, allowing short bursts above it.
+#[derive(Debug)]
+pub struct RateLimit {
+    rate: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<SessionId, TokenBucket>>,
+}
+
+impl RateLimit {
+    pub fn new(rate: u32, burst: u32) -> Self {
+        Self {
+            rate: f64::from(rate),
+            burst: f64::from(burst.max(1)),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn from_config(sessions: &Sessions) -> Self {
+        Self::new(sessions.request_rate(), sessions.request_burst())
+    }
+
+    fn try_acquire(&self, context: &ServerContext, session_id: SessionId) -> bool {
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() > 2 * context.sessions().len() {
+            buckets.retain(|id, _| context.sessions().contains(*id));
+        }
+
+        let now = Instant::now();
+        let burst = self.burst;
+        let bucket = buckets.entry(session_id).or_insert(TokenBucket {
+            tokens: burst,
+            updated: now,
+        });
+
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(burst);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for RateLimit {
+    async fn handle(
+        &self,
+        request: &RequestContext,
+        message: &Message,
+        next: Next<'_>,
+    ) -> Result<Message> {
+        if !self.try_acquire(request.context(), request.session_id()) {
+            return Err(RequestError::new(ErrorCode::RateLimited, "too many requests").into());
+        }
+
+        next.run(request, message).await
+    }
+}
+
+/// Runs each request in a span identifying it, logging how it went.
+#[derive(Debug, Default)]
+pub struct Trace;
+
+#[async_trait]
+impl Middleware for Trace {
+    async fn handle(
+        &self,
+        request: &RequestContext,
+        message: &Message,
+        next: Next<'_>,
+    ) -> Result<Message> {
+        let span = info_span!(
+            "request",
+            message_type = message.raw_message_type(),
+            index = message.index()
+        );
+
+        async move {
+            let start = Instant::now();
+            let result = next.run(request, message).await;
+
+            match &result {
+                Ok(_) => debug!("Handled request in {:?}", start.elapsed()),
+                Err(e) => debug!("Request failed after {:?}: {}", start.elapsed(), e),
+            }
+
+            result
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+/// Counters for requests of a single message type.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RequestStats {
+    pub count: u64,
+    pub errors: u64,
+    pub total_time: Duration,
+}
+
+/// Request counters for every message type, collected by the [`Metrics`] middleware. Requests
+/// with a type the server doesn't know are counted together, so clients can't grow this without
+/// bound by sending made-up types.
+#[derive(Debug, Default)]
+pub struct RequestMetrics {
+    stats: Mutex<HashMap<Option<MessageType>, RequestStats>>,
+}
+
+impl RequestMetrics {
+    fn record(&self, message_type: Option<MessageType>, elapsed: Duration, succeeded: bool) {
+        let mut stats = self.stats.lock().unwrap();
+        let entry = stats.entry(message_type).or_default();
+
+        entry.count += 1;
+        entry.total_time += elapsed;
+        if !succeeded {
+            entry.errors += 1;
+        }
+    }
+
+    /// The counters for `message_type`, including types nothing handles.
+    pub fn get(&self, message_type: MessageType) -> RequestStats {
+        self.stats_for(Some(message_type))
+    }
+
+    /// The counters for every request with a type the server doesn't know.
+    pub fn unknown(&self) -> RequestStats {
+        self.stats_for(None)
+    }
+
+    /// The counters for every type seen so far, with unknown types under `None`.
+    pub fn snapshot(&self) -> HashMap<Option<MessageType>, RequestStats> {
+        self.stats.lock().unwrap().clone()
+    }
+
+    fn stats_for(&self, message_type: Option<MessageType>) -> RequestStats {
+        self.stats
+            .lock()
+            .unwrap()
+            .get(&message_type)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Records every request in the server's [`RequestMetrics`].
+#[derive(Debug, Default)]
+pub struct Metrics;
+
+#[async_trait]
+impl Middleware for Metrics {
+    async fn handle(
+        &self,
+        request: &RequestContext,
+        message: &Message,
+        next: Next<'_>,
+    ) -> Result<Message> {
+        let start = Instant::now();
+        let result = next.run(request, message).await;
+
+        request.context().request_metrics().record(
+            message.message_type(),
+            start.elapsed(),
+            result.is_ok(),
+        );
+
+        result
+    }
+}
@@ -0,0 +1,319 @@
+//! Framing shared by the TCP based login and auth servers.
+//!
+//! Every packet on the wire is prefixed with its length as a big-endian `u16`. Once unwrapped
+//! (and decrypted, where applicable) a packet holds a [`Message`]: an 8 byte header naming the
+//! message type and the index of the request it belongs to, followed by a protobuf payload.
+
+use std::convert::TryFrom;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use futures::{SinkExt, StreamExt};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_util::codec::{Framed, LengthDelimitedCodec};
+
+use crate::crypto::CwcCipher;
+use crate::Result;
+
+pub const HEADER_LEN: usize = 8;
+
+/// The index of messages the server sends of its own accord, which answer no request.
+pub const PUSH_INDEX: u32 = 0;
+
+pub fn packet_codec() -> LengthDelimitedCodec {
+    LengthDelimitedCodec::builder()
+        .length_field_length(2)
+        .new_codec()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MessageType {
+    Reply = 0x0000,
+    KeyMaterial = 0x0001,
+    GetServiceStatus = 0x0002,
+    SteamTicket = 0x0003,
+    RequestQueryLoginServerInfo = 0x0005,
+    RequestHandshake = 0x0006,
+    RequestGetAnnounceMessageList = 0x0100,
+    RequestCreateBloodMessage = 0x0110,
+    RequestGetBloodMessageList = 0x0111,
+    RequestReentryBloodMessage = 0x0112,
+    RequestRemoveBloodMessage = 0x0113,
+    RequestEvaluateBloodMessage = 0x0114,
+    RequestCreateBloodstain = 0x0120,
+    RequestGetBloodstainList = 0x0121,
+    RequestGetBloodstainReplay = 0x0122,
+    RequestCreateGhostData = 0x0130,
+    RequestGetGhostDataList = 0x0131,
+    RequestCreateSign = 0x0140,
+    RequestRemoveSign = 0x0141,
+    RequestGetSignList = 0x0142,
+    RequestSummonSign = 0x0143,
+    RequestRespondSummonSign = 0x0144,
+    RequestGetBreakInTargetList = 0x0150,
+    RequestBreakInTarget = 0x0151,
+    RequestUpdatePlayerStatus = 0x0160,
+    RequestCountAreaPlayers = 0x0161,
+    RequestGetRightMatchingArea = 0x0162,
+    RequestRegisterCovenant = 0x0170,
+    RequestUnregisterCovenant = 0x0171,
+    RequestRegisterQuickMatch = 0x0180,
+    RequestUnregisterQuickMatch = 0x0181,
+    RequestSearchQuickMatch = 0x0182,
+    RequestJoinQuickMatch = 0x0183,
+    RequestAcceptQuickMatch = 0x0184,
+    RequestStartQuickMatch = 0x0185,
+    RequestSendQuickMatchResult = 0x0186,
+    RequestGetArenaLadder = 0x0187,
+    RequestRegisterRanking = 0x0190,
+    RequestGetCurrentRank = 0x0191,
+    RequestCountRanking = 0x0192,
+    RequestGetRankingPage = 0x0193,
+    RequestNotifyDeath = 0x01a0,
+    RequestNotifyKillEnemy = 0x01a1,
+    RequestNotifyKillBoss = 0x01a2,
+    RequestNotifyKillPlayer = 0x01a3,
+    RequestNotifyBuyItem = 0x01a4,
+    RequestNotifyJoinMultiplayer = 0x01a5,
+    RequestNotifyLeaveMultiplayer = 0x01a6,
+    RequestMeasureUploadBandwidth = 0x01b0,
+    RequestMeasureDownloadBandwidth = 0x01b1,
+
+    PushEvaluateBloodMessage = 0x0800,
+    PushRequestSummonSign = 0x0801,
+    PushSummonSignResult = 0x0802,
+    PushRequestBreakIn = 0x0803,
+    PushRequestVisit = 0x0804,
+    PushRequestJoinQuickMatch = 0x0805,
+    PushJoinQuickMatchResult = 0x0806,
+    PushStartQuickMatch = 0x0807,
+    PushQuickMatchDissolved = 0x0808,
+    PushBroadcastMessage = 0x0809,
+
+    /// Sent in place of a reply when a request could not be handled.
+    ErrorReply = 0xffff,
+}
+
+impl MessageType {
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+}
+
+impl TryFrom<u32> for MessageType {
+    type Error = u32;
+
+    fn try_from(id: u32) -> std::result::Result<Self, Self::Error> {
+        Ok(match id {
+            0x0000 => MessageType::Reply,
+            0x0001 => MessageType::KeyMaterial,
+            0x0002 => MessageType::GetServiceStatus,
+            0x0003 => MessageType::SteamTicket,
+            0x0005 => MessageType::RequestQueryLoginServerInfo,
+            0x0006 => MessageType::RequestHandshake,
+            0x0100 => MessageType::RequestGetAnnounceMessageList,
+            0x0110 => MessageType::RequestCreateBloodMessage,
+            0x0111 => MessageType::RequestGetBloodMessageList,
+            0x0112 => MessageType::RequestReentryBloodMessage,
+            0x0113 => MessageType::RequestRemoveBloodMessage,
+            0x0114 => MessageType::RequestEvaluateBloodMessage,
+            0x0120 => MessageType::RequestCreateBloodstain,
+            0x0121 => MessageType::RequestGetBloodstainList,
+            0x0122 => MessageType::RequestGetBloodstainReplay,
+            0x0130 => MessageType::RequestCreateGhostData,
+            0x0131 => MessageType::RequestGetGhostDataList,
+            0x0140 => MessageType::RequestCreateSign,
+            0x0141 => MessageType::RequestRemoveSign,
+            0x0142 => MessageType::RequestGetSignList,
+            0x0143 => MessageType::RequestSummonSign,
+            0x0144 => MessageType::RequestRespondSummonSign,
+            0x0150 => MessageType::RequestGetBreakInTargetList,
+            0x0151 => MessageType::RequestBreakInTarget,
+            0x0160 => MessageType::RequestUpdatePlayerStatus,
+            0x0161 => MessageType::RequestCountAreaPlayers,
+            0x0162 => MessageType::RequestGetRightMatchingArea,
+            0x0170 => MessageType::RequestRegisterCovenant,
+            0x0171 => MessageType::RequestUnregisterCovenant,
+            0x0180 => MessageType::RequestRegisterQuickMatch,
+            0x0181 => MessageType::RequestUnregisterQuickMatch,
+            0x0182 => MessageType::RequestSearchQuickMatch,
+            0x0183 => MessageType::RequestJoinQuickMatch,
+            0x0184 => MessageType::RequestAcceptQuickMatch,
+            0x0185 => MessageType::RequestStartQuickMatch,
+            0x0186 => MessageType::RequestSendQuickMatchResult,
+            0x0187 => MessageType::RequestGetArenaLadder,
+            0x0190 => MessageType::RequestRegisterRanking,
+            0x0191 => MessageType::RequestGetCurrentRank,
+            0x0192 => MessageType::RequestCountRanking,
+            0x0193 => MessageType::RequestGetRankingPage,
+            0x01a0 => MessageType::RequestNotifyDeath,
+            0x01a1 => MessageType::RequestNotifyKillEnemy,
+            0x01a2 => MessageType::RequestNotifyKillBoss,
+            0x01a3 => MessageType::RequestNotifyKillPlayer,
+            0x01a4 => MessageType::RequestNotifyBuyItem,
+            0x01a5 => MessageType::RequestNotifyJoinMultiplayer,
+            0x01a6 => MessageType::RequestNotifyLeaveMultiplayer,
+            0x01b0 => MessageType::RequestMeasureUploadBandwidth,
+            0x01b1 => MessageType::RequestMeasureDownloadBandwidth,
+            0x0800 => MessageType::PushEvaluateBloodMessage,
+            0x0801 => MessageType::PushRequestSummonSign,
+            0x0802 => MessageType::PushSummonSignResult,
+            0x0803 => MessageType::PushRequestBreakIn,
+            0x0804 => MessageType::PushRequestVisit,
+            0x0805 => MessageType::PushRequestJoinQuickMatch,
+            0x0806 => MessageType::PushJoinQuickMatchResult,
+            0x0807 => MessageType::PushStartQuickMatch,
+            0x0808 => MessageType::PushQuickMatchDissolved,
+            0x0809 => MessageType::PushBroadcastMessage,
+            0xffff => MessageType::ErrorReply,
+            _ => return Err(id),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    message_type: u32,
+    index: u32,
+    payload: Bytes,
+}
+
+impl Message {
+    pub fn new<M>(message_type: MessageType, index: u32, payload: &M) -> Result<Self>
+    where
+        M: protobuf::Message,
+    {
+        Ok(Self {
+            message_type: message_type.id(),
+            index,
+            payload: Bytes::from(payload.write_to_bytes()?),
+        })
+    }
+
+    /// Create a response to the request with the given `index`.
+    pub fn reply<M>(index: u32, payload: &M) -> Result<Self>
+    where
+        M: protobuf::Message,
+    {
+        Self::new(MessageType::Reply, index, payload)
+    }
+
+    /// Create a message pushed to a client without it being asked for.
+    pub fn push<M>(message_type: MessageType, payload: &M) -> Result<Self>
+    where
+        M: protobuf::Message,
+    {
+        Self::new(message_type, PUSH_INDEX, payload)
+    }
+
+    /// Parse a message from a decrypted packet.
+    pub fn parse(mut data: Bytes) -> Result<Self> {
+        if data.len() < HEADER_LEN {
+            return Err(format!("message is too short ({} bytes)", data.len()).into());
+        }
+
+        let message_type = data.get_u32();
+        let index = data.get_u32();
+
+        Ok(Self {
+            message_type,
+            index,
+            payload: data,
+        })
+    }
+
+    pub fn encode(&self) -> Bytes {
+        let mut data = BytesMut::with_capacity(HEADER_LEN + self.payload.len());
+        data.put_u32(self.message_type);
+        data.put_u32(self.index);
+        data.put_slice(&self.payload);
+        data.freeze()
+    }
+
+    /// Fail unless this message is of the `expected` type.
+    pub fn expect(&self, expected: MessageType) -> Result<()> {
+        if self.message_type() == Some(expected) {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected {:?}, got message type {:#x}",
+                expected, self.message_type
+            )
+            .into())
+        }
+    }
+
+    pub fn decode<M>(&self) -> Result<M>
+    where
+        M: protobuf::Message,
+    {
+        let mut message = M::new();
+        message.merge_from_bytes(&self.payload)?;
+        message.check_initialized()?;
+
+        Ok(message)
+    }
+
+    /// The type of this message, or `None` if it isn't one we know about.
+    pub fn message_type(&self) -> Option<MessageType> {
+        MessageType::try_from(self.message_type).ok()
+    }
+
+    pub fn raw_message_type(&self) -> u32 {
+        self.message_type
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn is_push(&self) -> bool {
+        self.index == PUSH_INDEX
+    }
+
+    pub fn payload(&self) -> &Bytes {
+        &self.payload
+    }
+}
+
+/// A length delimited stream of [`Message`]s encrypted with a CWC session key.
+pub struct CwcFramed<T> {
+    framed: Framed<T, LengthDelimitedCodec>,
+    cipher: CwcCipher,
+}
+
+impl<T> CwcFramed<T>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    pub fn new(framed: Framed<T, LengthDelimitedCodec>, cipher: CwcCipher) -> Self {
+        Self { framed, cipher }
+    }
+
+    pub async fn send(&mut self, message: &Message) -> Result<()> {
+        let packet = self
+            .cipher
+            .encrypt(&mut rand::thread_rng(), &message.encode())?;
+
+        self.framed.send(Bytes::from(packet)).await?;
+
+        Ok(())
+    }
+
+    /// Receive the next message, or `None` if the peer closed the connection.
+    pub async fn receive(&mut self) -> Result<Option<Message>> {
+        let packet = match self.framed.next().await {
+            Some(packet) => packet?,
+            None => return Ok(None),
+        };
+
+        let plaintext = self.cipher.decrypt(&packet)?;
+        Ok(Some(Message::parse(plaintext.into())?))
+    }
+
+    /// Receive the next message, treating a closed connection as an error.
+    pub async fn expect_message(&mut self) -> Result<Message> {
+        self.receive()
+            .await?
+            .ok_or_else(|| "connection closed unexpectedly".into())
+    }
+}
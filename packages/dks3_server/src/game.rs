@@ -0,0 +1,305 @@
+//! The game server accepts the reliable UDP connections clients open once they've been
+//! through the auth server, and tracks each one as a [`Session`]. Every payload a client sends
+//! holds a single [`Message`], which is routed to its handler by the [`Dispatcher`].
+//!
+//! [`Session`]: crate::session::Session
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use tokio::net::ToSocketAddrs;
+use tracing::{info, info_span, warn};
+use tracing_futures::Instrument;
+
+use crate::announce;
+use crate::area;
+use crate::auth::AuthTicket;
+use crate::bandwidth;
+use crate::blood_message;
+use crate::bloodstain;
+use crate::covenant;
+use crate::dispatch::{Dispatcher, Metrics, RateLimit, RequestContext, RequireSession, Trace};
+use crate::frame::{Message, MessageType};
+use crate::ghost;
+use crate::invasion;
+use crate::leaderboard;
+use crate::player;
+use crate::quick_match;
+use crate::rating;
+use crate::session::NewSession;
+use crate::sign;
+use crate::telemetry;
+use crate::transport::{Connection, Listener};
+use crate::{Result, ServerContext};
+
+/// Build the dispatcher serving every request the game server understands.
+pub fn dispatcher(context: &ServerContext) -> Dispatcher {
+    let mut dispatcher = Dispatcher::default();
+    dispatcher
+        .wrap(Trace)
+        .wrap(Metrics)
+        .wrap(RequireSession)
+        .wrap(RateLimit::from_config(context.config().sessions()))
+        .register(
+            MessageType::RequestGetAnnounceMessageList,
+            announce::get_announce_message_list,
+        )
+        .register(
+            MessageType::RequestCreateBloodMessage,
+            blood_message::create_blood_message,
+        )
+        .register(
+            MessageType::RequestGetBloodMessageList,
+            blood_message::get_blood_message_list,
+        )
+        .register(
+            MessageType::RequestReentryBloodMessage,
+            blood_message::reentry_blood_message,
+        )
+        .register(
+            MessageType::RequestRemoveBloodMessage,
+            blood_message::remove_blood_message,
+        )
+        .register(
+            MessageType::RequestEvaluateBloodMessage,
+            blood_message::evaluate_blood_message,
+        )
+        .register(
+            MessageType::RequestCreateBloodstain,
+            bloodstain::create_bloodstain,
+        )
+        .register(
+            MessageType::RequestGetBloodstainList,
+            bloodstain::get_bloodstain_list,
+        )
+        .register(
+            MessageType::RequestGetBloodstainReplay,
+            bloodstain::get_bloodstain_replay,
+        )
+        .register(
+            MessageType::RequestCreateGhostData,
+            ghost::create_ghost_data,
+        )
+        .register(
+            MessageType::RequestGetGhostDataList,
+            ghost::get_ghost_data_list,
+        )
+        .register(MessageType::RequestCreateSign, sign::create_sign)
+        .register(MessageType::RequestRemoveSign, sign::remove_sign)
+        .register(MessageType::RequestGetSignList, sign::get_sign_list)
+        .register(MessageType::RequestSummonSign, sign::summon_sign)
+        .register(
+            MessageType::RequestRespondSummonSign,
+            sign::respond_summon_sign,
+        )
+        .register(
+            MessageType::RequestGetBreakInTargetList,
+            invasion::get_break_in_target_list,
+        )
+        .register(MessageType::RequestBreakInTarget, invasion::break_in_target)
+        .register(
+            MessageType::RequestUpdatePlayerStatus,
+            player::update_player_status,
+        )
+        .register(
+            MessageType::RequestCountAreaPlayers,
+            area::count_area_players,
+        )
+        .register(
+            MessageType::RequestGetRightMatchingArea,
+            area::get_right_matching_area,
+        )
+        .register(
+            MessageType::RequestRegisterCovenant,
+            covenant::register_covenant,
+        )
+        .register(
+            MessageType::RequestUnregisterCovenant,
+            covenant::unregister_covenant,
+        )
+        .register(
+            MessageType::RequestRegisterQuickMatch,
+            quick_match::register_quick_match,
+        )
+        .register(
+            MessageType::RequestUnregisterQuickMatch,
+            quick_match::unregister_quick_match,
+        )
+        .register(
+            MessageType::RequestSearchQuickMatch,
+            quick_match::search_quick_match,
+        )
+        .register(
+            MessageType::RequestJoinQuickMatch,
+            quick_match::join_quick_match,
+        )
+        .register(
+            MessageType::RequestAcceptQuickMatch,
+            quick_match::accept_quick_match,
+        )
+        .register(
+            MessageType::RequestStartQuickMatch,
+            quick_match::start_quick_match,
+        )
+        .register(
+            MessageType::RequestSendQuickMatchResult,
+            quick_match::send_quick_match_result,
+        )
+        .register(MessageType::RequestGetArenaLadder, rating::get_arena_ladder)
+        .register(
+            MessageType::RequestRegisterRanking,
+            leaderboard::register_ranking,
+        )
+        .register(
+            MessageType::RequestGetCurrentRank,
+            leaderboard::get_current_rank,
+        )
+        .register(MessageType::RequestCountRanking, leaderboard::count_ranking)
+        .register(
+            MessageType::RequestGetRankingPage,
+            leaderboard::get_ranking_page,
+        )
+        .register(MessageType::RequestNotifyDeath, telemetry::notify_death)
+        .register(
+            MessageType::RequestNotifyKillEnemy,
+            telemetry::notify_kill_enemy,
+        )
+        .register(
+            MessageType::RequestNotifyKillBoss,
+            telemetry::notify_kill_boss,
+        )
+        .register(
+            MessageType::RequestNotifyKillPlayer,
+            telemetry::notify_kill_player,
+        )
+        .register(
+            MessageType::RequestNotifyBuyItem,
+            telemetry::notify_buy_item,
+        )
+        .register(
+            MessageType::RequestNotifyJoinMultiplayer,
+            telemetry::notify_join_multiplayer,
+        )
+        .register(
+            MessageType::RequestNotifyLeaveMultiplayer,
+            telemetry::notify_leave_multiplayer,
+        )
+        .register(
+            MessageType::RequestMeasureUploadBandwidth,
+            bandwidth::measure_upload_bandwidth,
+        )
+        .register(
+            MessageType::RequestMeasureDownloadBandwidth,
+            bandwidth::measure_download_bandwidth,
+        );
+
+    dispatcher
+}
+
+pub struct GameServer {
+    context: ServerContext,
+    listener: Listener,
+    dispatcher: Arc<Dispatcher>,
+}
+
+impl GameServer {
+    pub async fn bind<A: ToSocketAddrs>(context: ServerContext, addr: A) -> Result<Self> {
+        let dispatcher = dispatcher(&context);
+
+        Self::with_dispatcher(context, addr, dispatcher).await
+    }
+
+    pub async fn with_dispatcher<A: ToSocketAddrs>(
+        context: ServerContext,
+        addr: A,
+        dispatcher: Dispatcher,
+    ) -> Result<Self> {
+        let listener = Listener::bind(context.clone(), addr).await?;
+
+        Ok(Self {
+            context,
+            listener,
+            dispatcher: Arc::new(dispatcher),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.listener.local_addr()
+    }
+
+    pub async fn serve(mut self) -> Result<()> {
+        info!("Game server listening on {}", self.local_addr());
+
+        while let Some((connection, ticket)) = self.listener.accept().await {
+            let peer = connection.peer_addr();
+            let session_id = self.context.sessions().register(NewSession {
+                steam_id: ticket.steam_id.clone(),
+                auth_token: connection.token(),
+                remote_addr: peer,
+                session_key: ticket.game_key,
+                sender: connection.sender(),
+            });
+
+            let request = RequestContext::new(
+                self.context.clone(),
+                session_id,
+                ticket.steam_id.clone(),
+                peer,
+            );
+
+            tokio::spawn(
+                handle_connection(request, self.dispatcher.clone(), ticket, connection)
+                    .instrument(info_span!("game", %peer, session_id)),
+            );
+        }
+
+        Err("game server listener stopped".into())
+    }
+}
+
+async fn handle_connection(
+    request: RequestContext,
+    dispatcher: Arc<Dispatcher>,
+    ticket: AuthTicket,
+    mut connection: Connection,
+) {
+    info!(steam_id = ticket.steam_id.as_str(), "Player connected");
+
+    let sessions = request.context().sessions();
+    let session_id = request.session_id();
+
+    while let Some(payload) = connection.recv().await {
+        if !sessions.contains(session_id) {
+            break;
+        }
+
+        sessions.touch(session_id);
+
+        if let Err(e) = handle_payload(&request, &dispatcher, &connection, payload).await {
+            warn!("Dropping connection: {}", e);
+            break;
+        }
+    }
+
+    connection.close();
+    sessions.remove(session_id);
+    request.context().areas().remove(session_id);
+    sign::session_ended(request.context(), session_id);
+    request.context().covenants().unregister(session_id);
+    quick_match::session_ended(request.context(), session_id);
+
+    info!(steam_id = ticket.steam_id.as_str(), "Player disconnected");
+}
+
+async fn handle_payload(
+    request: &RequestContext,
+    dispatcher: &Dispatcher,
+    connection: &Connection,
+    payload: Bytes,
+) -> Result<()> {
+    let message = Message::parse(payload)?;
+    let reply = dispatcher.dispatch(request, &message).await?;
+
+    connection.send(reply.encode())
+}
@@ -0,0 +1,192 @@
+//! Ghosts: short recordings of other players' movements, replayed as the white phantoms that
+//! wander through each world. They're only interesting while fresh, so they aren't persisted.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use dks3_proto::Frpg2RequestMessage::{
+    GhostData, RequestCreateGhostData, RequestCreateGhostDataResponse, RequestGetGhostDataList,
+    RequestGetGhostDataListResponse,
+};
+
+use crate::dispatch::{ErrorCode, RequestContext, RequestError};
+use crate::session::{SessionId, SessionRegistry};
+use crate::Result;
+
+pub type GhostId = u64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ghost {
+    pub id: GhostId,
+    pub steam_id: String,
+    pub online_area_id: u32,
+    pub map_id: u32,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Default)]
+struct Ghosts {
+    next_id: GhostId,
+    // Oldest first, so the front is the next to go when an area is full.
+    by_area: HashMap<u32, VecDeque<Ghost>>,
+}
+
+#[derive(Debug, Default)]
+pub struct GhostStore {
+    ghosts: Mutex<Ghosts>,
+    last_upload: Mutex<HashMap<SessionId, Instant>>,
+}
+
+impl GhostStore {
+    /// Store `ghost` under a newly assigned ID, which is returned. If its area already has
+    /// `max_per_area` ghosts, the oldest are removed to make room.
+    pub fn insert(&self, mut ghost: Ghost, max_per_area: usize) -> GhostId {
+        let mut ghosts = self.ghosts.lock().unwrap();
+
+        ghosts.next_id += 1;
+        ghost.id = ghosts.next_id;
+
+        let id = ghost.id;
+        let area = ghosts.by_area.entry(ghost.online_area_id).or_default();
+        while area.len() >= max_per_area.max(1) {
+            area.pop_front();
+        }
+        area.push_back(ghost);
+
+        id
+    }
+
+    /// The number of ghosts kept for `online_area_id`.
+    pub fn area_len(&self, online_area_id: u32) -> usize {
+        self.ghosts
+            .lock()
+            .unwrap()
+            .by_area
+            .get(&online_area_id)
+            .map_or(0, VecDeque::len)
+    }
+
+    /// Pick up to `count` ghosts at random from `online_area_id` that weren't recorded by
+    /// `steam_id`.
+    pub fn sample<R: Rng>(
+        &self,
+        online_area_id: u32,
+        steam_id: &str,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<Ghost> {
+        let ghosts = self.ghosts.lock().unwrap();
+        let area = match ghosts.by_area.get(&online_area_id) {
+            Some(area) => area,
+            None => return vec![],
+        };
+
+        let candidates: Vec<&Ghost> = area
+            .iter()
+            .filter(|ghost| ghost.steam_id != steam_id)
+            .collect();
+
+        candidates
+            .choose_multiple(rng, count)
+            .map(|ghost| (*ghost).clone())
+            .collect()
+    }
+
+    /// Record an upload from `session_id`, unless it already uploaded within the last
+    /// `interval`, returning whether it may go ahead.
+    pub fn try_upload(
+        &self,
+        sessions: &SessionRegistry,
+        session_id: SessionId,
+        interval: Duration,
+    ) -> bool {
+        let mut last_upload = self.last_upload.lock().unwrap();
+        if last_upload.len() > 2 * sessions.len() {
+            last_upload.retain(|id, _| sessions.contains(*id));
+        }
+
+        let now = Instant::now();
+        match last_upload.get(&session_id) {
+            Some(last) if now.duration_since(*last) < interval => false,
+            _ => {
+                last_upload.insert(session_id, now);
+                true
+            }
+        }
+    }
+}
+
+pub async fn create_ghost_data(
+    request: RequestContext,
+    mut body: RequestCreateGhostData,
+) -> Result<RequestCreateGhostDataResponse> {
+    let context = request.context();
+    let config = context.config().ghosts();
+    if body.get_ghost_data().len() > config.max_data_bytes() {
+        return Err(RequestError::invalid(format!(
+            "ghost data is larger than {} bytes",
+            config.max_data_bytes()
+        ))
+        .into());
+    }
+
+    if !context.ghosts().try_upload(
+        context.sessions(),
+        request.session_id(),
+        config.upload_interval(),
+    ) {
+        return Err(
+            RequestError::new(ErrorCode::RateLimited, "ghost uploaded too recently").into(),
+        );
+    }
+
+    let ghost = Ghost {
+        id: 0,
+        steam_id: request.steam_id().to_string(),
+        online_area_id: body.get_online_area_id(),
+        map_id: body.get_map_id(),
+        data: body.take_ghost_data(),
+    };
+    context.ghosts().insert(ghost, config.max_per_area());
+
+    Ok(RequestCreateGhostDataResponse::new())
+}
+
+pub async fn get_ghost_data_list(
+    request: RequestContext,
+    body: RequestGetGhostDataList,
+) -> Result<RequestGetGhostDataListResponse> {
+    let context = request.context();
+    let mut count = context.config().ghosts().max_list_entries();
+    if body.has_max_entries() {
+        count = count.min(body.get_max_entries() as usize);
+    }
+
+    let ghosts = context.ghosts().sample(
+        body.get_online_area_id(),
+        request.steam_id(),
+        count,
+        &mut rand::thread_rng(),
+    );
+
+    let mut response = RequestGetGhostDataListResponse::new();
+    for ghost in ghosts {
+        response.mut_ghosts().push(to_ghost_data(ghost));
+    }
+
+    Ok(response)
+}
+
+fn to_ghost_data(ghost: Ghost) -> GhostData {
+    let mut data = GhostData::new();
+    data.set_ghost_id(ghost.id);
+    data.set_steam_id(ghost.steam_id);
+    data.set_online_area_id(ghost.online_area_id);
+    data.set_map_id(ghost.map_id);
+    data.set_ghost_data(ghost.data);
+    data
+}
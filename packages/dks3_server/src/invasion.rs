@@ -0,0 +1,192 @@
+//! Invasions: players using a red eye orb break into the world of a host who is embered or has
+//! phantoms with them.
+//!
+//! An invader asks for the hosts they could invade, picks one, and the server pushes the break
+//! in to that host. Hosts are left alone for a while after being invaded.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rand::seq::SliceRandom;
+
+use dks3_config::Matchmaking;
+use dks3_proto::Frpg2RequestMessage::{
+    BreakInTargetData, PushRequestBreakIn, RequestBreakInTarget, RequestBreakInTargetResponse,
+    RequestGetBreakInTargetList, RequestGetBreakInTargetListResponse,
+};
+
+use crate::covenant;
+use crate::dispatch::{RequestContext, RequestError};
+use crate::frame::MessageType;
+use crate::matchmaking::{self, Level, MultiplayerType};
+use crate::session::{CharacterSummary, Session, SessionId, SessionRegistry};
+use crate::Result;
+
+/// Remembers when each host was last invaded.
+#[derive(Debug, Default)]
+pub struct InvasionTracker {
+    last_invaded: Mutex<HashMap<SessionId, Instant>>,
+}
+
+impl InvasionTracker {
+    /// Whether the host with `session_id` was invaded within the last `cooldown`.
+    pub fn recently_invaded(&self, session_id: SessionId, cooldown: Duration) -> bool {
+        self.last_invaded
+            .lock()
+            .unwrap()
+            .get(&session_id)
+            .iter()
+            .any(|last| last.elapsed() < cooldown)
+    }
+
+    /// Record an invasion of the host with `session_id`, unless it was invaded within the last
+    /// `cooldown`, returning whether it may go ahead.
+    pub fn try_invade(
+        &self,
+        sessions: &SessionRegistry,
+        session_id: SessionId,
+        cooldown: Duration,
+    ) -> bool {
+        let mut last_invaded = self.last_invaded.lock().unwrap();
+        if last_invaded.len() > 2 * sessions.len() {
+            last_invaded.retain(|id, _| sessions.contains(*id));
+        }
+
+        let now = Instant::now();
+        match last_invaded.get(&session_id) {
+            Some(last) if now.duration_since(*last) < cooldown => false,
+            _ => {
+                last_invaded.insert(session_id, now);
+                true
+            }
+        }
+    }
+}
+
+/// Whether `character` is in a state where they can be invaded.
+fn is_invadable(character: &CharacterSummary) -> bool {
+    character.embered || character.in_coop
+}
+
+/// Whether an invader at `invader` in `online_area_id` could break into the world of the host
+/// playing `character` under the `matchmaking` rules.
+fn is_target(
+    matchmaking: &Matchmaking,
+    online_area_id: u32,
+    invader: &Level,
+    character: &CharacterSummary,
+) -> bool {
+    character.online_area_id == online_area_id
+        && is_invadable(character)
+        && matchmaking::is_match(
+            matchmaking,
+            MultiplayerType::Invasion,
+            invader,
+            &character.level(),
+        )
+}
+
+pub async fn get_break_in_target_list(
+    request: RequestContext,
+    body: RequestGetBreakInTargetList,
+) -> Result<RequestGetBreakInTargetListResponse> {
+    let context = request.context();
+    let config = context.config().invasions();
+    let mut count = config.max_list_entries();
+    if body.has_max_entries() {
+        count = count.min(body.get_max_entries() as usize);
+    }
+
+    let threshold = context.config().bandwidth().poor_connection_kbps();
+    let invader = matchmaking::from_matching_parameter(body.get_matching_parameter());
+    let online_area_id = body.get_online_area_id();
+    let mut candidates: Vec<Session> = context
+        .sessions()
+        .filter(|session| {
+            session.steam_id != request.steam_id()
+                && session.character.iter().any(|character| {
+                    is_target(
+                        context.config().matchmaking(),
+                        online_area_id,
+                        &invader,
+                        character,
+                    )
+                })
+        })
+        .into_iter()
+        .filter(|session| {
+            !context
+                .invasions()
+                .recently_invaded(session.id, config.cooldown())
+        })
+        .collect();
+
+    // Offer hosts on poor connections last.
+    candidates.shuffle(&mut rand::thread_rng());
+    candidates.sort_by_key(|host| host.bandwidth.is_poor(threshold));
+
+    let mut response = RequestGetBreakInTargetListResponse::new();
+    for host in candidates.iter().take(count) {
+        let level = host
+            .character
+            .as_ref()
+            .map(CharacterSummary::level)
+            .unwrap_or_default();
+
+        let mut target = BreakInTargetData::new();
+        target.set_steam_id(host.steam_id.clone());
+        target.set_matching_parameter(matchmaking::to_matching_parameter(&level));
+        response.mut_targets().push(target);
+    }
+
+    Ok(response)
+}
+
+pub async fn break_in_target(
+    request: RequestContext,
+    body: RequestBreakInTarget,
+) -> Result<RequestBreakInTargetResponse> {
+    let context = request.context();
+    let invader = request
+        .session()
+        .and_then(|session| session.character)
+        .ok_or_else(|| RequestError::invalid("invader has not reported their status"))?;
+
+    // The host may have left, or stopped being invadable, since the invader saw them. Invaders
+    // can ask for any Steam ID, so the host is held to the same rules as the target list.
+    let host = context
+        .sessions()
+        .by_steam_id(body.get_steam_id())
+        .filter(|host| host.id != request.session_id())
+        .filter(|host| {
+            host.character.iter().any(|character| {
+                is_target(
+                    context.config().matchmaking(),
+                    invader.online_area_id,
+                    &invader.level(),
+                    character,
+                )
+            })
+        })
+        .ok_or_else(|| RequestError::invalid("host is no longer available"))?;
+
+    if !context.invasions().try_invade(
+        context.sessions(),
+        host.id,
+        context.config().invasions().cooldown(),
+    ) {
+        return Err(RequestError::invalid("host was invaded too recently").into());
+    }
+
+    let mut push = PushRequestBreakIn::new();
+    push.set_invader_steam_id(request.steam_id().to_string());
+    push.set_invader_character_name(invader.name);
+
+    host.push(MessageType::PushRequestBreakIn, &push)
+        .map_err(|_| RequestError::invalid("host is no longer available"))?;
+
+    covenant::host_invaded(context, &host, request.session_id());
+
+    Ok(RequestBreakInTargetResponse::new())
+}
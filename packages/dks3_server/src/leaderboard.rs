@@ -0,0 +1,338 @@
+//! Leaderboards: the ranking screens in the client, such as the offerings made to each
+//! covenant.
+//!
+//! Players register scores on the leaderboards set up in the configuration, which decides how a
+//! new score combines with the one the player already has and how often each leaderboard starts
+//! over. Players are ranked by score, and players with the same score by who got there first.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use serde_derive::{Deserialize, Serialize};
+
+use dks3_config::{Leaderboard, ScorePolicy};
+use dks3_proto::Frpg2RequestMessage::{
+    RankingData, RequestCountRanking, RequestCountRankingResponse, RequestGetCurrentRank,
+    RequestGetCurrentRankResponse, RequestGetRankingPage, RequestGetRankingPageResponse,
+    RequestRegisterRanking, RequestRegisterRankingResponse,
+};
+
+use crate::dispatch::{RequestContext, RequestError};
+use crate::storage::{DirtyFlag, Storage};
+use crate::Result;
+
+const STORAGE_NAME: &str = "leaderboards";
+
+pub type BoardId = u32;
+
+/// A player's score on a leaderboard.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Ranking {
+    pub steam_id: String,
+    pub character_name: String,
+    pub score: u64,
+    /// When the player's score last changed.
+    pub updated: SystemTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Board {
+    id: BoardId,
+    /// When the leaderboard was last cleared.
+    started: SystemTime,
+    rankings: Vec<Ranking>,
+}
+
+impl Board {
+    fn new(id: BoardId, started: SystemTime) -> Self {
+        Self {
+            id,
+            started,
+            rankings: vec![],
+        }
+    }
+
+    /// Put the rankings back in order after a score has changed.
+    fn sort(&mut self) {
+        self.rankings.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.updated.cmp(&b.updated))
+                .then_with(|| a.steam_id.cmp(&b.steam_id))
+        });
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Snapshot {
+    boards: Vec<Board>,
+}
+
+#[derive(Debug, Default)]
+pub struct LeaderboardStore {
+    boards: RwLock<HashMap<BoardId, Board>>,
+    dirty: DirtyFlag,
+}
+
+impl LeaderboardStore {
+    /// Load the leaderboards saved in `storage`.
+    pub fn load(storage: &Storage) -> Result<Self> {
+        let snapshot: Snapshot = storage.load(STORAGE_NAME)?.unwrap_or_default();
+        let boards = snapshot
+            .boards
+            .into_iter()
+            .map(|board| (board.id, board))
+            .collect();
+
+        Ok(Self {
+            boards: RwLock::new(boards),
+            dirty: DirtyFlag::default(),
+        })
+    }
+
+    /// Save the leaderboards to `storage` if they have changed since they were last saved.
+    pub fn save(&self, storage: &Storage) -> Result<()> {
+        if !self.dirty.take() {
+            return Ok(());
+        }
+
+        let snapshot = Snapshot {
+            boards: self.boards.read().unwrap().values().cloned().collect(),
+        };
+        let result = storage.save(STORAGE_NAME, &snapshot);
+        if result.is_err() {
+            self.dirty.mark();
+        }
+
+        result
+    }
+
+    /// Register `score` for the player with `steam_id` on `board`, returning the score they
+    /// have on it afterwards.
+    pub fn register(
+        &self,
+        board: &Leaderboard,
+        steam_id: &str,
+        character_name: &str,
+        score: u64,
+        now: SystemTime,
+    ) -> u64 {
+        let mut boards = self.boards.write().unwrap();
+        let entry = boards
+            .entry(board.id())
+            .or_insert_with(|| Board::new(board.id(), now));
+
+        let index = match entry
+            .rankings
+            .iter()
+            .position(|ranking| ranking.steam_id == steam_id)
+        {
+            Some(index) => index,
+            None => {
+                entry.rankings.push(Ranking {
+                    steam_id: steam_id.to_string(),
+                    character_name: String::new(),
+                    score: 0,
+                    updated: now,
+                });
+                entry.rankings.len() - 1
+            }
+        };
+
+        let ranking = &mut entry.rankings[index];
+        let new_score = match board.score_policy() {
+            ScorePolicy::Highest => ranking.score.max(score),
+            ScorePolicy::Total => ranking.score.saturating_add(score),
+            ScorePolicy::Latest => score,
+        };
+
+        if new_score != ranking.score {
+            ranking.updated = now;
+        }
+        ranking.score = new_score;
+        ranking.character_name = character_name.to_string();
+
+        entry.sort();
+        self.dirty.mark();
+        new_score
+    }
+
+    /// The rank of the player with `steam_id` on the leaderboard with `id`, counting from 1,
+    /// along with their score.
+    pub fn rank(&self, id: BoardId, steam_id: &str) -> Option<(usize, Ranking)> {
+        let boards = self.boards.read().unwrap();
+        boards.get(&id).and_then(|board| {
+            board
+                .rankings
+                .iter()
+                .position(|ranking| ranking.steam_id == steam_id)
+                .map(|index| (index + 1, board.rankings[index].clone()))
+        })
+    }
+
+    /// How many players are on the leaderboard with `id`.
+    pub fn count(&self, id: BoardId) -> usize {
+        self.boards
+            .read()
+            .unwrap()
+            .get(&id)
+            .map(|board| board.rankings.len())
+            .unwrap_or(0)
+    }
+
+    /// Up to `count` players on the leaderboard with `id` from `offset` onwards, best first,
+    /// with their ranks.
+    pub fn page(&self, id: BoardId, offset: usize, count: usize) -> Vec<(usize, Ranking)> {
+        let boards = self.boards.read().unwrap();
+        let board = match boards.get(&id) {
+            Some(board) => board,
+            None => return vec![],
+        };
+
+        board
+            .rankings
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(count)
+            .map(|(index, ranking)| (index + 1, ranking.clone()))
+            .collect()
+    }
+
+    /// Clear every leaderboard in `boards` that has gone longer than its reset interval
+    /// without being cleared, returning their IDs.
+    pub fn reset_expired(&self, boards: &[Leaderboard], now: SystemTime) -> Vec<BoardId> {
+        let mut stored = self.boards.write().unwrap();
+        let mut reset = vec![];
+
+        for config in boards {
+            let interval = match config.reset_interval() {
+                Some(interval) => interval,
+                None => continue,
+            };
+
+            let board = match stored.get_mut(&config.id()) {
+                Some(board) => board,
+                None => continue,
+            };
+
+            let elapsed = now.duration_since(board.started).unwrap_or_default();
+            if elapsed >= interval {
+                *board = Board::new(board.id, now);
+                reset.push(board.id);
+            }
+        }
+
+        if !reset.is_empty() {
+            self.dirty.mark();
+        }
+
+        reset.sort();
+        reset
+    }
+}
+
+pub async fn register_ranking(
+    request: RequestContext,
+    body: RequestRegisterRanking,
+) -> Result<RequestRegisterRankingResponse> {
+    let board = board(&request, body.get_board_id())?;
+    if !board.score_range().contains(&body.get_score()) {
+        return Err(
+            RequestError::invalid(format!("score {} is out of range", body.get_score())).into(),
+        );
+    }
+
+    let character_name = request
+        .session()
+        .and_then(|session| session.character)
+        .map(|character| character.name)
+        .unwrap_or_default();
+
+    let score = request.context().leaderboards().register(
+        board,
+        request.steam_id(),
+        &character_name,
+        body.get_score(),
+        SystemTime::now(),
+    );
+
+    let mut response = RequestRegisterRankingResponse::new();
+    response.set_score(score);
+
+    Ok(response)
+}
+
+pub async fn get_current_rank(
+    request: RequestContext,
+    body: RequestGetCurrentRank,
+) -> Result<RequestGetCurrentRankResponse> {
+    let board = board(&request, body.get_board_id())?;
+
+    let mut response = RequestGetCurrentRankResponse::new();
+    if let Some((rank, ranking)) = request
+        .context()
+        .leaderboards()
+        .rank(board.id(), request.steam_id())
+    {
+        response.set_ranking(to_ranking_data(rank, &ranking));
+    }
+
+    Ok(response)
+}
+
+pub async fn count_ranking(
+    request: RequestContext,
+    body: RequestCountRanking,
+) -> Result<RequestCountRankingResponse> {
+    let board = board(&request, body.get_board_id())?;
+
+    let mut response = RequestCountRankingResponse::new();
+    response.set_count(request.context().leaderboards().count(board.id()) as u32);
+
+    Ok(response)
+}
+
+pub async fn get_ranking_page(
+    request: RequestContext,
+    body: RequestGetRankingPage,
+) -> Result<RequestGetRankingPageResponse> {
+    let board = board(&request, body.get_board_id())?;
+    let mut count = board.max_page_entries();
+    if body.has_max_entries() {
+        count = count.min(body.get_max_entries() as usize);
+    }
+
+    let page = request
+        .context()
+        .leaderboards()
+        .page(board.id(), body.get_offset() as usize, count);
+
+    let mut response = RequestGetRankingPageResponse::new();
+    for (rank, ranking) in &page {
+        response
+            .mut_rankings()
+            .push(to_ranking_data(*rank, ranking));
+    }
+
+    Ok(response)
+}
+
+fn board(request: &RequestContext, id: BoardId) -> Result<&Leaderboard> {
+    request
+        .context()
+        .config()
+        .leaderboards()
+        .board(id)
+        .ok_or_else(|| RequestError::invalid(format!("unknown leaderboard {}", id)).into())
+}
+
+fn to_ranking_data(rank: usize, ranking: &Ranking) -> RankingData {
+    let mut data = RankingData::new();
+    data.set_rank(rank as u32);
+    data.set_steam_id(ranking.steam_id.clone());
+    data.set_character_name(ranking.character_name.clone());
+    data.set_score(ranking.score);
+    data
+}
@@ -1,20 +1,394 @@
+use std::future::Future;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use dks3_config::Config;
+use futures::future::{self, BoxFuture, FutureExt, TryFutureExt};
+use tracing::{info, warn};
+
+use crate::announce::AnnouncementBoard;
+use crate::area::AreaTracker;
+use crate::auth::{AuthServer, AuthTokens};
+use crate::blood_message::BloodMessageStore;
+use crate::bloodstain::BloodstainStore;
+use crate::broadcast::BroadcastFilter;
+use crate::covenant::CovenantRegistry;
+use crate::dispatch::RequestMetrics;
+use crate::game::GameServer;
+use crate::ghost::GhostStore;
+use crate::invasion::InvasionTracker;
+use crate::leaderboard::LeaderboardStore;
+use crate::login::LoginServer;
+use crate::player::PlayerHistory;
+use crate::quick_match::QuickMatchPool;
+use crate::rating::RatingStore;
+use crate::session::SessionRegistry;
+use crate::sign::SignPool;
+use crate::steam::TicketVerifier;
+use crate::storage::Storage;
+use crate::telemetry::TelemetryStore;
+
+pub mod admin;
+pub mod announce;
+pub mod area;
+pub mod auth;
+pub mod bandwidth;
+pub mod blood_message;
+pub mod bloodstain;
+pub mod broadcast;
+pub mod covenant;
+pub mod crypto;
+pub mod dispatch;
+pub mod frame;
+pub mod game;
+pub mod ghost;
+pub mod invasion;
+pub mod leaderboard;
+pub mod location;
+pub mod login;
+pub mod matchmaking;
+pub mod player;
+pub mod quick_match;
+pub mod rating;
+pub mod session;
+pub mod sign;
+pub mod steam;
+pub mod storage;
+pub mod telemetry;
+pub mod transport;
 
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// How often to check for idle sessions.
+const SESSION_REAP_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often to check for bloodstains that have outlived the configured maximum age.
+const BLOODSTAIN_EXPIRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often to check for arena matches that have gone quiet.
+const QUICK_MATCH_EXPIRY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often to check for leaderboards that are due to be reset.
+const LEADERBOARD_RESET_INTERVAL: Duration = Duration::from_secs(60);
+
 #[derive(Debug, Clone)]
 pub struct ServerContext {
     shared: Arc<Shared>,
 }
 
-impl Default for ServerContext {
-    fn default() -> Self {
-        Self {
-            shared: Arc::new(Shared {}),
+impl ServerContext {
+    /// Create the server's shared state from `config`, loading everything saved in its data
+    /// directory.
+    pub fn new(config: Config) -> Result<Self> {
+        let ticket_verifier = steam::from_config(config.steam())
+            .map_err(|e| format!("Steam verifier configuration is invalid: {}", e))?;
+
+        Self::with_ticket_verifier(config, ticket_verifier)
+    }
+
+    pub fn with_ticket_verifier(
+        config: Config,
+        ticket_verifier: Box<dyn TicketVerifier>,
+    ) -> Result<Self> {
+        let announcements = AnnouncementBoard::new(config.announcements().path());
+        let storage = Storage::new(config.storage().data_dir());
+        let blood_messages =
+            BloodMessageStore::load(&storage).map_err(load_error("blood messages"))?;
+        let bloodstains = BloodstainStore::load(&storage).map_err(load_error("bloodstains"))?;
+        let ratings = RatingStore::load(&storage).map_err(load_error("ratings"))?;
+        let leaderboards = LeaderboardStore::load(&storage).map_err(load_error("leaderboards"))?;
+        let player_history = PlayerHistory::load(&storage).map_err(load_error("player history"))?;
+        let telemetry =
+            TelemetryStore::load(&storage).map_err(load_error("gameplay statistics"))?;
+
+        Ok(Self {
+            shared: Arc::new(Shared {
+                config,
+                auth_tokens: AuthTokens::default(),
+                ticket_verifier,
+                sessions: SessionRegistry::default(),
+                areas: AreaTracker::default(),
+                request_metrics: RequestMetrics::default(),
+                announcements,
+                storage,
+                blood_messages,
+                bloodstains,
+                ghosts: GhostStore::default(),
+                signs: SignPool::default(),
+                invasions: InvasionTracker::default(),
+                covenants: CovenantRegistry::default(),
+                quick_matches: QuickMatchPool::default(),
+                ratings,
+                leaderboards,
+                player_history,
+                telemetry,
+            }),
+        })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.shared.config
+    }
+
+    pub fn auth_tokens(&self) -> &AuthTokens {
+        &self.shared.auth_tokens
+    }
+
+    pub fn ticket_verifier(&self) -> &dyn TicketVerifier {
+        self.shared.ticket_verifier.as_ref()
+    }
+
+    pub fn sessions(&self) -> &SessionRegistry {
+        &self.shared.sessions
+    }
+
+    pub fn areas(&self) -> &AreaTracker {
+        &self.shared.areas
+    }
+
+    pub fn request_metrics(&self) -> &RequestMetrics {
+        &self.shared.request_metrics
+    }
+
+    pub fn announcements(&self) -> &AnnouncementBoard {
+        &self.shared.announcements
+    }
+
+    pub fn storage(&self) -> &Storage {
+        &self.shared.storage
+    }
+
+    pub fn blood_messages(&self) -> &BloodMessageStore {
+        &self.shared.blood_messages
+    }
+
+    pub fn bloodstains(&self) -> &BloodstainStore {
+        &self.shared.bloodstains
+    }
+
+    pub fn ghosts(&self) -> &GhostStore {
+        &self.shared.ghosts
+    }
+
+    pub fn signs(&self) -> &SignPool {
+        &self.shared.signs
+    }
+
+    pub fn invasions(&self) -> &InvasionTracker {
+        &self.shared.invasions
+    }
+
+    pub fn covenants(&self) -> &CovenantRegistry {
+        &self.shared.covenants
+    }
+
+    pub fn quick_matches(&self) -> &QuickMatchPool {
+        &self.shared.quick_matches
+    }
+
+    pub fn ratings(&self) -> &RatingStore {
+        &self.shared.ratings
+    }
+
+    pub fn leaderboards(&self) -> &LeaderboardStore {
+        &self.shared.leaderboards
+    }
+
+    pub fn player_history(&self) -> &PlayerHistory {
+        &self.shared.player_history
+    }
+
+    pub fn telemetry(&self) -> &TelemetryStore {
+        &self.shared.telemetry
+    }
+
+    /// Push a message to every player online matching `filter`, returning how many it was sent
+    /// to.
+    pub fn broadcast(&self, filter: &BroadcastFilter, header: &str, message: &str) -> usize {
+        broadcast::broadcast(self, filter, header, message)
+    }
+
+    /// Save every store that has changed since it was last saved.
+    pub fn flush(&self) -> Result<()> {
+        self.blood_messages().save(self.storage())?;
+        self.bloodstains().save(self.storage())?;
+        self.ratings().save(self.storage())?;
+        self.leaderboards().save(self.storage())?;
+        self.player_history().save(self.storage())?;
+        self.telemetry().save(self.storage())
+    }
+
+    /// Start every listener and serve connections until one of them fails or `shutdown`
+    /// resolves.
+    pub async fn run<F>(&self, shutdown: F) -> Result<()>
+    where
+        F: Future<Output = ()>,
+    {
+        let listeners =
+            future::try_join_all(self.listeners()).and_then(|_| future::pending::<Result<()>>());
+
+        let result = tokio::select! {
+            result = listeners => result,
+            _ = shutdown => {
+                info!("Shutdown requested, stopping listeners");
+                Ok(())
+            }
+        };
+
+        self.flush()?;
+        result
+    }
+
+    fn listeners(&self) -> Vec<BoxFuture<'static, Result<()>>> {
+        let server = self.config().server();
+        let login_addr = format!("{}:{}", server.bind_addr(), server.login_port());
+        let auth_addr = format!("{}:{}", server.bind_addr(), server.auth_port());
+        let game_addr = format!("{}:{}", server.bind_addr(), server.game_port());
+        let login_context = self.clone();
+        let auth_context = self.clone();
+        let game_context = self.clone();
+        let reaper_context = self.clone();
+        let announce_context = self.clone();
+        let flush_context = self.clone();
+        let expiry_context = self.clone();
+        let quick_match_context = self.clone();
+        let leaderboard_context = self.clone();
+        let admin_context = self.clone();
+
+        vec![
+            async move {
+                LoginServer::bind(login_context, login_addr)
+                    .await?
+                    .serve()
+                    .await
+            }
+            .boxed(),
+            async move {
+                AuthServer::bind(auth_context, auth_addr)
+                    .await?
+                    .serve()
+                    .await
+            }
+            .boxed(),
+            async move {
+                GameServer::bind(game_context, game_addr)
+                    .await?
+                    .serve()
+                    .await
+            }
+            .boxed(),
+            reaper_context.reap_idle_sessions().boxed(),
+            announce::watch(announce_context).boxed(),
+            flush_context.flush_periodically().boxed(),
+            expiry_context.expire_bloodstains().boxed(),
+            quick_match_context.dissolve_stale_quick_matches().boxed(),
+            leaderboard_context.reset_leaderboards().boxed(),
+            admin::listen(admin_context).boxed(),
+        ]
+    }
+
+    async fn flush_periodically(self) -> Result<()> {
+        if !self.storage().is_persistent() {
+            return Ok(());
+        }
+
+        let mut interval = tokio::time::interval(self.config().storage().flush_interval());
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.flush() {
+                warn!("Failed to save server state: {}", e);
+            }
+        }
+    }
+
+    /// Periodically evict sessions that have been idle for longer than the configured timeout.
+    async fn reap_idle_sessions(self) -> Result<()> {
+        let idle_timeout = self.config().sessions().idle_timeout();
+        let mut interval = tokio::time::interval(SESSION_REAP_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            for session in self.sessions().expire_idle(idle_timeout) {
+                info!(
+                    steam_id = session.steam_id.as_str(),
+                    "Evicted idle session {}", session.id
+                );
+            }
+        }
+    }
+
+    /// Periodically remove bloodstains that are older than the configured maximum age.
+    async fn expire_bloodstains(self) -> Result<()> {
+        let max_age = self.config().bloodstains().max_age();
+        let mut interval = tokio::time::interval(BLOODSTAIN_EXPIRY_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let expired = self.bloodstains().expire(max_age);
+            if expired > 0 {
+                info!("Removed {} expired bloodstains", expired);
+            }
+        }
+    }
+
+    /// Periodically dissolve arena matches that have gone quiet for longer than the configured
+    /// timeout.
+    async fn dissolve_stale_quick_matches(self) -> Result<()> {
+        let mut interval = tokio::time::interval(QUICK_MATCH_EXPIRY_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let dissolved = quick_match::dissolve_stale(&self);
+            if dissolved > 0 {
+                info!("Dissolved {} stale quick matches", dissolved);
+            }
+        }
+    }
+
+    /// Periodically clear leaderboards that have gone longer than their configured reset
+    /// interval without being cleared.
+    async fn reset_leaderboards(self) -> Result<()> {
+        let mut interval = tokio::time::interval(LEADERBOARD_RESET_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let boards = self.config().leaderboards().boards();
+            for id in self.leaderboards().reset_expired(boards, SystemTime::now()) {
+                info!("Reset leaderboard {}", id);
+            }
         }
     }
 }
 
 #[derive(Debug)]
-pub struct Shared {}
+pub struct Shared {
+    config: Config,
+    auth_tokens: AuthTokens,
+    ticket_verifier: Box<dyn TicketVerifier>,
+    sessions: SessionRegistry,
+    areas: AreaTracker,
+    request_metrics: RequestMetrics,
+    announcements: AnnouncementBoard,
+    storage: Storage,
+    blood_messages: BloodMessageStore,
+    bloodstains: BloodstainStore,
+    ghosts: GhostStore,
+    signs: SignPool,
+    invasions: InvasionTracker,
+    covenants: CovenantRegistry,
+    quick_matches: QuickMatchPool,
+    ratings: RatingStore,
+    leaderboards: LeaderboardStore,
+    player_history: PlayerHistory,
+    telemetry: TelemetryStore,
+}
+
+fn load_error(what: &'static str) -> impl FnOnce(Error) -> Error {
+    move |e| format!("Failed to load saved {}: {}", what, e).into()
+}
@@ -0,0 +1,53 @@
+use serde_derive::{Deserialize, Serialize};
+
+use dks3_proto::common::Vector3;
+
+use crate::dispatch::RequestError;
+
+/// Where in the world something was placed by a player.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Location {
+    pub online_area_id: u32,
+    pub map_id: u32,
+    pub position: [f32; 3],
+    pub angle: f32,
+}
+
+impl Location {
+    /// A location sent by a client, rejecting positions and angles that aren't finite numbers:
+    /// they can't be saved and read back, and mean nothing to other players' clients.
+    pub fn from_request(
+        online_area_id: u32,
+        map_id: u32,
+        position: &Vector3,
+        angle: f32,
+    ) -> Result<Self, RequestError> {
+        let position = from_vector(position);
+        if !position
+            .iter()
+            .chain(Some(&angle))
+            .all(|value| value.is_finite())
+        {
+            return Err(RequestError::invalid("location is not a finite number"));
+        }
+
+        Ok(Self {
+            online_area_id,
+            map_id,
+            position,
+            angle,
+        })
+    }
+}
+
+pub fn from_vector(vector: &Vector3) -> [f32; 3] {
+    [vector.get_x(), vector.get_y(), vector.get_z()]
+}
+
+pub fn to_vector(position: [f32; 3]) -> Vector3 {
+    let mut vector = Vector3::new();
+    vector.set_x(position[0]);
+    vector.set_y(position[1]);
+    vector.set_z(position[2]);
+    vector
+}
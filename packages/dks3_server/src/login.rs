@@ -0,0 +1,94 @@
+//! The login server is the first thing a patched client talks to. It sends an RSA encrypted
+//! `RequestQueryLoginServerInfo` and expects to be told where to find the auth server.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use futures::{SinkExt, StreamExt};
+use rsa::RSAPrivateKey;
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio_util::codec::Framed;
+use tracing::{info, info_span, warn};
+use tracing_futures::Instrument;
+
+use dks3_proto::Frpg2RequestMessage::{
+    RequestQueryLoginServerInfo, RequestQueryLoginServerInfoResponse,
+};
+
+use crate::crypto::rsa_decrypt;
+use crate::frame::{packet_codec, Message, MessageType};
+use crate::{Result, ServerContext};
+
+pub struct LoginServer {
+    context: ServerContext,
+    listener: TcpListener,
+    private_key: Arc<RSAPrivateKey>,
+}
+
+impl LoginServer {
+    pub async fn bind<A: ToSocketAddrs>(context: ServerContext, addr: A) -> Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let private_key = Arc::new(context.config().security().private_key());
+
+        Ok(Self {
+            context,
+            listener,
+            private_key,
+        })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    pub async fn serve(mut self) -> Result<()> {
+        info!("Login server listening on {}", self.local_addr()?);
+
+        loop {
+            let (stream, peer) = self.listener.accept().await?;
+            let context = self.context.clone();
+            let private_key = self.private_key.clone();
+
+            tokio::spawn(
+                async move {
+                    if let Err(e) = handle_connection(context, &private_key, stream).await {
+                        warn!("Login request failed: {}", e);
+                    }
+                }
+                .instrument(info_span!("login", %peer)),
+            );
+        }
+    }
+}
+
+async fn handle_connection(
+    context: ServerContext,
+    private_key: &RSAPrivateKey,
+    stream: TcpStream,
+) -> Result<()> {
+    let mut framed = Framed::new(stream, packet_codec());
+    let packet = match framed.next().await {
+        Some(packet) => packet?,
+        None => return Ok(()),
+    };
+
+    let message = Message::parse(rsa_decrypt(private_key, &packet)?.into())?;
+    message.expect(MessageType::RequestQueryLoginServerInfo)?;
+
+    let request: RequestQueryLoginServerInfo = message.decode()?;
+    info!(
+        steam_id = request.get_steam_id(),
+        "Login server info requested"
+    );
+
+    let server = context.config().server();
+    let mut response = RequestQueryLoginServerInfoResponse::new();
+    response.set_server_ip(server.hostname().to_string());
+    response.set_port(u64::from(server.auth_port()));
+
+    framed
+        .send(Message::reply(message.index(), &response)?.encode())
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use std::error::Error;
+use std::path::PathBuf;
+
+use tokio::signal;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+use dks3_config::ConfigBuilder;
+use dks3_server::ServerContext;
+
+const CONFIG_PATH: &str = "server/config.toml";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let config_path: PathBuf = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| CONFIG_PATH.to_string())
+        .into();
+
+    let config = ConfigBuilder::default()
+        .add_file(&config_path)
+        .and_then(|c| c.add_env())
+        .and_then(|c| c.build())?;
+
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_new(config.logging().level())?)
+        .try_init()?;
+
+    info!("Loaded configuration from {}", config_path.display());
+
+    let context = ServerContext::new(config)?;
+    context.run(shutdown_signal()).await?;
+
+    info!("Server stopped");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+async fn shutdown_signal() {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut terminate = signal(SignalKind::terminate()).expect("failed to install SIGTERM handler");
+
+    tokio::select! {
+        _ = signal::ctrl_c() => {},
+        _ = terminate.recv() => {},
+    }
+}
+
+#[cfg(not(unix))]
+async fn shutdown_signal() {
+    signal::ctrl_c()
+        .await
+        .expect("failed to install Ctrl-C handler");
+}
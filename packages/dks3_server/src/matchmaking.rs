@@ -0,0 +1,85 @@
+//! Which players are allowed to meet each other, based on their soul levels, how far they've
+//! upgraded their weapons, and any password they've set. The rules themselves come from the
+//! server configuration.
+
+use dks3_config::{MatchingRules, Matchmaking};
+use dks3_proto::Frpg2RequestMessage::MatchingParameter;
+
+/// The parts of a character that matchmaking looks at.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Level {
+    pub soul_level: u32,
+    pub max_weapon_level: u32,
+    pub password: Option<String>,
+}
+
+/// The ways players can end up in each other's worlds, each with its own rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MultiplayerType {
+    /// A host summoning a co-op phantom or duelist from their sign.
+    Coop,
+    /// An invader breaking into a host's world.
+    Invasion,
+    /// A covenant member being summoned into a host's world to defend it.
+    Covenant,
+    /// A player joining an arena match.
+    Arena,
+}
+
+impl MultiplayerType {
+    pub fn rules(self, matchmaking: &Matchmaking) -> &MatchingRules {
+        match self {
+            MultiplayerType::Coop => matchmaking.coop(),
+            MultiplayerType::Invasion => matchmaking.invasion(),
+            MultiplayerType::Covenant => matchmaking.covenant(),
+            MultiplayerType::Arena => matchmaking.arena(),
+        }
+    }
+}
+
+/// Whether the player at `a`, who is looking for someone to play with, may be matched with the
+/// player at `b`. Soul level bands and weapon level ranges are relative to `a`.
+pub fn is_match(matchmaking: &Matchmaking, ty: MultiplayerType, a: &Level, b: &Level) -> bool {
+    let rules = ty.rules(matchmaking);
+
+    if rules.check_weapon_level()
+        && !matchmaking
+            .weapon_levels(a.max_weapon_level)
+            .contains(b.max_weapon_level)
+    {
+        return false;
+    }
+
+    // A password keeps a player to others using the same one.
+    if a.password != b.password {
+        return false;
+    }
+
+    if a.password.is_some() && rules.password_bypass() {
+        return true;
+    }
+
+    let (lowest, highest) = rules.soul_levels().range(a.soul_level);
+    b.soul_level >= lowest && b.soul_level <= highest
+}
+
+pub fn from_matching_parameter(parameter: &MatchingParameter) -> Level {
+    let password = Some(parameter.get_password())
+        .filter(|password| !password.is_empty())
+        .map(str::to_string);
+
+    Level {
+        soul_level: parameter.get_soul_level(),
+        max_weapon_level: parameter.get_max_weapon_level(),
+        password,
+    }
+}
+
+/// The matching parameter to send to clients for a player at `level`. The password is left
+/// out, as it's never shared with other players.
+pub fn to_matching_parameter(level: &Level) -> MatchingParameter {
+    let mut parameter = MatchingParameter::new();
+    parameter.set_soul_level(level.soul_level);
+    parameter.set_max_weapon_level(level.max_weapon_level);
+    parameter
+}
@@ -0,0 +1,173 @@
+//! The state of the characters players are logged in with, as reported by their clients.
+//!
+//! The latest status is kept on the player's session for matchmaking, and every change to it is
+//! kept in a history that moderators can look back over after the player has logged off.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use dks3_config::Covenant;
+use dks3_proto::Frpg2RequestMessage::{
+    RequestUpdatePlayerStatus, RequestUpdatePlayerStatusResponse,
+};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::dispatch::{RequestContext, RequestError};
+use crate::matchmaking;
+use crate::session::CharacterSummary;
+use crate::storage::{DirtyFlag, Storage};
+use crate::Result;
+
+const STORAGE_NAME: &str = "player_history";
+
+/// A player's character status from the time it was reported.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct StatusRecord {
+    pub steam_id: String,
+    pub time: SystemTime,
+    /// The character's status, without its matchmaking password.
+    pub character: CharacterSummary,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Snapshot {
+    records: Vec<StatusRecord>,
+}
+
+/// Every player's past character statuses, oldest first.
+#[derive(Debug, Default)]
+pub struct PlayerHistory {
+    records: RwLock<HashMap<String, VecDeque<StatusRecord>>>,
+    dirty: DirtyFlag,
+}
+
+impl PlayerHistory {
+    /// Load the history saved in `storage`.
+    pub fn load(storage: &Storage) -> Result<Self> {
+        let snapshot: Snapshot = storage.load(STORAGE_NAME)?.unwrap_or_default();
+        let mut records: HashMap<String, VecDeque<StatusRecord>> = HashMap::new();
+        for record in snapshot.records {
+            records
+                .entry(record.steam_id.clone())
+                .or_default()
+                .push_back(record);
+        }
+
+        Ok(Self {
+            records: RwLock::new(records),
+            dirty: DirtyFlag::default(),
+        })
+    }
+
+    /// Save the history to `storage` if it has changed since it was last saved.
+    pub fn save(&self, storage: &Storage) -> Result<()> {
+        if !self.dirty.take() {
+            return Ok(());
+        }
+
+        let snapshot = Snapshot {
+            records: self
+                .records
+                .read()
+                .unwrap()
+                .values()
+                .flat_map(|records| records.iter().cloned())
+                .collect(),
+        };
+        let result = storage.save(STORAGE_NAME, &snapshot);
+        if result.is_err() {
+            self.dirty.mark();
+        }
+
+        result
+    }
+
+    /// Record the status the player with `steam_id` reported, unless it's the same as the last
+    /// one, dropping their oldest records beyond `max_entries`. Returns whether it was recorded.
+    ///
+    /// Passwords are private to the players sharing them, so they're left out of the history,
+    /// and changing only the password isn't recorded.
+    pub fn record(
+        &self,
+        steam_id: &str,
+        character: &CharacterSummary,
+        time: SystemTime,
+        max_entries: usize,
+    ) -> bool {
+        let character = CharacterSummary {
+            password: None,
+            ..character.clone()
+        };
+
+        let mut records = self.records.write().unwrap();
+        let player = records.entry(steam_id.to_string()).or_default();
+
+        if player.back().map(|last| &last.character) == Some(&character) {
+            return false;
+        }
+
+        player.push_back(StatusRecord {
+            steam_id: steam_id.to_string(),
+            time,
+            character,
+        });
+        while player.len() > max_entries {
+            player.pop_front();
+        }
+
+        self.dirty.mark();
+        true
+    }
+
+    /// The statuses the player with `steam_id` has reported, oldest first.
+    pub fn history(&self, steam_id: &str) -> Vec<StatusRecord> {
+        self.records
+            .read()
+            .unwrap()
+            .get(steam_id)
+            .map(|records| records.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+pub async fn update_player_status(
+    request: RequestContext,
+    body: RequestUpdatePlayerStatus,
+) -> Result<RequestUpdatePlayerStatusResponse> {
+    let context = request.context();
+    let covenant = match body.get_covenant() {
+        0 => None,
+        id => Some(
+            Covenant::try_from(id)
+                .map_err(|id| RequestError::invalid(format!("unknown covenant {}", id)))?,
+        ),
+    };
+
+    let level = matchmaking::from_matching_parameter(body.get_matching_parameter());
+    let character = CharacterSummary {
+        name: body.get_character_name().to_string(),
+        soul_level: level.soul_level,
+        max_weapon_level: level.max_weapon_level,
+        online_area_id: body.get_online_area_id(),
+        covenant,
+        ng_cycle: body.get_ng_cycle(),
+        embered: body.get_embered(),
+        in_coop: body.get_in_coop(),
+        password: level.password,
+    };
+
+    context.player_history().record(
+        request.steam_id(),
+        &character,
+        SystemTime::now(),
+        context.config().players().max_history_entries(),
+    );
+    context.areas().update(request.session_id(), &character);
+    context
+        .sessions()
+        .set_character(request.session_id(), character);
+
+    Ok(RequestUpdatePlayerStatusResponse::new())
+}
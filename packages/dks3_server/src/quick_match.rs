@@ -0,0 +1,787 @@
+//! Quick matches in the Undead Match arena.
+//!
+//! A host registers a match for a mode and map, and other players search for matches they're
+//! a match for and ask to join. The host accepts or rejects each player, and starts the match
+//! once it's full. Every player reports how it went when it's over, and the match is finished,
+//! updating their ratings, once they all have. Players who leave a started match, or never
+//! report before it goes stale, are counted as having lost. Matches nobody has touched for a
+//! while are dissolved, as are the ones whose host leaves before they start.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde_derive::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use dks3_config::Matchmaking;
+use dks3_proto::Frpg2RequestMessage::{
+    MatchingParameter, PushJoinQuickMatchResult, PushQuickMatchDissolved,
+    PushRequestJoinQuickMatch, PushStartQuickMatch, QuickMatchData, RequestAcceptQuickMatch,
+    RequestAcceptQuickMatchResponse, RequestJoinQuickMatch, RequestJoinQuickMatchResponse,
+    RequestRegisterQuickMatch, RequestRegisterQuickMatchResponse, RequestSearchQuickMatch,
+    RequestSearchQuickMatchResponse, RequestSendQuickMatchResult,
+    RequestSendQuickMatchResultResponse, RequestStartQuickMatch, RequestStartQuickMatchResponse,
+    RequestUnregisterQuickMatch, RequestUnregisterQuickMatchResponse,
+};
+
+use crate::bandwidth;
+use crate::dispatch::{RequestContext, RequestError};
+use crate::frame::MessageType;
+use crate::matchmaking::{self, Level, MultiplayerType};
+use crate::rating;
+use crate::session::SessionId;
+use crate::{Result, ServerContext};
+
+pub type MatchId = u64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum QuickMatchMode {
+    Duel = 0,
+    TwoVersusTwo = 1,
+    ThreeVersusThree = 2,
+    Brawl = 3,
+    SixPlayerBrawl = 4,
+}
+
+impl QuickMatchMode {
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// How many players, host included, a match in this mode is played with.
+    pub fn players(self) -> usize {
+        match self {
+            QuickMatchMode::Duel => 2,
+            QuickMatchMode::TwoVersusTwo | QuickMatchMode::Brawl => 4,
+            QuickMatchMode::ThreeVersusThree | QuickMatchMode::SixPlayerBrawl => 6,
+        }
+    }
+}
+
+impl TryFrom<u32> for QuickMatchMode {
+    type Error = u32;
+
+    fn try_from(id: u32) -> std::result::Result<Self, u32> {
+        match id {
+            0 => Ok(QuickMatchMode::Duel),
+            1 => Ok(QuickMatchMode::TwoVersusTwo),
+            2 => Ok(QuickMatchMode::ThreeVersusThree),
+            3 => Ok(QuickMatchMode::Brawl),
+            4 => Ok(QuickMatchMode::SixPlayerBrawl),
+            _ => Err(id),
+        }
+    }
+}
+
+/// How a match went for the player reporting it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchResult {
+    Lose = 0,
+    Win = 1,
+    Draw = 2,
+}
+
+impl MatchResult {
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+}
+
+impl TryFrom<u32> for MatchResult {
+    type Error = u32;
+
+    fn try_from(id: u32) -> std::result::Result<Self, u32> {
+        match id {
+            0 => Ok(MatchResult::Lose),
+            1 => Ok(MatchResult::Win),
+            2 => Ok(MatchResult::Draw),
+            _ => Err(id),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Player {
+    pub session_id: SessionId,
+    pub steam_id: String,
+    pub character_name: String,
+    pub level: Level,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LobbyState {
+    /// Waiting for players to join.
+    Open,
+    /// Being played.
+    Started,
+}
+
+#[derive(Clone, Debug)]
+pub struct Lobby {
+    pub id: MatchId,
+    pub mode: QuickMatchMode,
+    pub map_id: u32,
+    /// The players in the match, host first.
+    pub players: Vec<Player>,
+    /// Players waiting for the host to accept them.
+    pub pending: Vec<Player>,
+    pub state: LobbyState,
+    /// The results reported so far, in the order they came in.
+    pub results: Vec<(SessionId, MatchResult)>,
+    last_activity: Instant,
+}
+
+impl Lobby {
+    pub fn host(&self) -> &Player {
+        &self.players[0]
+    }
+
+    /// Whether no more players can ask to join.
+    pub fn is_full(&self) -> bool {
+        self.players.len() + self.pending.len() >= self.mode.players()
+    }
+
+    fn has_player(&self, session_id: SessionId) -> bool {
+        self.players
+            .iter()
+            .chain(&self.pending)
+            .any(|player| player.session_id == session_id)
+    }
+
+    /// Whether the match was played and everyone in it has reported how it went.
+    pub fn is_finished(&self) -> bool {
+        self.state == LobbyState::Started && self.results.len() == self.players.len()
+    }
+
+    fn has_reported(&self, session_id: SessionId) -> bool {
+        self.results.iter().any(|(other, _)| *other == session_id)
+    }
+
+    /// Count every player who hasn't reported how the match went as having lost.
+    fn forfeit_unreported(&mut self) {
+        let unreported: Vec<SessionId> = self
+            .players
+            .iter()
+            .map(|player| player.session_id)
+            .filter(|session_id| !self.has_reported(*session_id))
+            .collect();
+
+        for session_id in unreported {
+            self.results.push((session_id, MatchResult::Lose));
+        }
+    }
+
+    fn touch(&mut self) {
+        self.last_activity = Instant::now();
+    }
+}
+
+/// The result of a player asking to join a match.
+#[derive(Debug)]
+pub enum Join {
+    /// The host needs to be asked. Holds the match.
+    Requested(Lobby),
+    /// The match is full, started, or already has the player in it.
+    Unavailable,
+    /// The player's level is too far from the host's.
+    OutOfRange,
+    NotFound,
+}
+
+/// The result of a player reporting how a match went.
+#[derive(Debug)]
+pub enum Report {
+    /// Other players have yet to report.
+    Recorded,
+    /// Everyone has reported, and the match is over. Holds the finished match.
+    Finished(Lobby),
+}
+
+#[derive(Debug, Default)]
+struct Lobbies {
+    next_id: MatchId,
+    by_id: HashMap<MatchId, Lobby>,
+    by_key: HashMap<(QuickMatchMode, u32), Vec<MatchId>>,
+}
+
+impl Lobbies {
+    fn remove(&mut self, id: MatchId) -> Option<Lobby> {
+        let lobby = self.by_id.remove(&id)?;
+        if let Some(ids) = self.by_key.get_mut(&(lobby.mode, lobby.map_id)) {
+            ids.retain(|other| *other != id);
+        }
+
+        Some(lobby)
+    }
+
+    fn hosted_by(&self, session_id: SessionId) -> Option<MatchId> {
+        self.by_id
+            .values()
+            .find(|lobby| lobby.host().session_id == session_id)
+            .map(|lobby| lobby.id)
+    }
+}
+
+/// Arena matches, kept by mode and map.
+#[derive(Debug, Default)]
+pub struct QuickMatchPool {
+    lobbies: Mutex<Lobbies>,
+}
+
+impl QuickMatchPool {
+    /// Open a match hosted by `host`, returning its ID. Players only host one match at a
+    /// time, so any other match they host is removed and returned.
+    pub fn register(
+        &self,
+        mode: QuickMatchMode,
+        map_id: u32,
+        host: Player,
+    ) -> (MatchId, Option<Lobby>) {
+        let mut lobbies = self.lobbies.lock().unwrap();
+        let replaced = lobbies
+            .hosted_by(host.session_id)
+            .and_then(|id| lobbies.remove(id));
+
+        lobbies.next_id += 1;
+        let id = lobbies.next_id;
+        lobbies.by_id.insert(
+            id,
+            Lobby {
+                id,
+                mode,
+                map_id,
+                players: vec![host],
+                pending: vec![],
+                state: LobbyState::Open,
+                results: vec![],
+                last_activity: Instant::now(),
+            },
+        );
+        lobbies.by_key.entry((mode, map_id)).or_default().push(id);
+
+        (id, replaced)
+    }
+
+    /// Remove the match hosted by `session_id`, if any.
+    pub fn unregister(&self, session_id: SessionId) -> Option<Lobby> {
+        let mut lobbies = self.lobbies.lock().unwrap();
+        lobbies
+            .hosted_by(session_id)
+            .and_then(|id| lobbies.remove(id))
+    }
+
+    pub fn get(&self, id: MatchId) -> Option<Lobby> {
+        self.lobbies.lock().unwrap().by_id.get(&id).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lobbies.lock().unwrap().by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The open matches for `mode` on `map_id` that a player at `level` could join under the
+    /// `matchmaking` rules, those waiting longest first. Matches hosted by `steam_id` are left
+    /// out.
+    pub fn search(
+        &self,
+        matchmaking: &Matchmaking,
+        mode: QuickMatchMode,
+        map_id: u32,
+        steam_id: &str,
+        level: &Level,
+    ) -> Vec<Lobby> {
+        let lobbies = self.lobbies.lock().unwrap();
+        let ids = match lobbies.by_key.get(&(mode, map_id)) {
+            Some(ids) => ids,
+            None => return vec![],
+        };
+
+        ids.iter()
+            .filter_map(|id| lobbies.by_id.get(id))
+            .filter(|lobby| lobby.state == LobbyState::Open && !lobby.is_full())
+            .filter(|lobby| lobby.host().steam_id != steam_id)
+            .filter(|lobby| {
+                matchmaking::is_match(
+                    matchmaking,
+                    MultiplayerType::Arena,
+                    level,
+                    &lobby.host().level,
+                )
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Ask for `player` to join the match with `id`.
+    pub fn join(&self, matchmaking: &Matchmaking, id: MatchId, player: Player) -> Join {
+        let mut lobbies = self.lobbies.lock().unwrap();
+        let lobby = match lobbies.by_id.get_mut(&id) {
+            Some(lobby) => lobby,
+            None => return Join::NotFound,
+        };
+
+        if lobby.state != LobbyState::Open || lobby.is_full() || lobby.has_player(player.session_id)
+        {
+            return Join::Unavailable;
+        }
+
+        if !matchmaking::is_match(
+            matchmaking,
+            MultiplayerType::Arena,
+            &player.level,
+            &lobby.host().level,
+        ) {
+            return Join::OutOfRange;
+        }
+
+        lobby.pending.push(player);
+        lobby.touch();
+        Join::Requested(lobby.clone())
+    }
+
+    /// Record the answer of the host with `session_id` to the player with `steam_id` asking
+    /// to join, returning that player. Accepted players join the match.
+    pub fn respond(
+        &self,
+        id: MatchId,
+        session_id: SessionId,
+        steam_id: &str,
+        accepted: bool,
+    ) -> Option<Player> {
+        let mut lobbies = self.lobbies.lock().unwrap();
+        let lobby = lobbies
+            .by_id
+            .get_mut(&id)
+            .filter(|lobby| lobby.host().session_id == session_id)?;
+
+        let index = lobby
+            .pending
+            .iter()
+            .position(|player| player.steam_id == steam_id)?;
+
+        let player = lobby.pending.remove(index);
+        if accepted {
+            lobby.players.push(player.clone());
+        }
+
+        lobby.touch();
+        Some(player)
+    }
+
+    /// Start the match with `id`, if it's hosted by `session_id` and full.
+    pub fn start(&self, id: MatchId, session_id: SessionId) -> Option<Lobby> {
+        let mut lobbies = self.lobbies.lock().unwrap();
+        let lobby = lobbies.by_id.get_mut(&id).filter(|lobby| {
+            lobby.host().session_id == session_id
+                && lobby.state == LobbyState::Open
+                && lobby.players.len() == lobby.mode.players()
+        })?;
+
+        lobby.state = LobbyState::Started;
+        lobby.touch();
+        Some(lobby.clone())
+    }
+
+    /// Record how the match with `id` went for the player with `session_id`. Returns `None` if
+    /// they aren't playing in it, or have already reported.
+    pub fn report(
+        &self,
+        id: MatchId,
+        session_id: SessionId,
+        result: MatchResult,
+    ) -> Option<Report> {
+        let mut lobbies = self.lobbies.lock().unwrap();
+        let lobby = lobbies.by_id.get_mut(&id).filter(|lobby| {
+            lobby.state == LobbyState::Started
+                && lobby
+                    .players
+                    .iter()
+                    .any(|player| player.session_id == session_id)
+                && !lobby.has_reported(session_id)
+        })?;
+
+        lobby.results.push((session_id, result));
+        lobby.touch();
+
+        if !lobby.is_finished() {
+            return Some(Report::Recorded);
+        }
+
+        lobbies.remove(id).map(Report::Finished)
+    }
+
+    /// Remove every match nothing has happened in for `timeout`, returning them. Started
+    /// matches that someone has reported on are settled, counting the players who didn't report
+    /// as having lost, and come back finished.
+    pub fn expire(&self, timeout: Duration) -> Vec<Lobby> {
+        let mut lobbies = self.lobbies.lock().unwrap();
+        let stale: Vec<MatchId> = lobbies
+            .by_id
+            .values()
+            .filter(|lobby| lobby.last_activity.elapsed() >= timeout)
+            .map(|lobby| lobby.id)
+            .collect();
+
+        let mut expired: Vec<Lobby> = stale
+            .into_iter()
+            .filter_map(|id| lobbies.remove(id))
+            .collect();
+
+        for lobby in &mut expired {
+            if lobby.state == LobbyState::Started && !lobby.results.is_empty() {
+                lobby.forfeit_unreported();
+            }
+        }
+
+        expired
+    }
+
+    /// Forget a session that has ended. Open matches it hosted are removed and returned, and it
+    /// leaves any other open match it was in or waiting on. In started matches it's counted as
+    /// having lost if it hadn't reported yet, and those that are finished by that are removed
+    /// and returned as well.
+    pub fn remove_session(&self, session_id: SessionId) -> Vec<Lobby> {
+        let mut lobbies = self.lobbies.lock().unwrap();
+        let hosted: Vec<MatchId> = lobbies
+            .by_id
+            .values()
+            .filter(|lobby| lobby.state == LobbyState::Open)
+            .filter(|lobby| lobby.host().session_id == session_id)
+            .map(|lobby| lobby.id)
+            .collect();
+
+        let mut removed: Vec<Lobby> = hosted
+            .into_iter()
+            .filter_map(|id| lobbies.remove(id))
+            .collect();
+
+        let mut finished = vec![];
+        for lobby in lobbies.by_id.values_mut() {
+            match lobby.state {
+                LobbyState::Open => {
+                    lobby
+                        .players
+                        .retain(|player| player.session_id != session_id);
+                    lobby
+                        .pending
+                        .retain(|player| player.session_id != session_id);
+                }
+                LobbyState::Started => {
+                    if lobby.has_player(session_id) && !lobby.has_reported(session_id) {
+                        lobby.results.push((session_id, MatchResult::Lose));
+                        if lobby.is_finished() {
+                            finished.push(lobby.id);
+                        }
+                    }
+                }
+            }
+        }
+
+        removed.extend(finished.into_iter().filter_map(|id| lobbies.remove(id)));
+        removed
+    }
+}
+
+/// Clean up after a player whose session has ended, dissolving the matches they were hosting
+/// and finishing the ones only their report was missing from.
+pub fn session_ended(context: &ServerContext, session_id: SessionId) {
+    for lobby in context.quick_matches().remove_session(session_id) {
+        if lobby.is_finished() {
+            finish(context, &lobby);
+        } else {
+            push_dissolved(context, &lobby, Some(session_id));
+        }
+    }
+}
+
+/// Dissolve every match that has gone without anything happening for longer than the
+/// configured timeout, letting its players know. Matches that were settled are finished
+/// instead. Returns how many were removed.
+pub fn dissolve_stale(context: &ServerContext) -> usize {
+    let timeout = context.config().quick_matches().lobby_timeout();
+    let stale = context.quick_matches().expire(timeout);
+    for lobby in &stale {
+        if lobby.is_finished() {
+            finish(context, lobby);
+        } else {
+            push_dissolved(context, lobby, None);
+        }
+    }
+
+    stale.len()
+}
+
+/// Update the ratings of everyone in a match that is over.
+fn finish(context: &ServerContext, lobby: &Lobby) {
+    info!(
+        "Quick match {} ({:?}) finished with {} players",
+        lobby.id,
+        lobby.mode,
+        lobby.players.len()
+    );
+
+    rating::match_finished(context, lobby);
+}
+
+pub async fn register_quick_match(
+    request: RequestContext,
+    body: RequestRegisterQuickMatch,
+) -> Result<RequestRegisterQuickMatchResponse> {
+    let context = request.context();
+    let mode = mode(body.get_mode())?;
+    let host = player(&request, body.get_matching_parameter());
+
+    let (id, replaced) = context
+        .quick_matches()
+        .register(mode, body.get_map_id(), host);
+    if let Some(lobby) = replaced {
+        push_dissolved(context, &lobby, Some(request.session_id()));
+    }
+
+    let mut response = RequestRegisterQuickMatchResponse::new();
+    response.set_match_id(id);
+
+    Ok(response)
+}
+
+pub async fn unregister_quick_match(
+    request: RequestContext,
+    _body: RequestUnregisterQuickMatch,
+) -> Result<RequestUnregisterQuickMatchResponse> {
+    let context = request.context();
+    if let Some(lobby) = context.quick_matches().unregister(request.session_id()) {
+        push_dissolved(context, &lobby, Some(request.session_id()));
+    }
+
+    Ok(RequestUnregisterQuickMatchResponse::new())
+}
+
+pub async fn search_quick_match(
+    request: RequestContext,
+    body: RequestSearchQuickMatch,
+) -> Result<RequestSearchQuickMatchResponse> {
+    let context = request.context();
+    let mut count = context.config().quick_matches().max_list_entries();
+    if body.has_max_entries() {
+        count = count.min(body.get_max_entries() as usize);
+    }
+
+    let mode = mode(body.get_mode())?;
+    let lobbies = context.quick_matches().search(
+        context.config().matchmaking(),
+        mode,
+        body.get_map_id(),
+        request.steam_id(),
+        &matchmaking::from_matching_parameter(body.get_matching_parameter()),
+    );
+
+    // Prefer hosts rated closest to the player, and leave out those rated too far away. Hosts
+    // on poor connections come after everyone else.
+    let ratings = context.config().ratings();
+    let rating = context.ratings().rating(ratings, mode, request.steam_id());
+    let mut candidates: Vec<((bool, u64), Lobby)> = lobbies
+        .into_iter()
+        .map(|lobby| {
+            let host = context
+                .ratings()
+                .rating(ratings, mode, &lobby.host().steam_id);
+            ((host - rating).abs(), lobby)
+        })
+        .filter(|(distance, _)| *distance <= ratings.pairing_range())
+        .map(|(distance, lobby)| {
+            let poor = bandwidth::is_poor_connection(context, lobby.host().session_id);
+            ((poor, distance.round() as u64), lobby)
+        })
+        .collect();
+    candidates.sort_by_key(|(key, _)| *key);
+
+    let mut response = RequestSearchQuickMatchResponse::new();
+    for (_, lobby) in candidates.iter().take(count) {
+        response.mut_matches().push(to_quick_match_data(lobby));
+    }
+
+    Ok(response)
+}
+
+pub async fn join_quick_match(
+    request: RequestContext,
+    body: RequestJoinQuickMatch,
+) -> Result<RequestJoinQuickMatchResponse> {
+    let context = request.context();
+    let player = player(&request, body.get_matching_parameter());
+
+    let lobby = match context.quick_matches().join(
+        context.config().matchmaking(),
+        body.get_match_id(),
+        player.clone(),
+    ) {
+        Join::Requested(lobby) => lobby,
+        Join::Unavailable => return Err(RequestError::invalid("match cannot be joined").into()),
+        Join::OutOfRange => return Err(RequestError::invalid("match is out of range").into()),
+        Join::NotFound => return Err(RequestError::invalid("no such match").into()),
+    };
+
+    let mut push = PushRequestJoinQuickMatch::new();
+    push.set_match_id(lobby.id);
+    push.set_steam_id(player.steam_id.clone());
+    push.set_character_name(player.character_name.clone());
+    push.set_matching_parameter(matchmaking::to_matching_parameter(&player.level));
+
+    // The host may have left since the player found the match. The match goes with them.
+    let delivered = context
+        .sessions()
+        .get(lobby.host().session_id)
+        .map(|host| host.push(MessageType::PushRequestJoinQuickMatch, &push));
+
+    if !matches!(delivered, Some(Ok(()))) {
+        if let Some(lobby) = context.quick_matches().unregister(lobby.host().session_id) {
+            push_dissolved(context, &lobby, Some(request.session_id()));
+        }
+        return Err(RequestError::invalid("match host is no longer available").into());
+    }
+
+    Ok(RequestJoinQuickMatchResponse::new())
+}
+
+pub async fn accept_quick_match(
+    request: RequestContext,
+    body: RequestAcceptQuickMatch,
+) -> Result<RequestAcceptQuickMatchResponse> {
+    let context = request.context();
+    let player = context
+        .quick_matches()
+        .respond(
+            body.get_match_id(),
+            request.session_id(),
+            body.get_steam_id(),
+            body.get_accepted(),
+        )
+        .ok_or_else(|| RequestError::invalid("player is not waiting to join this match"))?;
+
+    let mut push = PushJoinQuickMatchResult::new();
+    push.set_match_id(body.get_match_id());
+    push.set_accepted(body.get_accepted());
+    push_to(
+        context,
+        player.session_id,
+        MessageType::PushJoinQuickMatchResult,
+        &push,
+    );
+
+    Ok(RequestAcceptQuickMatchResponse::new())
+}
+
+pub async fn start_quick_match(
+    request: RequestContext,
+    body: RequestStartQuickMatch,
+) -> Result<RequestStartQuickMatchResponse> {
+    let context = request.context();
+    let lobby = context
+        .quick_matches()
+        .start(body.get_match_id(), request.session_id())
+        .ok_or_else(|| RequestError::invalid("match cannot be started"))?;
+
+    let mut push = PushStartQuickMatch::new();
+    push.set_match_id(lobby.id);
+    for player in &lobby.players {
+        push.mut_steam_ids().push(player.steam_id.clone());
+    }
+
+    for player in &lobby.players[1..] {
+        push_to(
+            context,
+            player.session_id,
+            MessageType::PushStartQuickMatch,
+            &push,
+        );
+    }
+
+    Ok(RequestStartQuickMatchResponse::new())
+}
+
+pub async fn send_quick_match_result(
+    request: RequestContext,
+    body: RequestSendQuickMatchResult,
+) -> Result<RequestSendQuickMatchResultResponse> {
+    let result = MatchResult::try_from(body.get_result())
+        .map_err(|id| RequestError::invalid(format!("unknown match result {}", id)))?;
+
+    let report = request
+        .context()
+        .quick_matches()
+        .report(body.get_match_id(), request.session_id(), result)
+        .ok_or_else(|| RequestError::invalid("not playing in this match"))?;
+
+    if let Report::Finished(lobby) = report {
+        finish(request.context(), &lobby);
+    }
+
+    Ok(RequestSendQuickMatchResultResponse::new())
+}
+
+fn mode(id: u32) -> Result<QuickMatchMode> {
+    QuickMatchMode::try_from(id)
+        .map_err(|id| RequestError::invalid(format!("unknown quick match mode {}", id)).into())
+}
+
+fn player(request: &RequestContext, parameter: &MatchingParameter) -> Player {
+    Player {
+        session_id: request.session_id(),
+        steam_id: request.steam_id().to_string(),
+        character_name: request
+            .session()
+            .and_then(|session| session.character)
+            .map(|character| character.name)
+            .unwrap_or_default(),
+        level: matchmaking::from_matching_parameter(parameter),
+    }
+}
+
+/// Let everyone in a match that has been dissolved know, except the player with `except`
+/// whose doing it was.
+fn push_dissolved(context: &ServerContext, lobby: &Lobby, except: Option<SessionId>) {
+    let mut push = PushQuickMatchDissolved::new();
+    push.set_match_id(lobby.id);
+
+    for player in lobby.players.iter().chain(&lobby.pending) {
+        if Some(player.session_id) != except {
+            push_to(
+                context,
+                player.session_id,
+                MessageType::PushQuickMatchDissolved,
+                &push,
+            );
+        }
+    }
+}
+
+fn push_to<M>(context: &ServerContext, session_id: SessionId, message_type: MessageType, body: &M)
+where
+    M: protobuf::Message,
+{
+    let session = match context.sessions().get(session_id) {
+        Some(session) => session,
+        None => return,
+    };
+
+    if let Err(e) = session.push(message_type, body) {
+        debug!("Failed to push {:?}: {}", message_type, e);
+    }
+}
+
+fn to_quick_match_data(lobby: &Lobby) -> QuickMatchData {
+    let mut data = QuickMatchData::new();
+    data.set_match_id(lobby.id);
+    data.set_host_steam_id(lobby.host().steam_id.clone());
+    data.set_host_character_name(lobby.host().character_name.clone());
+    data.set_mode(lobby.mode.id());
+    data.set_map_id(lobby.map_id);
+    data.set_players(lobby.players.len() as u32);
+    data.set_matching_parameter(matchmaking::to_matching_parameter(&lobby.host().level));
+    data
+}
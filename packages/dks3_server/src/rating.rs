@@ -0,0 +1,289 @@
+//! Elo ratings for arena players, one per quick match mode, and the ladders ranking them.
+//!
+//! Once everyone in a quick match has reported how it went, every player is scored against each
+//! of their opponents, and their rating moves by the average of how far those scores were from
+//! what their ratings predicted. Quick match searches favour hosts rated close to the player
+//! searching.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::RwLock;
+
+use serde_derive::{Deserialize, Serialize};
+
+use dks3_config::Ratings;
+use dks3_proto::Frpg2RequestMessage::{
+    ArenaLadderEntry, RequestGetArenaLadder, RequestGetArenaLadderResponse,
+};
+
+use crate::dispatch::{RequestContext, RequestError};
+use crate::quick_match::{Lobby, MatchResult, QuickMatchMode};
+use crate::storage::{DirtyFlag, Storage};
+use crate::{Result, ServerContext};
+
+const STORAGE_NAME: &str = "ratings";
+
+/// A player's standing in one mode.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Rating {
+    pub mode: QuickMatchMode,
+    pub steam_id: String,
+    /// The name of the character the player last played a match with.
+    pub character_name: String,
+    pub rating: f64,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+/// How a player did in a finished match.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub steam_id: String,
+    pub character_name: String,
+    pub result: MatchResult,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Snapshot {
+    ratings: Vec<Rating>,
+}
+
+#[derive(Debug, Default)]
+pub struct RatingStore {
+    ratings: RwLock<HashMap<(QuickMatchMode, String), Rating>>,
+    dirty: DirtyFlag,
+}
+
+impl RatingStore {
+    /// Load the ratings saved in `storage`.
+    pub fn load(storage: &Storage) -> Result<Self> {
+        let snapshot: Snapshot = storage.load(STORAGE_NAME)?.unwrap_or_default();
+        let ratings = snapshot
+            .ratings
+            .into_iter()
+            .map(|rating| ((rating.mode, rating.steam_id.clone()), rating))
+            .collect();
+
+        Ok(Self {
+            ratings: RwLock::new(ratings),
+            dirty: DirtyFlag::default(),
+        })
+    }
+
+    /// Save the ratings to `storage` if they have changed since they were last saved.
+    pub fn save(&self, storage: &Storage) -> Result<()> {
+        if !self.dirty.take() {
+            return Ok(());
+        }
+
+        let snapshot = Snapshot {
+            ratings: self.ratings.read().unwrap().values().cloned().collect(),
+        };
+        let result = storage.save(STORAGE_NAME, &snapshot);
+        if result.is_err() {
+            self.dirty.mark();
+        }
+
+        result
+    }
+
+    pub fn get(&self, mode: QuickMatchMode, steam_id: &str) -> Option<Rating> {
+        self.ratings
+            .read()
+            .unwrap()
+            .get(&(mode, steam_id.to_string()))
+            .cloned()
+    }
+
+    /// The rating of the player with `steam_id` in `mode`, or the configured initial rating if
+    /// they haven't played it yet.
+    pub fn rating(&self, config: &Ratings, mode: QuickMatchMode, steam_id: &str) -> f64 {
+        self.get(mode, steam_id)
+            .map(|rating| rating.rating)
+            .unwrap_or_else(|| config.initial_rating())
+    }
+
+    /// How many players have a rating in `mode`.
+    pub fn players(&self, mode: QuickMatchMode) -> usize {
+        self.ratings
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|(other, _)| *other == mode)
+            .count()
+    }
+
+    /// Update the ratings of everyone who played a match in `mode`, returning their new ratings
+    /// in the order of `outcomes`.
+    pub fn record(
+        &self,
+        config: &Ratings,
+        mode: QuickMatchMode,
+        outcomes: &[Outcome],
+    ) -> Vec<Rating> {
+        let mut ratings = self.ratings.write().unwrap();
+
+        let before: Vec<f64> = outcomes
+            .iter()
+            .map(|outcome| {
+                ratings
+                    .get(&(mode, outcome.steam_id.clone()))
+                    .map(|rating| rating.rating)
+                    .unwrap_or_else(|| config.initial_rating())
+            })
+            .collect();
+
+        let mut updated = vec![];
+        for (i, outcome) in outcomes.iter().enumerate() {
+            let mut change = 0.0;
+            let mut opponents = 0;
+            for (j, other) in outcomes.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+
+                if let Some(score) = score(outcome.result, other.result) {
+                    change += score - expected_score(before[i], before[j]);
+                    opponents += 1;
+                }
+            }
+
+            let rating = ratings
+                .entry((mode, outcome.steam_id.clone()))
+                .or_insert_with(|| Rating {
+                    mode,
+                    steam_id: outcome.steam_id.clone(),
+                    character_name: String::new(),
+                    rating: config.initial_rating(),
+                    wins: 0,
+                    losses: 0,
+                    draws: 0,
+                });
+
+            // A player nobody agrees they played against, e.g. when both sides of a duel claim
+            // the win, keeps both their rating and their record as they were.
+            if opponents > 0 {
+                rating.rating = before[i] + config.k_factor() * change / opponents as f64;
+                match outcome.result {
+                    MatchResult::Win => rating.wins += 1,
+                    MatchResult::Lose => rating.losses += 1,
+                    MatchResult::Draw => rating.draws += 1,
+                }
+            }
+
+            rating.character_name = outcome.character_name.clone();
+
+            updated.push(rating.clone());
+        }
+
+        self.dirty.mark();
+        updated
+    }
+
+    /// Up to `count` players in `mode` from `offset` onwards, best rated first, along with
+    /// how many players are on the ladder in all.
+    pub fn ladder(
+        &self,
+        mode: QuickMatchMode,
+        offset: usize,
+        count: usize,
+    ) -> (Vec<Rating>, usize) {
+        let ratings = self.ratings.read().unwrap();
+        let mut ladder: Vec<&Rating> = ratings
+            .values()
+            .filter(|rating| rating.mode == mode)
+            .collect();
+
+        ladder.sort_by(|a, b| {
+            b.rating
+                .partial_cmp(&a.rating)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.steam_id.cmp(&b.steam_id))
+        });
+
+        let total = ladder.len();
+        let page = ladder
+            .into_iter()
+            .skip(offset)
+            .take(count)
+            .cloned()
+            .collect();
+
+        (page, total)
+    }
+}
+
+/// The score a player with `result` gets against an opponent with `other`, or `None` if they
+/// weren't opponents: players who both won or both lost were on the same team, or disagree
+/// about how it went.
+fn score(result: MatchResult, other: MatchResult) -> Option<f64> {
+    match (result, other) {
+        (MatchResult::Draw, MatchResult::Draw) => Some(0.5),
+        (a, b) if a == b => None,
+        (MatchResult::Win, _) | (MatchResult::Draw, MatchResult::Lose) => Some(1.0),
+        _ => Some(0.0),
+    }
+}
+
+/// The score a player rated `rating` is expected to get against one rated `other`.
+pub fn expected_score(rating: f64, other: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((other - rating) / 400.0))
+}
+
+/// Update the ratings of everyone who played in `lobby`, which has just finished.
+pub fn match_finished(context: &ServerContext, lobby: &Lobby) {
+    let outcomes: Vec<Outcome> = lobby
+        .results
+        .iter()
+        .filter_map(|(session_id, result)| {
+            let player = lobby
+                .players
+                .iter()
+                .find(|player| player.session_id == *session_id)?;
+
+            Some(Outcome {
+                steam_id: player.steam_id.clone(),
+                character_name: player.character_name.clone(),
+                result: *result,
+            })
+        })
+        .collect();
+
+    context
+        .ratings()
+        .record(context.config().ratings(), lobby.mode, &outcomes);
+}
+
+pub async fn get_arena_ladder(
+    request: RequestContext,
+    body: RequestGetArenaLadder,
+) -> Result<RequestGetArenaLadderResponse> {
+    let context = request.context();
+    let mode = QuickMatchMode::try_from(body.get_mode())
+        .map_err(|id| RequestError::invalid(format!("unknown quick match mode {}", id)))?;
+
+    let mut count = context.config().ratings().max_ladder_entries();
+    if body.has_max_entries() {
+        count = count.min(body.get_max_entries() as usize);
+    }
+
+    let offset = body.get_offset() as usize;
+    let (ladder, total) = context.ratings().ladder(mode, offset, count);
+
+    let mut response = RequestGetArenaLadderResponse::new();
+    for (i, rating) in ladder.iter().enumerate() {
+        let mut entry = ArenaLadderEntry::new();
+        entry.set_rank((offset + i + 1) as u32);
+        entry.set_steam_id(rating.steam_id.clone());
+        entry.set_character_name(rating.character_name.clone());
+        entry.set_rating(rating.rating.round().max(0.0) as u32);
+        entry.set_wins(rating.wins);
+        entry.set_losses(rating.losses);
+        entry.set_draws(rating.draws);
+        response.mut_entries().push(entry);
+    }
+    response.set_total(total as u32);
+
+    Ok(response)
+}
@@ -0,0 +1,250 @@
+//! Tracking of the players that are currently connected to the game server.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::RwLock;
+use std::time::{Duration, Instant, SystemTime};
+
+use bytes::Bytes;
+use dks3_config::Covenant;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::bandwidth::Bandwidth;
+use crate::crypto::CWC_KEY_LEN;
+use crate::frame::{Message, MessageType};
+use crate::matchmaking::Level;
+use crate::transport::ConnectionSender;
+use crate::Result;
+
+pub type SessionId = u64;
+
+/// What we know about the character a player is currently logged in with.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct CharacterSummary {
+    pub name: String,
+    pub soul_level: u32,
+    pub max_weapon_level: u32,
+    pub online_area_id: u32,
+    /// The covenant the character has equipped, if any.
+    pub covenant: Option<Covenant>,
+    /// How many times the character has finished the game, starting from 0 on their first
+    /// playthrough.
+    pub ng_cycle: u32,
+    /// Whether the character is embered, and so can be invaded and summon help.
+    pub embered: bool,
+    /// Whether the character currently has phantoms in their world.
+    pub in_coop: bool,
+    /// The matchmaking password the character is using, if any.
+    pub password: Option<String>,
+}
+
+impl CharacterSummary {
+    pub fn level(&self) -> Level {
+        Level {
+            soul_level: self.soul_level,
+            max_weapon_level: self.max_weapon_level,
+            password: self.password.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub id: SessionId,
+    pub steam_id: String,
+    pub auth_token: u64,
+    pub remote_addr: SocketAddr,
+    pub session_key: [u8; CWC_KEY_LEN],
+    pub login_time: SystemTime,
+    pub last_activity: Instant,
+    pub character: Option<CharacterSummary>,
+    pub bandwidth: Bandwidth,
+    sender: ConnectionSender,
+}
+
+impl Session {
+    /// Send a payload to this player's client.
+    pub fn send(&self, payload: Bytes) -> Result<()> {
+        self.sender.send(payload)
+    }
+
+    /// Push a message to this player's client.
+    pub fn push<M>(&self, message_type: MessageType, body: &M) -> Result<()>
+    where
+        M: protobuf::Message,
+    {
+        self.send(Message::push(message_type, body)?.encode())
+    }
+
+    pub fn sender(&self) -> &ConnectionSender {
+        &self.sender
+    }
+}
+
+/// The details of a newly connected player, used to register their session.
+#[derive(Debug)]
+pub struct NewSession {
+    pub steam_id: String,
+    pub auth_token: u64,
+    pub remote_addr: SocketAddr,
+    pub session_key: [u8; CWC_KEY_LEN],
+    pub sender: ConnectionSender,
+}
+
+#[derive(Debug, Default)]
+struct Sessions {
+    next_id: SessionId,
+    by_id: HashMap<SessionId, Session>,
+    by_steam_id: HashMap<String, SessionId>,
+}
+
+impl Sessions {
+    fn remove(&mut self, id: SessionId) -> Option<Session> {
+        let session = self.by_id.remove(&id)?;
+        if self.by_steam_id.get(&session.steam_id) == Some(&id) {
+            self.by_steam_id.remove(&session.steam_id);
+        }
+
+        Some(session)
+    }
+}
+
+/// A concurrent registry of sessions, indexed by session ID and Steam ID.
+#[derive(Debug, Default)]
+pub struct SessionRegistry {
+    sessions: RwLock<Sessions>,
+}
+
+impl SessionRegistry {
+    /// Register a new session. If the player already had a session, for example because they
+    /// relogged before their previous connection timed out, the old one is evicted.
+    pub fn register(&self, new: NewSession) -> SessionId {
+        let mut sessions = self.sessions.write().unwrap();
+        sessions.next_id += 1;
+
+        let id = sessions.next_id;
+        let now = Instant::now();
+        let session = Session {
+            id,
+            steam_id: new.steam_id,
+            auth_token: new.auth_token,
+            remote_addr: new.remote_addr,
+            session_key: new.session_key,
+            login_time: SystemTime::now(),
+            last_activity: now,
+            character: None,
+            bandwidth: Bandwidth::default(),
+            sender: new.sender,
+        };
+
+        if let Some(previous) = sessions.by_steam_id.insert(session.steam_id.clone(), id) {
+            if let Some(previous) = sessions.remove(previous) {
+                previous.sender.close();
+            }
+        }
+
+        sessions.by_id.insert(id, session);
+        id
+    }
+
+    pub fn get(&self, id: SessionId) -> Option<Session> {
+        self.sessions.read().unwrap().by_id.get(&id).cloned()
+    }
+
+    pub fn by_steam_id(&self, steam_id: &str) -> Option<Session> {
+        let sessions = self.sessions.read().unwrap();
+        let id = sessions.by_steam_id.get(steam_id)?;
+
+        sessions.by_id.get(id).cloned()
+    }
+
+    pub fn contains(&self, id: SessionId) -> bool {
+        self.sessions.read().unwrap().by_id.contains_key(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.sessions.read().unwrap().by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A snapshot of every session.
+    pub fn all(&self) -> Vec<Session> {
+        self.sessions
+            .read()
+            .unwrap()
+            .by_id
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// A snapshot of every session matching `predicate`.
+    pub fn filter<P>(&self, mut predicate: P) -> Vec<Session>
+    where
+        P: FnMut(&Session) -> bool,
+    {
+        self.sessions
+            .read()
+            .unwrap()
+            .by_id
+            .values()
+            .filter(|session| predicate(session))
+            .cloned()
+            .collect()
+    }
+
+    /// Apply `update` to a session, returning `None` if it doesn't exist.
+    pub fn update<F, R>(&self, id: SessionId, update: F) -> Option<R>
+    where
+        F: FnOnce(&mut Session) -> R,
+    {
+        self.sessions
+            .write()
+            .unwrap()
+            .by_id
+            .get_mut(&id)
+            .map(update)
+    }
+
+    /// Record activity on a session, keeping it from expiring.
+    pub fn touch(&self, id: SessionId) {
+        self.update(id, |session| session.last_activity = Instant::now());
+    }
+
+    pub fn set_character(&self, id: SessionId, character: CharacterSummary) {
+        self.update(id, |session| session.character = Some(character));
+    }
+
+    /// Remove a session and close its connection.
+    pub fn evict(&self, id: SessionId) -> Option<Session> {
+        let session = self.remove(id)?;
+        session.sender.close();
+
+        Some(session)
+    }
+
+    /// Remove a session whose connection has already closed.
+    pub fn remove(&self, id: SessionId) -> Option<Session> {
+        self.sessions.write().unwrap().remove(id)
+    }
+
+    /// Evict every session that has been idle for at least `idle_timeout`.
+    pub fn expire_idle(&self, idle_timeout: Duration) -> Vec<Session> {
+        let mut sessions = self.sessions.write().unwrap();
+        let expired: Vec<SessionId> = sessions
+            .by_id
+            .values()
+            .filter(|session| session.last_activity.elapsed() >= idle_timeout)
+            .map(|session| session.id)
+            .collect();
+
+        expired
+            .into_iter()
+            .filter_map(|id| sessions.remove(id))
+            .inspect(|session| session.sender.close())
+            .collect()
+    }
+}
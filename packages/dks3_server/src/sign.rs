@@ -0,0 +1,492 @@
+//! Summon signs: written on the ground by players offering to join someone else's world, either
+//! to help (white signs) or to duel (red signs).
+//!
+//! A host who touches a sign asks the server to summon its owner. The request is pushed to the
+//! owner, whose answer is pushed back to the host. Signs only last as long as their owner's
+//! session.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use tracing::debug;
+
+use dks3_config::Matchmaking;
+use dks3_proto::Frpg2RequestMessage::{
+    PushRequestSummonSign, PushSummonSignResult, RequestCreateSign, RequestCreateSignResponse,
+    RequestGetSignList, RequestGetSignListResponse, RequestRemoveSign, RequestRemoveSignResponse,
+    RequestRespondSummonSign, RequestRespondSummonSignResponse, RequestSummonSign,
+    RequestSummonSignResponse, SignData,
+};
+
+use crate::bandwidth;
+use crate::dispatch::{RequestContext, RequestError};
+use crate::frame::MessageType;
+use crate::location::{self, Location};
+use crate::matchmaking::{self, Level, MultiplayerType};
+use crate::session::SessionId;
+use crate::{Result, ServerContext};
+
+pub type SignId = u64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignType {
+    /// Summons the owner to help the host.
+    White = 0,
+    /// Summons the owner to duel the host.
+    Red = 1,
+}
+
+impl SignType {
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+}
+
+impl TryFrom<u32> for SignType {
+    type Error = u32;
+
+    fn try_from(id: u32) -> std::result::Result<Self, u32> {
+        match id {
+            0 => Ok(SignType::White),
+            1 => Ok(SignType::Red),
+            _ => Err(id),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sign {
+    pub id: SignId,
+    pub session_id: SessionId,
+    pub steam_id: String,
+    pub character_name: String,
+    pub location: Location,
+    pub sign_type: SignType,
+    pub item_id: u32,
+    pub level: Level,
+}
+
+/// A host waiting for a sign's owner to answer their summon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingSummon {
+    pub session_id: SessionId,
+    pub steam_id: String,
+    requested: Instant,
+}
+
+/// The result of a host trying to summon a sign's owner.
+#[derive(Debug, PartialEq)]
+pub enum Summon {
+    /// The owner needs to be asked. Holds the sign, and the host whose unanswered summon was
+    /// abandoned for this one, if any.
+    Requested(Sign, Option<PendingSummon>),
+    /// Another host is already waiting for the owner to answer.
+    Busy,
+    OwnSign,
+    /// The host couldn't have been shown the sign under the matchmaking rules.
+    NoMatch,
+    NotFound,
+}
+
+#[derive(Debug)]
+struct Entry {
+    sign: Sign,
+    pending: Option<PendingSummon>,
+}
+
+#[derive(Debug, Default)]
+struct Signs {
+    next_id: SignId,
+    by_id: HashMap<SignId, Entry>,
+}
+
+#[derive(Debug, Default)]
+pub struct SignPool {
+    signs: RwLock<Signs>,
+}
+
+impl SignPool {
+    /// Put `sign` in the pool under a newly assigned ID, which is returned. Players only have
+    /// one sign down at a time, so any other sign from the same session is removed.
+    pub fn insert(&self, mut sign: Sign) -> SignId {
+        let mut signs = self.signs.write().unwrap();
+        signs
+            .by_id
+            .retain(|_, entry| entry.sign.session_id != sign.session_id);
+
+        signs.next_id += 1;
+        sign.id = signs.next_id;
+
+        let id = sign.id;
+        signs.by_id.insert(
+            id,
+            Entry {
+                sign,
+                pending: None,
+            },
+        );
+
+        id
+    }
+
+    pub fn get(&self, id: SignId) -> Option<Sign> {
+        self.signs
+            .read()
+            .unwrap()
+            .by_id
+            .get(&id)
+            .map(|entry| entry.sign.clone())
+    }
+
+    pub fn len(&self) -> usize {
+        self.signs.read().unwrap().by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove a sign, but only if it belongs to `session_id`. Returns the host that was waiting
+    /// on it, if any.
+    pub fn remove_owned(
+        &self,
+        id: SignId,
+        session_id: SessionId,
+    ) -> Option<(Sign, Option<PendingSummon>)> {
+        let mut signs = self.signs.write().unwrap();
+        match signs.by_id.get(&id) {
+            Some(entry) if entry.sign.session_id == session_id => signs
+                .by_id
+                .remove(&id)
+                .map(|entry| (entry.sign, entry.pending)),
+            _ => None,
+        }
+    }
+
+    /// Pick up to `count` signs at random from `online_area_id` that a host at `host` could
+    /// summon under the `matchmaking` rules. Signs belonging to `steam_id` or waiting on an
+    /// answer are left out.
+    pub fn visible_to<R: Rng>(
+        &self,
+        matchmaking: &Matchmaking,
+        online_area_id: u32,
+        steam_id: &str,
+        host: &Level,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<Sign> {
+        let signs = self.signs.read().unwrap();
+        let candidates: Vec<&Sign> = signs
+            .by_id
+            .values()
+            .filter(|entry| entry.pending.is_none())
+            .map(|entry| &entry.sign)
+            .filter(|sign| sign.location.online_area_id == online_area_id)
+            .filter(|sign| sign.steam_id != steam_id)
+            .filter(|sign| {
+                matchmaking::is_match(matchmaking, MultiplayerType::Coop, host, &sign.level)
+            })
+            .collect();
+
+        candidates
+            .choose_multiple(rng, count)
+            .map(|sign| (*sign).clone())
+            .collect()
+    }
+
+    /// Record that the host with `session_id` at `host` wants to summon a sign's owner. Signs
+    /// are checked against the `matchmaking` rules again, since hosts can ask for any sign ID,
+    /// not only those they were shown. A summon that has gone unanswered for longer than
+    /// `timeout` is abandoned in favour of the new one.
+    pub fn request_summon(
+        &self,
+        matchmaking: &Matchmaking,
+        id: SignId,
+        session_id: SessionId,
+        steam_id: &str,
+        host: &Level,
+        timeout: Duration,
+    ) -> Summon {
+        let mut signs = self.signs.write().unwrap();
+        let entry = match signs.by_id.get_mut(&id) {
+            Some(entry) => entry,
+            None => return Summon::NotFound,
+        };
+
+        if entry.sign.steam_id == steam_id {
+            return Summon::OwnSign;
+        }
+
+        if !matchmaking::is_match(matchmaking, MultiplayerType::Coop, host, &entry.sign.level) {
+            return Summon::NoMatch;
+        }
+
+        if let Some(pending) = &entry.pending {
+            if pending.requested.elapsed() < timeout {
+                return Summon::Busy;
+            }
+        }
+
+        let abandoned = entry.pending.replace(PendingSummon {
+            session_id,
+            steam_id: steam_id.to_string(),
+            requested: Instant::now(),
+        });
+
+        Summon::Requested(entry.sign.clone(), abandoned)
+    }
+
+    /// Record the answer of the owner with `session_id` to the summon waiting on their sign,
+    /// returning the host that was waiting. An accepted sign is used up; a rejected one goes
+    /// back in the pool.
+    pub fn respond(
+        &self,
+        id: SignId,
+        session_id: SessionId,
+        accepted: bool,
+    ) -> Option<PendingSummon> {
+        let mut signs = self.signs.write().unwrap();
+        let entry = signs
+            .by_id
+            .get_mut(&id)
+            .filter(|entry| entry.sign.session_id == session_id)?;
+
+        let pending = entry.pending.take()?;
+        if accepted {
+            signs.by_id.remove(&id);
+        }
+
+        Some(pending)
+    }
+
+    /// Forget everything to do with a session that has ended: the signs it owned are removed,
+    /// and any summons it was waiting on are abandoned. Returns the hosts that were waiting on
+    /// the removed signs.
+    pub fn remove_session(&self, session_id: SessionId) -> Vec<(Sign, PendingSummon)> {
+        let mut signs = self.signs.write().unwrap();
+
+        let owned: Vec<SignId> = signs
+            .by_id
+            .values()
+            .filter(|entry| entry.sign.session_id == session_id)
+            .map(|entry| entry.sign.id)
+            .collect();
+
+        let mut abandoned = vec![];
+        for id in owned {
+            if let Some(Entry {
+                sign,
+                pending: Some(pending),
+            }) = signs.by_id.remove(&id)
+            {
+                abandoned.push((sign, pending));
+            }
+        }
+
+        for entry in signs.by_id.values_mut() {
+            if entry.pending.as_ref().map(|pending| pending.session_id) == Some(session_id) {
+                entry.pending = None;
+            }
+        }
+
+        abandoned
+    }
+}
+
+/// Clean up after a player whose session has ended, letting any host waiting on one of their
+/// signs know the summon won't happen.
+pub fn session_ended(context: &ServerContext, session_id: SessionId) {
+    for (sign, host) in context.signs().remove_session(session_id) {
+        push_result(context, sign.id, &host, false);
+    }
+}
+
+pub async fn create_sign(
+    request: RequestContext,
+    body: RequestCreateSign,
+) -> Result<RequestCreateSignResponse> {
+    let sign_type = SignType::try_from(body.get_sign_type())
+        .map_err(|id| RequestError::invalid(format!("unknown sign type {}", id)))?;
+
+    let sign = Sign {
+        id: 0,
+        session_id: request.session_id(),
+        steam_id: request.steam_id().to_string(),
+        character_name: character_name(&request),
+        location: Location::from_request(
+            body.get_online_area_id(),
+            body.get_map_id(),
+            body.get_position(),
+            body.get_angle(),
+        )?,
+        sign_type,
+        item_id: body.get_item_id(),
+        level: matchmaking::from_matching_parameter(body.get_matching_parameter()),
+    };
+
+    let mut response = RequestCreateSignResponse::new();
+    response.set_sign_id(request.context().signs().insert(sign));
+
+    Ok(response)
+}
+
+pub async fn remove_sign(
+    request: RequestContext,
+    body: RequestRemoveSign,
+) -> Result<RequestRemoveSignResponse> {
+    let context = request.context();
+    let (sign, pending) = context
+        .signs()
+        .remove_owned(body.get_sign_id(), request.session_id())
+        .ok_or_else(|| RequestError::invalid("no such sign"))?;
+
+    if let Some(host) = pending {
+        push_result(context, sign.id, &host, false);
+    }
+
+    Ok(RequestRemoveSignResponse::new())
+}
+
+pub async fn get_sign_list(
+    request: RequestContext,
+    body: RequestGetSignList,
+) -> Result<RequestGetSignListResponse> {
+    let context = request.context();
+    let mut count = context.config().signs().max_list_entries();
+    if body.has_max_entries() {
+        count = count.min(body.get_max_entries() as usize);
+    }
+
+    let mut signs = context.signs().visible_to(
+        context.config().matchmaking(),
+        body.get_online_area_id(),
+        request.steam_id(),
+        &matchmaking::from_matching_parameter(body.get_matching_parameter()),
+        usize::MAX,
+        &mut rand::thread_rng(),
+    );
+
+    // Offer signs from players on poor connections last.
+    signs.sort_by_key(|sign| bandwidth::is_poor_connection(context, sign.session_id));
+    signs.truncate(count);
+
+    let mut response = RequestGetSignListResponse::new();
+    for sign in &signs {
+        response.mut_signs().push(to_sign_data(sign));
+    }
+
+    Ok(response)
+}
+
+pub async fn summon_sign(
+    request: RequestContext,
+    body: RequestSummonSign,
+) -> Result<RequestSummonSignResponse> {
+    let context = request.context();
+    let host = request
+        .session()
+        .and_then(|session| session.character)
+        .map(|character| character.level())
+        .ok_or_else(|| RequestError::invalid("host has not reported their status"))?;
+
+    let sign = match context.signs().request_summon(
+        context.config().matchmaking(),
+        body.get_sign_id(),
+        request.session_id(),
+        request.steam_id(),
+        &host,
+        context.config().signs().summon_timeout(),
+    ) {
+        Summon::Requested(sign, abandoned) => {
+            // The host that was waiting on the owner hears no more than if they'd said no.
+            if let Some(abandoned) = abandoned {
+                push_result(context, sign.id, &abandoned, false);
+            }
+            sign
+        }
+        Summon::Busy => return Err(RequestError::invalid("sign is already being summoned").into()),
+        Summon::OwnSign => return Err(RequestError::invalid("cannot summon own sign").into()),
+        // Answer as if it doesn't exist, so hosts can't find out about signs they can't see.
+        Summon::NoMatch => return Err(RequestError::invalid("no such sign").into()),
+        Summon::NotFound => return Err(RequestError::invalid("no such sign").into()),
+    };
+
+    let mut push = PushRequestSummonSign::new();
+    push.set_sign_id(sign.id);
+    push.set_host_steam_id(request.steam_id().to_string());
+    push.set_host_character_name(character_name(&request));
+
+    // The owner may have left since the host saw the sign. Their sign goes with them.
+    let delivered = context
+        .sessions()
+        .get(sign.session_id)
+        .map(|owner| owner.push(MessageType::PushRequestSummonSign, &push));
+
+    if !matches!(delivered, Some(Ok(()))) {
+        context.signs().remove_owned(sign.id, sign.session_id);
+        return Err(RequestError::invalid("sign owner is no longer available").into());
+    }
+
+    Ok(RequestSummonSignResponse::new())
+}
+
+pub async fn respond_summon_sign(
+    request: RequestContext,
+    body: RequestRespondSummonSign,
+) -> Result<RequestRespondSummonSignResponse> {
+    let context = request.context();
+    let host = context
+        .signs()
+        .respond(
+            body.get_sign_id(),
+            request.session_id(),
+            body.get_accepted(),
+        )
+        .ok_or_else(|| RequestError::invalid("no summon is waiting on this sign"))?;
+
+    push_result(context, body.get_sign_id(), &host, body.get_accepted());
+
+    Ok(RequestRespondSummonSignResponse::new())
+}
+
+fn push_result(context: &ServerContext, sign_id: SignId, host: &PendingSummon, accepted: bool) {
+    let session = match context.sessions().get(host.session_id) {
+        Some(session) => session,
+        None => return,
+    };
+
+    let mut push = PushSummonSignResult::new();
+    push.set_sign_id(sign_id);
+    push.set_accepted(accepted);
+
+    if let Err(e) = session.push(MessageType::PushSummonSignResult, &push) {
+        debug!("Failed to notify host of summon result: {}", e);
+    }
+}
+
+fn character_name(request: &RequestContext) -> String {
+    request
+        .session()
+        .and_then(|session| session.character)
+        .map(|character| character.name)
+        .unwrap_or_default()
+}
+
+fn to_sign_data(sign: &Sign) -> SignData {
+    let mut data = SignData::new();
+    data.set_sign_id(sign.id);
+    data.set_steam_id(sign.steam_id.clone());
+    data.set_character_name(sign.character_name.clone());
+    data.set_online_area_id(sign.location.online_area_id);
+    data.set_map_id(sign.location.map_id);
+    data.set_position(location::to_vector(sign.location.position));
+    data.set_angle(sign.location.angle);
+    data.set_sign_type(sign.sign_type.id());
+    data.set_item_id(sign.item_id);
+    data.set_matching_parameter(matchmaking::to_matching_parameter(&sign.level));
+    data
+}
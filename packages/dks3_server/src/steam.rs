@@ -0,0 +1,140 @@
+//! Verification of the Steam session ticket presented during authentication.
+//!
+//! A private server can choose how strict it wants to be: accept everyone (LAN play), only
+//! accept a fixed list of Steam IDs, or defer to an HTTP service. Only the HTTP service looks at
+//! the ticket itself. It issues a `GET` to the configured endpoint with `steam_id` and a hex
+//! encoded `ticket` as query parameters, and treats any successful status code as acceptance.
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use hyper::client::HttpConnector;
+use hyper::{Client, StatusCode, Uri};
+
+use dks3_config::SteamVerifier;
+
+use crate::Result;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    Rejected(String),
+}
+
+#[async_trait]
+pub trait TicketVerifier: Debug + Send + Sync {
+    /// Decide whether `ticket` proves the client owns `steam_id`. An `Err` means the ticket
+    /// could not be checked at all, rather than that it was invalid.
+    async fn verify(&self, steam_id: &str, ticket: &[u8]) -> Result<Verdict>;
+}
+
+pub fn from_config(config: &SteamVerifier) -> Result<Box<dyn TicketVerifier>> {
+    Ok(match config {
+        SteamVerifier::AcceptAll => Box::new(AcceptAll),
+        SteamVerifier::Allowlist { steam_ids } => {
+            Box::new(Allowlist::new(steam_ids.iter().cloned()))
+        }
+        SteamVerifier::Http {
+            endpoint,
+            timeout_secs,
+        } => Box::new(HttpVerifier::new(
+            endpoint,
+            Duration::from_secs(*timeout_secs),
+        )?),
+    })
+}
+
+#[derive(Debug)]
+pub struct AcceptAll;
+
+#[async_trait]
+impl TicketVerifier for AcceptAll {
+    async fn verify(&self, _steam_id: &str, _ticket: &[u8]) -> Result<Verdict> {
+        Ok(Verdict::Accepted)
+    }
+}
+
+/// Accepts the listed Steam IDs without looking at the ticket.
+#[derive(Debug)]
+pub struct Allowlist {
+    steam_ids: HashSet<String>,
+}
+
+impl Allowlist {
+    pub fn new<I: IntoIterator<Item = String>>(steam_ids: I) -> Self {
+        Self {
+            steam_ids: steam_ids.into_iter().collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl TicketVerifier for Allowlist {
+    async fn verify(&self, steam_id: &str, _ticket: &[u8]) -> Result<Verdict> {
+        if self.steam_ids.contains(steam_id) {
+            Ok(Verdict::Accepted)
+        } else {
+            Ok(Verdict::Rejected(format!("{} is not allowed", steam_id)))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct HttpVerifier {
+    client: Client<HttpConnector>,
+    endpoint: Uri,
+    timeout: Duration,
+}
+
+impl HttpVerifier {
+    pub fn new(endpoint: &str, timeout: Duration) -> Result<Self> {
+        Ok(Self {
+            client: Client::new(),
+            endpoint: endpoint.parse()?,
+            timeout,
+        })
+    }
+}
+
+#[async_trait]
+impl TicketVerifier for HttpVerifier {
+    async fn verify(&self, steam_id: &str, ticket: &[u8]) -> Result<Verdict> {
+        if !steam_id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Ok(Verdict::Rejected(format!(
+                "malformed Steam ID {:?}",
+                steam_id
+            )));
+        }
+
+        let separator = if self.endpoint.query().is_some() {
+            '&'
+        } else {
+            '?'
+        };
+
+        let uri: Uri = format!(
+            "{}{}steam_id={}&ticket={}",
+            self.endpoint,
+            separator,
+            steam_id,
+            hex::encode(ticket)
+        )
+        .parse()?;
+
+        let response = tokio::time::timeout(self.timeout, self.client.get(uri)).await??;
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(Verdict::Accepted)
+        } else if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+            Ok(Verdict::Rejected(format!(
+                "verifier responded with {}",
+                status
+            )))
+        } else {
+            Err(format!("verifier responded with {}", status).into())
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Persistence of server state across restarts.
+//!
+//! Each store that needs to survive a restart is written as a JSON snapshot to its own file in
+//! the configured data directory. Stores mark themselves dirty when they change and are flushed
+//! periodically and on shutdown.
+//!
+//! Large binary data, such as replays, is kept out of the snapshots and written as separate
+//! blobs in a directory named after the store, so that it is only written once rather than on
+//! every flush.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::Result;
+
+#[derive(Debug, Default)]
+pub struct Storage {
+    data_dir: Option<PathBuf>,
+}
+
+impl Storage {
+    /// Storage in `data_dir`, or nowhere at all if it is `None`.
+    pub fn new(data_dir: Option<&Path>) -> Self {
+        Self {
+            data_dir: data_dir.map(Path::to_path_buf),
+        }
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.data_dir.is_some()
+    }
+
+    /// Load the snapshot called `name`, or `None` if there isn't one.
+    pub fn load<T>(&self, name: &str) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let path = match self.path(name) {
+            Some(path) if path.exists() => path,
+            _ => return Ok(None),
+        };
+
+        let data = fs::read(&path)?;
+        serde_json::from_slice(&data)
+            .map(Some)
+            .map_err(|e| format!("{} is corrupt: {}", path.display(), e).into())
+    }
+
+    /// Replace the snapshot called `name` with `value`.
+    pub fn save<T>(&self, name: &str, value: &T) -> Result<()>
+    where
+        T: Serialize,
+    {
+        let path = match self.path(name) {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Write to a temporary file first so a crash mid-write can't lose the previous snapshot.
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_vec(value)?)?;
+        fs::rename(temp_path, path)?;
+
+        Ok(())
+    }
+
+    /// Load the blob called `key` belonging to the store called `name`, or `None` if there isn't
+    /// one.
+    pub fn load_blob(&self, name: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        match self.blob_path(name, key) {
+            Some(path) if path.exists() => Ok(Some(fs::read(path)?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Replace the blob called `key` belonging to the store called `name` with `data`.
+    pub fn save_blob(&self, name: &str, key: &str, data: &[u8]) -> Result<()> {
+        let path = match self.blob_path(name, key) {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let temp_path = path.with_extension("bin.tmp");
+        fs::write(&temp_path, data)?;
+        fs::rename(temp_path, path)?;
+
+        Ok(())
+    }
+
+    /// Remove the blob called `key` belonging to the store called `name`, if there is one.
+    pub fn remove_blob(&self, name: &str, key: &str) -> Result<()> {
+        match self.blob_path(name, key) {
+            Some(path) if path.exists() => Ok(fs::remove_file(path)?),
+            _ => Ok(()),
+        }
+    }
+
+    fn path(&self, name: &str) -> Option<PathBuf> {
+        self.data_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.json", name)))
+    }
+
+    fn blob_path(&self, name: &str, key: &str) -> Option<PathBuf> {
+        self.data_dir
+            .as_ref()
+            .map(|dir| dir.join(name).join(format!("{}.bin", key)))
+    }
+}
+
+/// Tracks whether a store has changed since it was last saved.
+#[derive(Debug, Default)]
+pub struct DirtyFlag(AtomicBool);
+
+impl DirtyFlag {
+    pub fn mark(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Clear the flag, returning whether it was set.
+    pub fn take(&self) -> bool {
+        self.0.swap(false, Ordering::SeqCst)
+    }
+}
@@ -0,0 +1,329 @@
+//! Gameplay statistics gathered from the notifications clients send when something happens in
+//! their world: dying, killing enemies, bosses and other players, buying items, and joining or
+//! leaving multiplayer sessions.
+//!
+//! Nothing about who sent a notification is kept. Events are only counted, per day (in UTC),
+//! per online area, per boss and per item, which is enough for heatmaps and stats pages.
+
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+use dks3_proto::Frpg2RequestMessage::{
+    RequestNotifyBuyItem, RequestNotifyBuyItemResponse, RequestNotifyDeath,
+    RequestNotifyDeathResponse, RequestNotifyJoinMultiplayer, RequestNotifyJoinMultiplayerResponse,
+    RequestNotifyKillBoss, RequestNotifyKillBossResponse, RequestNotifyKillEnemy,
+    RequestNotifyKillEnemyResponse, RequestNotifyKillPlayer, RequestNotifyKillPlayerResponse,
+    RequestNotifyLeaveMultiplayer, RequestNotifyLeaveMultiplayerResponse,
+};
+
+use crate::dispatch::RequestContext;
+use crate::storage::{DirtyFlag, Storage};
+use crate::Result;
+
+const STORAGE_NAME: &str = "telemetry";
+
+/// Something that happened in a player's world.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    Death {
+        online_area_id: u32,
+    },
+    EnemyKill {
+        online_area_id: u32,
+        enemy_id: u32,
+    },
+    BossKill {
+        online_area_id: u32,
+        boss_id: u32,
+    },
+    PlayerKill {
+        online_area_id: u32,
+    },
+    Purchase {
+        online_area_id: u32,
+        item_id: u32,
+        quantity: u32,
+    },
+    JoinMultiplayer {
+        online_area_id: u32,
+    },
+    LeaveMultiplayer {
+        online_area_id: u32,
+    },
+}
+
+impl Event {
+    pub fn online_area_id(&self) -> u32 {
+        match *self {
+            Event::Death { online_area_id }
+            | Event::EnemyKill { online_area_id, .. }
+            | Event::BossKill { online_area_id, .. }
+            | Event::PlayerKill { online_area_id }
+            | Event::Purchase { online_area_id, .. }
+            | Event::JoinMultiplayer { online_area_id }
+            | Event::LeaveMultiplayer { online_area_id } => online_area_id,
+        }
+    }
+}
+
+/// How many times each kind of event happened.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Counters {
+    pub deaths: u64,
+    pub enemy_kills: u64,
+    pub boss_kills: u64,
+    pub player_kills: u64,
+    pub purchases: u64,
+    pub multiplayer_joins: u64,
+    pub multiplayer_leaves: u64,
+}
+
+impl Counters {
+    fn count(&mut self, event: &Event) {
+        let counter = match event {
+            Event::Death { .. } => &mut self.deaths,
+            Event::EnemyKill { .. } => &mut self.enemy_kills,
+            Event::BossKill { .. } => &mut self.boss_kills,
+            Event::PlayerKill { .. } => &mut self.player_kills,
+            Event::Purchase { .. } => &mut self.purchases,
+            Event::JoinMultiplayer { .. } => &mut self.multiplayer_joins,
+            Event::LeaveMultiplayer { .. } => &mut self.multiplayer_leaves,
+        };
+
+        *counter += 1;
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemSales {
+    /// How many times the item was bought.
+    pub purchases: u64,
+    /// How many of the item were bought in all.
+    pub quantity: u64,
+}
+
+/// Everything that happened on one day.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DailyStats {
+    pub totals: Counters,
+    pub areas: BTreeMap<u32, Counters>,
+    /// How many times each boss was killed.
+    pub bosses: BTreeMap<u32, u64>,
+    pub items: BTreeMap<u32, ItemSales>,
+}
+
+impl DailyStats {
+    fn record(&mut self, event: &Event) {
+        self.totals.count(event);
+        self.areas
+            .entry(event.online_area_id())
+            .or_default()
+            .count(event);
+
+        match *event {
+            Event::BossKill { boss_id, .. } => *self.bosses.entry(boss_id).or_insert(0) += 1,
+            Event::Purchase {
+                item_id, quantity, ..
+            } => {
+                let sales = self.items.entry(item_id).or_default();
+                sales.purchases += 1;
+                sales.quantity += u64::from(quantity);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Snapshot {
+    days: BTreeMap<String, DailyStats>,
+}
+
+/// Daily statistics, keyed by the date they were gathered on, e.g. `2020-11-01`.
+#[derive(Debug, Default)]
+pub struct TelemetryStore {
+    days: RwLock<BTreeMap<String, DailyStats>>,
+    dirty: DirtyFlag,
+}
+
+impl TelemetryStore {
+    /// Load the statistics saved in `storage`.
+    pub fn load(storage: &Storage) -> Result<Self> {
+        let snapshot: Snapshot = storage.load(STORAGE_NAME)?.unwrap_or_default();
+
+        Ok(Self {
+            days: RwLock::new(snapshot.days),
+            dirty: DirtyFlag::default(),
+        })
+    }
+
+    /// Save the statistics to `storage` if they have changed since they were last saved.
+    pub fn save(&self, storage: &Storage) -> Result<()> {
+        if !self.dirty.take() {
+            return Ok(());
+        }
+
+        let snapshot = Snapshot {
+            days: self.days.read().unwrap().clone(),
+        };
+        let result = storage.save(STORAGE_NAME, &snapshot);
+        if result.is_err() {
+            self.dirty.mark();
+        }
+
+        result
+    }
+
+    /// Count `event`, which happened at `time`, dropping the oldest days beyond `max_days`.
+    pub fn record(&self, event: &Event, time: DateTime<Utc>, max_days: usize) {
+        let mut days = self.days.write().unwrap();
+        days.entry(day(time)).or_default().record(event);
+
+        while days.len() > max_days {
+            let oldest = match days.keys().next() {
+                Some(oldest) => oldest.clone(),
+                None => break,
+            };
+            days.remove(&oldest);
+        }
+
+        self.dirty.mark();
+    }
+
+    /// The dates there are statistics for, oldest first.
+    pub fn days(&self) -> Vec<String> {
+        self.days.read().unwrap().keys().cloned().collect()
+    }
+
+    /// The statistics for the date `day`, e.g. `2020-11-01`.
+    pub fn day(&self, day: &str) -> Option<DailyStats> {
+        self.days.read().unwrap().get(day).cloned()
+    }
+
+    /// The totals for every day there are statistics for.
+    pub fn totals(&self) -> BTreeMap<String, Counters> {
+        self.days
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(day, stats)| (day.clone(), stats.totals.clone()))
+            .collect()
+    }
+}
+
+/// The date `time` falls on in UTC, as used to key daily statistics.
+pub fn day(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%d").to_string()
+}
+
+fn record(request: &RequestContext, event: Event) {
+    let context = request.context();
+    context
+        .telemetry()
+        .record(&event, Utc::now(), context.config().telemetry().max_days());
+}
+
+pub async fn notify_death(
+    request: RequestContext,
+    body: RequestNotifyDeath,
+) -> Result<RequestNotifyDeathResponse> {
+    record(
+        &request,
+        Event::Death {
+            online_area_id: body.get_online_area_id(),
+        },
+    );
+
+    Ok(RequestNotifyDeathResponse::new())
+}
+
+pub async fn notify_kill_enemy(
+    request: RequestContext,
+    body: RequestNotifyKillEnemy,
+) -> Result<RequestNotifyKillEnemyResponse> {
+    record(
+        &request,
+        Event::EnemyKill {
+            online_area_id: body.get_online_area_id(),
+            enemy_id: body.get_enemy_id(),
+        },
+    );
+
+    Ok(RequestNotifyKillEnemyResponse::new())
+}
+
+pub async fn notify_kill_boss(
+    request: RequestContext,
+    body: RequestNotifyKillBoss,
+) -> Result<RequestNotifyKillBossResponse> {
+    record(
+        &request,
+        Event::BossKill {
+            online_area_id: body.get_online_area_id(),
+            boss_id: body.get_boss_id(),
+        },
+    );
+
+    Ok(RequestNotifyKillBossResponse::new())
+}
+
+pub async fn notify_kill_player(
+    request: RequestContext,
+    body: RequestNotifyKillPlayer,
+) -> Result<RequestNotifyKillPlayerResponse> {
+    record(
+        &request,
+        Event::PlayerKill {
+            online_area_id: body.get_online_area_id(),
+        },
+    );
+
+    Ok(RequestNotifyKillPlayerResponse::new())
+}
+
+pub async fn notify_buy_item(
+    request: RequestContext,
+    body: RequestNotifyBuyItem,
+) -> Result<RequestNotifyBuyItemResponse> {
+    record(
+        &request,
+        Event::Purchase {
+            online_area_id: body.get_online_area_id(),
+            item_id: body.get_item_id(),
+            quantity: body.get_quantity(),
+        },
+    );
+
+    Ok(RequestNotifyBuyItemResponse::new())
+}
+
+pub async fn notify_join_multiplayer(
+    request: RequestContext,
+    body: RequestNotifyJoinMultiplayer,
+) -> Result<RequestNotifyJoinMultiplayerResponse> {
+    record(
+        &request,
+        Event::JoinMultiplayer {
+            online_area_id: body.get_online_area_id(),
+        },
+    );
+
+    Ok(RequestNotifyJoinMultiplayerResponse::new())
+}
+
+pub async fn notify_leave_multiplayer(
+    request: RequestContext,
+    body: RequestNotifyLeaveMultiplayer,
+) -> Result<RequestNotifyLeaveMultiplayerResponse> {
+    record(
+        &request,
+        Event::LeaveMultiplayer {
+            online_area_id: body.get_online_area_id(),
+        },
+    );
+
+    Ok(RequestNotifyLeaveMultiplayerResponse::new())
+}
@@ -0,0 +1,500 @@
+//! The UDP transport used for the game server conversation.
+//!
+//! Each datagram starts with the 8 byte auth token the client was issued by the auth server,
+//! in the clear, so we know which session key to use. The rest of the datagram is a
+//! [`reliable::Packet`] encrypted with that key, with the token as additional authenticated
+//! data. The first datagram from a new peer must be a `Syn`, and its token is redeemed with
+//! [`AuthTokens`](crate::auth::AuthTokens) to open the connection.
+//!
+//! A [`Listener`] hands out a [`Connection`] for each client, from which payloads can be
+//! received in order and through which payloads can be sent reliably.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryInto;
+use std::net::SocketAddr;
+use std::time::Instant;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::future::{poll_fn, FutureExt};
+use tokio::net::udp::{RecvHalf, SendHalf};
+use tokio::net::{ToSocketAddrs, UdpSocket};
+use tokio::sync::mpsc;
+use tracing::{debug, info, info_span, warn};
+use tracing_futures::Instrument;
+
+use crate::auth::AuthTicket;
+use crate::crypto::CwcCipher;
+use crate::{Result, ServerContext};
+
+pub use self::reliable::{ChannelConfig, ChannelState, Packet, PacketKind, ReliableChannel};
+
+pub mod reliable;
+
+const TOKEN_LEN: usize = 8;
+const MAX_DATAGRAM_LEN: usize = 65536;
+
+type Datagram = (Bytes, SocketAddr);
+
+enum Outbound {
+    Payload(Bytes),
+    Close,
+}
+
+impl std::fmt::Debug for Outbound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outbound::Payload(payload) => write!(f, "Payload({} bytes)", payload.len()),
+            Outbound::Close => write!(f, "Close"),
+        }
+    }
+}
+
+/// A handle that can send payloads to a connection, and can be shared freely.
+#[derive(Debug, Clone)]
+pub struct ConnectionSender {
+    outbound: mpsc::UnboundedSender<Outbound>,
+}
+
+impl ConnectionSender {
+    pub fn send(&self, payload: Bytes) -> Result<()> {
+        self.outbound
+            .send(Outbound::Payload(payload))
+            .map_err(|_| "connection is closed".into())
+    }
+
+    /// Close the connection once the peer has acknowledged everything sent so far, or the
+    /// channel has given up retransmitting it. Nothing sent after this is delivered.
+    pub fn close(&self) {
+        let _ = self.outbound.send(Outbound::Close);
+    }
+}
+
+#[derive(Debug)]
+pub struct Connection {
+    peer: SocketAddr,
+    token: u64,
+    inbound: mpsc::Receiver<Bytes>,
+    sender: ConnectionSender,
+}
+
+impl Connection {
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer
+    }
+
+    /// The auth token this connection was opened with.
+    pub fn token(&self) -> u64 {
+        self.token
+    }
+
+    pub fn sender(&self) -> ConnectionSender {
+        self.sender.clone()
+    }
+
+    pub fn send(&self, payload: Bytes) -> Result<()> {
+        self.sender.send(payload)
+    }
+
+    /// Receive the next payload from the peer, or `None` once the connection has closed.
+    pub async fn recv(&mut self) -> Option<Bytes> {
+        self.inbound.recv().await
+    }
+
+    pub fn close(&self) {
+        self.sender.close()
+    }
+}
+
+/// Accepts connections to the game server.
+pub struct Listener {
+    local_addr: SocketAddr,
+    incoming: mpsc::Receiver<(Connection, AuthTicket)>,
+}
+
+impl Listener {
+    pub async fn bind<A: ToSocketAddrs>(context: ServerContext, addr: A) -> Result<Self> {
+        Self::bind_with_config(context, addr, ChannelConfig::default()).await
+    }
+
+    pub async fn bind_with_config<A: ToSocketAddrs>(
+        context: ServerContext,
+        addr: A,
+        config: ChannelConfig,
+    ) -> Result<Self> {
+        let socket = UdpSocket::bind(addr).await?;
+        let local_addr = socket.local_addr()?;
+        let (recv, send) = socket.split();
+
+        let datagrams = spawn_send_loop(send);
+        let (incoming_tx, incoming) = mpsc::channel(64);
+
+        tokio::spawn(
+            listen(context, config, recv, datagrams, incoming_tx)
+                .instrument(info_span!("game", %local_addr)),
+        );
+
+        Ok(Self {
+            local_addr,
+            incoming,
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Wait for the next client to connect, along with the ticket it authenticated with.
+    pub async fn accept(&mut self) -> Option<(Connection, AuthTicket)> {
+        self.incoming.recv().await
+    }
+}
+
+/// Connect to a game server as a client would, using a token and key from the auth server.
+pub async fn connect(addr: SocketAddr, token: u64, key: &[u8]) -> Result<Connection> {
+    connect_with_config(addr, token, key, ChannelConfig::default()).await
+}
+
+pub async fn connect_with_config(
+    addr: SocketAddr,
+    token: u64,
+    key: &[u8],
+    config: ChannelConfig,
+) -> Result<Connection> {
+    let local_addr: SocketAddr = if addr.is_ipv4() {
+        "0.0.0.0:0".parse()?
+    } else {
+        "[::]:0".parse()?
+    };
+
+    let socket = UdpSocket::bind(local_addr).await?;
+    let (mut recv, send) = socket.split();
+    let datagrams = spawn_send_loop(send);
+    let cipher = CwcCipher::new(key)?;
+    let (mut packets_tx, packets_rx) = mpsc::channel(256);
+    let (closed_tx, _) = mpsc::unbounded_channel();
+
+    let channel = ReliableChannel::connect(config, Instant::now());
+    let connection = spawn_connection(
+        channel,
+        cipher.clone(),
+        token,
+        addr,
+        packets_rx,
+        datagrams,
+        closed_tx,
+    );
+
+    tokio::spawn(async move {
+        let mut buffer = vec![0u8; MAX_DATAGRAM_LEN];
+        loop {
+            let (len, from) = match recv.recv_from(&mut buffer).await {
+                Ok(received) => received,
+                Err(_) => return,
+            };
+
+            if from != addr {
+                continue;
+            }
+
+            match open_datagram(&cipher, &buffer[..len]) {
+                Ok((_, packet)) => {
+                    if packets_tx.send(packet).await.is_err() {
+                        return;
+                    }
+                }
+                Err(e) => debug!("Dropping datagram from server: {}", e),
+            }
+        }
+    });
+
+    Ok(connection)
+}
+
+fn spawn_send_loop(mut send: SendHalf) -> mpsc::Sender<Datagram> {
+    let (datagrams_tx, mut datagrams) = mpsc::channel::<Datagram>(1024);
+
+    tokio::spawn(async move {
+        while let Some((datagram, peer)) = datagrams.recv().await {
+            if let Err(e) = send.send_to(&datagram, &peer).await {
+                warn!(%peer, "Failed to send datagram: {}", e);
+            }
+        }
+    });
+
+    datagrams_tx
+}
+
+struct Peer {
+    token: u64,
+    cipher: CwcCipher,
+    packets: mpsc::Sender<Packet>,
+}
+
+async fn listen(
+    context: ServerContext,
+    config: ChannelConfig,
+    mut recv: RecvHalf,
+    datagrams: mpsc::Sender<Datagram>,
+    mut incoming: mpsc::Sender<(Connection, AuthTicket)>,
+) {
+    let mut peers: HashMap<SocketAddr, Peer> = HashMap::new();
+    let (closed_tx, mut closed) = mpsc::unbounded_channel::<(SocketAddr, u64)>();
+    let mut buffer = vec![0u8; MAX_DATAGRAM_LEN];
+
+    loop {
+        let (len, addr) = tokio::select! {
+            received = recv.recv_from(&mut buffer) => match received {
+                Ok(received) => received,
+                Err(e) => {
+                    warn!("Failed to receive datagram: {}", e);
+                    continue;
+                }
+            },
+            Some((addr, token)) = closed.recv() => {
+                if peers.get(&addr).map(|peer| peer.token) == Some(token) {
+                    peers.remove(&addr);
+                }
+                continue;
+            }
+        };
+
+        let datagram = &buffer[..len];
+        let token = match datagram_token(datagram) {
+            Some(token) => token,
+            None => continue,
+        };
+
+        if let Some(peer) = peers.get_mut(&addr).filter(|peer| peer.token == token) {
+            match open_datagram(&peer.cipher, datagram) {
+                Ok((_, packet)) => {
+                    // If the connection is backed up we drop the packet, and the peer will
+                    // retransmit it.
+                    let _ = peer.packets.try_send(packet);
+                }
+                Err(e) => debug!(%addr, "Dropping datagram: {}", e),
+            }
+
+            continue;
+        }
+
+        // The token is only used up once it has opened a valid Syn, so a corrupt or spoofed
+        // datagram carrying it can't lock the client out.
+        let ticket = match context.auth_tokens().peek(token) {
+            Some(ticket) => ticket,
+            None => {
+                debug!(%addr, "Dropping datagram with unknown auth token");
+                continue;
+            }
+        };
+
+        let cipher = CwcCipher::new(&ticket.game_key).expect("auth tickets hold valid keys");
+        match open_datagram(&cipher, datagram) {
+            Ok((_, packet)) if packet.kind == PacketKind::Syn => {}
+            _ => {
+                warn!(%addr, "First datagram of a connection was not a valid Syn");
+                continue;
+            }
+        }
+
+        // Waiting for room in the accept queue would hold up every other peer's datagrams, so
+        // the Syn is dropped instead, without using up the token, and the client retransmits it.
+        match poll_fn(|cx| incoming.poll_ready(cx)).now_or_never() {
+            Some(Ok(())) => {}
+            Some(Err(_)) => return,
+            None => {
+                debug!(%addr, "Accept queue is full, dropping Syn");
+                continue;
+            }
+        }
+
+        let ticket = match context.auth_tokens().redeem(token) {
+            Some(ticket) => ticket,
+            None => continue,
+        };
+
+        // Accepting the channel answers the Syn.
+        let (packets_tx, packets_rx) = mpsc::channel(256);
+        let channel = ReliableChannel::accept(config.clone(), Instant::now());
+
+        let connection = spawn_connection(
+            channel,
+            cipher.clone(),
+            token,
+            addr,
+            packets_rx,
+            datagrams.clone(),
+            closed_tx.clone(),
+        );
+
+        info!(%addr, steam_id = ticket.steam_id.as_str(), "Client connected");
+
+        if incoming.try_send((connection, ticket)).is_err() {
+            return;
+        }
+
+        peers.insert(
+            addr,
+            Peer {
+                token,
+                cipher,
+                packets: packets_tx,
+            },
+        );
+    }
+}
+
+fn datagram_token(datagram: &[u8]) -> Option<u64> {
+    datagram
+        .get(..TOKEN_LEN)
+        .map(|token| u64::from_be_bytes(token.try_into().unwrap()))
+}
+
+fn open_datagram(cipher: &CwcCipher, datagram: &[u8]) -> Result<(u64, Packet)> {
+    let token = datagram_token(datagram).ok_or("datagram is too short")?;
+    let (aad, sealed) = datagram.split_at(TOKEN_LEN);
+    let packet = Packet::decode(cipher.open(aad, sealed)?.into())?;
+
+    Ok((token, packet))
+}
+
+fn seal_datagram(cipher: &CwcCipher, token: u64, packet: &Packet) -> Result<Bytes> {
+    let aad = token.to_be_bytes();
+    let sealed = cipher.seal(&mut rand::thread_rng(), &aad, &packet.encode())?;
+
+    let mut datagram = BytesMut::with_capacity(TOKEN_LEN + sealed.len());
+    datagram.put_slice(&aad);
+    datagram.put_slice(&sealed);
+
+    Ok(datagram.freeze())
+}
+
+fn spawn_connection(
+    channel: ReliableChannel,
+    cipher: CwcCipher,
+    token: u64,
+    peer: SocketAddr,
+    packets: mpsc::Receiver<Packet>,
+    datagrams: mpsc::Sender<Datagram>,
+    closed: mpsc::UnboundedSender<(SocketAddr, u64)>,
+) -> Connection {
+    let (inbound_tx, inbound) = mpsc::channel(256);
+    let (outbound_tx, outbound) = mpsc::unbounded_channel();
+
+    tokio::spawn(
+        async move {
+            let driver = ConnectionDriver {
+                channel,
+                cipher,
+                token,
+                peer,
+                datagrams,
+            };
+
+            driver.run(packets, outbound, inbound_tx).await;
+            let _ = closed.send((peer, token));
+        }
+        .instrument(info_span!("connection", %peer)),
+    );
+
+    Connection {
+        peer,
+        token,
+        inbound,
+        sender: ConnectionSender {
+            outbound: outbound_tx,
+        },
+    }
+}
+
+/// Hand as many `undelivered` payloads to the connection's owner as it has room for, closing
+/// `channel` if it has gone away.
+fn deliver(
+    undelivered: &mut VecDeque<Bytes>,
+    inbound: &mut mpsc::Sender<Bytes>,
+    channel: &mut ReliableChannel,
+) {
+    while let Some(payload) = undelivered.pop_front() {
+        match inbound.try_send(payload) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(payload)) => {
+                undelivered.push_front(payload);
+                return;
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                undelivered.clear();
+                channel.close();
+                return;
+            }
+        }
+    }
+}
+
+struct ConnectionDriver {
+    channel: ReliableChannel,
+    cipher: CwcCipher,
+    token: u64,
+    peer: SocketAddr,
+    datagrams: mpsc::Sender<Datagram>,
+}
+
+impl ConnectionDriver {
+    async fn run(
+        mut self,
+        mut packets: mpsc::Receiver<Packet>,
+        mut outbound: mpsc::UnboundedReceiver<Outbound>,
+        mut inbound: mpsc::Sender<Bytes>,
+    ) {
+        // Once asked to close, we stop taking payloads and close after the peer has
+        // acknowledged everything sent so far.
+        let mut closing = false;
+
+        // Payloads received but not yet taken by the connection's owner. While there are any we
+        // stop reading packets, leaving the peer to retransmit, but keep our timers running.
+        let mut undelivered: VecDeque<Bytes> = VecDeque::new();
+
+        loop {
+            if closing && self.channel.is_drained() {
+                self.channel.close();
+            }
+
+            if self.flush().await.is_err() || self.channel.is_closed() {
+                break;
+            }
+
+            let deadline = tokio::time::Instant::from_std(self.channel.next_deadline());
+
+            tokio::select! {
+                packet = packets.recv(), if undelivered.is_empty() => match packet {
+                    Some(packet) => {
+                        undelivered.extend(self.channel.receive(packet, Instant::now()));
+                        deliver(&mut undelivered, &mut inbound, &mut self.channel);
+                    }
+                    None => self.channel.close(),
+                },
+                ready = poll_fn(|cx| inbound.poll_ready(cx)), if !undelivered.is_empty() => {
+                    match ready {
+                        Ok(()) => deliver(&mut undelivered, &mut inbound, &mut self.channel),
+                        Err(_) => self.channel.close(),
+                    }
+                }
+                message = outbound.recv(), if !closing => match message {
+                    Some(Outbound::Payload(payload)) => self.channel.send(payload),
+                    Some(Outbound::Close) | None => closing = true,
+                },
+                _ = tokio::time::delay_until(deadline) => {}
+            }
+        }
+
+        let _ = self.flush().await;
+        debug!("Connection closed");
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        for packet in self.channel.poll_transmit(Instant::now()) {
+            let datagram = seal_datagram(&self.cipher, self.token, &packet)?;
+            self.datagrams.send((datagram, self.peer)).await?;
+        }
+
+        Ok(())
+    }
+}
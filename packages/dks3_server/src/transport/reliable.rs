@@ -0,0 +1,723 @@
+//! The reliability layer the game server conversation runs on top of.
+//!
+//! [`ReliableChannel`] holds no sockets or timers of its own. It is fed the packets that
+//! arrive for a connection along with the current time, and is polled for the packets that
+//! need to go out. This keeps the retransmission and ordering logic deterministic and lets it
+//! be tested against a simulated network.
+//!
+//! Every packet carries a cumulative acknowledgement of the highest sequence number received
+//! in order. Data packets are numbered from 1 and are retransmitted until acknowledged, up to
+//! [`ChannelConfig::max_attempts`] times, after which the channel gives up and closes.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::convert::TryFrom;
+use std::time::{Duration, Instant};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::Result;
+
+pub const HEADER_LEN: usize = 9;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketKind {
+    /// Sent by the client to open a connection.
+    Syn = 0,
+    /// Sent by the server to acknowledge a `Syn`.
+    SynAck = 1,
+    Data = 2,
+    Ack = 3,
+    Heartbeat = 4,
+    /// Sent by either side when closing the connection.
+    Fin = 5,
+}
+
+impl TryFrom<u8> for PacketKind {
+    type Error = u8;
+
+    fn try_from(kind: u8) -> std::result::Result<Self, Self::Error> {
+        Ok(match kind {
+            0 => PacketKind::Syn,
+            1 => PacketKind::SynAck,
+            2 => PacketKind::Data,
+            3 => PacketKind::Ack,
+            4 => PacketKind::Heartbeat,
+            5 => PacketKind::Fin,
+            _ => return Err(kind),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Packet {
+    pub kind: PacketKind,
+    pub seq: u32,
+    pub ack: u32,
+    pub payload: Bytes,
+}
+
+impl Packet {
+    fn control(kind: PacketKind, ack: u32) -> Self {
+        Self {
+            kind,
+            seq: 0,
+            ack,
+            payload: Bytes::new(),
+        }
+    }
+
+    pub fn encode(&self) -> Bytes {
+        let mut data = BytesMut::with_capacity(HEADER_LEN + self.payload.len());
+        data.put_u8(self.kind as u8);
+        data.put_u32(self.seq);
+        data.put_u32(self.ack);
+        data.put_slice(&self.payload);
+        data.freeze()
+    }
+
+    pub fn decode(mut data: Bytes) -> Result<Self> {
+        if data.len() < HEADER_LEN {
+            return Err(format!("packet is too short ({} bytes)", data.len()).into());
+        }
+
+        let kind = PacketKind::try_from(data.get_u8())
+            .map_err(|kind| format!("unknown packet kind {}", kind))?;
+        let seq = data.get_u32();
+        let ack = data.get_u32();
+
+        Ok(Self {
+            kind,
+            seq,
+            ack,
+            payload: data,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ChannelConfig {
+    /// How long to wait for an acknowledgement before sending a packet again.
+    pub retransmit_timeout: Duration,
+
+    /// How many times a packet is sent before the peer is considered gone.
+    pub max_attempts: u32,
+
+    /// How long the peer may stay silent before the connection times out.
+    pub idle_timeout: Duration,
+
+    /// How long we may stay silent before sending a heartbeat.
+    pub heartbeat_interval: Duration,
+
+    /// The maximum number of unacknowledged data packets in flight, and how far ahead of the
+    /// next expected packet we buffer out of order arrivals.
+    pub window: u32,
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self {
+            retransmit_timeout: Duration::from_millis(250),
+            max_attempts: 10,
+            idle_timeout: Duration::from_secs(30),
+            heartbeat_interval: Duration::from_secs(5),
+            window: 64,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelState {
+    /// Waiting for the server to acknowledge our `Syn`.
+    Connecting,
+    Established,
+    Closed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    Client,
+    Server,
+}
+
+#[derive(Debug)]
+struct InFlight {
+    payload: Bytes,
+    sent_at: Instant,
+    attempts: u32,
+}
+
+#[derive(Debug)]
+pub struct ReliableChannel {
+    config: ChannelConfig,
+    role: Role,
+    state: ChannelState,
+
+    /// The sequence number the next data packet will be sent with.
+    next_seq: u32,
+    in_flight: BTreeMap<u32, InFlight>,
+    pending: VecDeque<Bytes>,
+
+    /// The highest sequence number received in order, which is what we acknowledge.
+    received: u32,
+    out_of_order: BTreeMap<u32, Bytes>,
+    ack_due: bool,
+
+    syn_sent_at: Option<Instant>,
+    syn_attempts: u32,
+
+    last_received: Instant,
+    last_sent: Instant,
+    outgoing: Vec<Packet>,
+}
+
+impl ReliableChannel {
+    fn new(config: ChannelConfig, role: Role, state: ChannelState, now: Instant) -> Self {
+        Self {
+            config,
+            role,
+            state,
+            next_seq: 1,
+            in_flight: BTreeMap::new(),
+            pending: VecDeque::new(),
+            received: 0,
+            out_of_order: BTreeMap::new(),
+            ack_due: false,
+            syn_sent_at: None,
+            syn_attempts: 0,
+            last_received: now,
+            last_sent: now,
+            outgoing: vec![],
+        }
+    }
+
+    /// Open a connection to a server.
+    pub fn connect(config: ChannelConfig, now: Instant) -> Self {
+        Self::new(config, Role::Client, ChannelState::Connecting, now)
+    }
+
+    /// Accept a connection from a client that has sent us a `Syn`.
+    pub fn accept(config: ChannelConfig, now: Instant) -> Self {
+        let mut channel = Self::new(config, Role::Server, ChannelState::Established, now);
+        channel
+            .outgoing
+            .push(Packet::control(PacketKind::SynAck, 0));
+        channel
+    }
+
+    pub fn state(&self) -> ChannelState {
+        self.state
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state == ChannelState::Closed
+    }
+
+    /// Whether everything sent has been acknowledged by the peer.
+    pub fn is_drained(&self) -> bool {
+        self.pending.is_empty() && self.in_flight.is_empty()
+    }
+
+    /// Queue `payload` for reliable, in order delivery to the peer.
+    pub fn send(&mut self, payload: Bytes) {
+        if !self.is_closed() {
+            self.pending.push_back(payload);
+        }
+    }
+
+    /// Close the connection, letting the peer know we're going away.
+    pub fn close(&mut self) {
+        if !self.is_closed() {
+            self.outgoing
+                .push(Packet::control(PacketKind::Fin, self.received));
+            self.state = ChannelState::Closed;
+        }
+    }
+
+    /// Process a packet from the peer, returning any payloads that are now ready to be
+    /// delivered in order.
+    pub fn receive(&mut self, packet: Packet, now: Instant) -> Vec<Bytes> {
+        if self.is_closed() {
+            return vec![];
+        }
+
+        self.last_received = now;
+
+        match packet.kind {
+            PacketKind::Syn if self.role == Role::Server => {
+                self.outgoing
+                    .push(Packet::control(PacketKind::SynAck, self.received));
+            }
+            PacketKind::SynAck if self.state == ChannelState::Connecting => {
+                self.state = ChannelState::Established;
+                self.syn_sent_at = None;
+            }
+            PacketKind::Fin => {
+                self.state = ChannelState::Closed;
+                return vec![];
+            }
+            _ => {}
+        }
+
+        self.in_flight = self.in_flight.split_off(&packet.ack.wrapping_add(1));
+
+        if packet.kind != PacketKind::Data || packet.seq == 0 {
+            return vec![];
+        }
+
+        self.ack_due = true;
+
+        if packet.seq <= self.received || packet.seq > self.received + self.config.window {
+            // Either a duplicate of something we've already delivered, or too far ahead of what
+            // we're expecting to be worth buffering. The ack we send will sort the peer out.
+            return vec![];
+        }
+
+        self.out_of_order.insert(packet.seq, packet.payload);
+
+        let mut delivered = vec![];
+        while let Some(payload) = self.out_of_order.remove(&(self.received + 1)) {
+            self.received += 1;
+            delivered.push(payload);
+        }
+
+        delivered
+    }
+
+    /// Collect the packets that need to be sent to the peer at `now`.
+    pub fn poll_transmit(&mut self, now: Instant) -> Vec<Packet> {
+        if !self.is_closed() {
+            self.poll_timers(now);
+        }
+
+        if self.state == ChannelState::Established {
+            while self.in_flight.len() < self.config.window as usize {
+                let payload = match self.pending.pop_front() {
+                    Some(payload) => payload,
+                    None => break,
+                };
+
+                let seq = self.next_seq;
+                self.next_seq += 1;
+                self.in_flight.insert(
+                    seq,
+                    InFlight {
+                        payload: payload.clone(),
+                        sent_at: now,
+                        attempts: 1,
+                    },
+                );
+
+                self.transmit(Packet {
+                    kind: PacketKind::Data,
+                    seq,
+                    ack: self.received,
+                    payload,
+                });
+            }
+        }
+
+        if self.ack_due && !self.is_closed() {
+            self.transmit(Packet::control(PacketKind::Ack, self.received));
+        }
+
+        if !self.is_closed()
+            && self.outgoing.is_empty()
+            && now.duration_since(self.last_sent) >= self.config.heartbeat_interval
+        {
+            self.transmit(Packet::control(PacketKind::Heartbeat, self.received));
+        }
+
+        if !self.outgoing.is_empty() {
+            self.last_sent = now;
+        }
+
+        std::mem::take(&mut self.outgoing)
+    }
+
+    fn poll_timers(&mut self, now: Instant) {
+        if now.duration_since(self.last_received) >= self.config.idle_timeout {
+            self.state = ChannelState::Closed;
+            return;
+        }
+
+        if self.state == ChannelState::Connecting {
+            let due = match self.syn_sent_at {
+                Some(sent_at) => now.duration_since(sent_at) >= self.config.retransmit_timeout,
+                None => true,
+            };
+
+            if due {
+                if self.syn_attempts >= self.config.max_attempts {
+                    self.state = ChannelState::Closed;
+                    return;
+                }
+
+                self.syn_attempts += 1;
+                self.syn_sent_at = Some(now);
+                self.transmit(Packet::control(PacketKind::Syn, 0));
+            }
+        }
+
+        let mut retransmits = vec![];
+        for (seq, in_flight) in self.in_flight.iter_mut() {
+            if now.duration_since(in_flight.sent_at) < self.config.retransmit_timeout {
+                continue;
+            }
+
+            if in_flight.attempts >= self.config.max_attempts {
+                self.state = ChannelState::Closed;
+                return;
+            }
+
+            in_flight.attempts += 1;
+            in_flight.sent_at = now;
+            retransmits.push(Packet {
+                kind: PacketKind::Data,
+                seq: *seq,
+                ack: self.received,
+                payload: in_flight.payload.clone(),
+            });
+        }
+
+        for packet in retransmits {
+            self.transmit(packet);
+        }
+    }
+
+    fn transmit(&mut self, packet: Packet) {
+        // Every packet carries our latest cumulative ack.
+        self.ack_due = false;
+        self.outgoing.push(packet);
+    }
+
+    /// The next time [`poll_transmit`](Self::poll_transmit) has work to do if no packets
+    /// arrive in the meantime.
+    pub fn next_deadline(&self) -> Instant {
+        let mut deadline = (self.last_received + self.config.idle_timeout)
+            .min(self.last_sent + self.config.heartbeat_interval);
+
+        if let Some(sent_at) = self.syn_sent_at {
+            deadline = deadline.min(sent_at + self.config.retransmit_timeout);
+        }
+
+        for in_flight in self.in_flight.values() {
+            deadline = deadline.min(in_flight.sent_at + self.config.retransmit_timeout);
+        }
+
+        deadline
+    }
+
+    /// Whether there is work for [`poll_transmit`](Self::poll_transmit) to do right away.
+    pub fn wants_transmit(&self) -> bool {
+        !self.outgoing.is_empty()
+            || self.ack_due
+            || (self.state == ChannelState::Established
+                && !self.pending.is_empty()
+                && self.in_flight.len() < self.config.window as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    const TICK: Duration = Duration::from_millis(10);
+
+    /// A pair of channels connected by a lossy, reordering network.
+    struct Network {
+        client: ReliableChannel,
+        server: Option<ReliableChannel>,
+        now: Instant,
+        rng: StdRng,
+        loss: f64,
+        reorder: bool,
+        to_server: Vec<Packet>,
+        to_client: Vec<Packet>,
+        client_received: Vec<Bytes>,
+        server_received: Vec<Bytes>,
+    }
+
+    impl Network {
+        fn new(loss: f64, reorder: bool) -> Self {
+            let now = Instant::now();
+
+            Self {
+                client: ReliableChannel::connect(ChannelConfig::default(), now),
+                server: None,
+                now,
+                rng: StdRng::seed_from_u64(0x5EED),
+                loss,
+                reorder,
+                to_server: vec![],
+                to_client: vec![],
+                client_received: vec![],
+                server_received: vec![],
+            }
+        }
+
+        fn lossy(&mut self, packets: Vec<Packet>) -> Vec<Packet> {
+            let loss = self.loss;
+            let rng = &mut self.rng;
+            let mut packets: Vec<_> = packets
+                .into_iter()
+                .filter(|_| !rng.gen_bool(loss))
+                .collect();
+
+            if self.reorder {
+                packets.shuffle(rng);
+            }
+
+            packets
+        }
+
+        fn step(&mut self) {
+            self.now += TICK;
+            let now = self.now;
+
+            let to_server = std::mem::take(&mut self.to_server);
+            for packet in self.lossy(to_server) {
+                // As with the listener, accepting the first Syn is what answers it.
+                let server = match self.server.as_mut() {
+                    Some(server) => server,
+                    None => {
+                        assert_eq!(PacketKind::Syn, packet.kind);
+                        self.server = Some(ReliableChannel::accept(ChannelConfig::default(), now));
+                        continue;
+                    }
+                };
+
+                let delivered = server.receive(packet, now);
+                self.server_received.extend(delivered);
+            }
+
+            let to_client = std::mem::take(&mut self.to_client);
+            for packet in self.lossy(to_client) {
+                let delivered = self.client.receive(packet, now);
+                self.client_received.extend(delivered);
+            }
+
+            self.to_server.extend(self.client.poll_transmit(now));
+            if let Some(server) = self.server.as_mut() {
+                self.to_client.extend(server.poll_transmit(now));
+            }
+        }
+
+        fn run_until<F: Fn(&Network) -> bool>(&mut self, limit: Duration, done: F) {
+            let deadline = self.now + limit;
+            while !done(self) {
+                assert!(self.now < deadline, "network did not settle in {:?}", limit);
+                self.step();
+            }
+        }
+    }
+
+    fn payloads(count: u32) -> Vec<Bytes> {
+        (0..count)
+            .map(|i| Bytes::from(format!("message {}", i)))
+            .collect()
+    }
+
+    fn exchange(loss: f64, reorder: bool) {
+        let mut network = Network::new(loss, reorder);
+        let sent = payloads(200);
+
+        for payload in &sent {
+            network.client.send(payload.clone());
+        }
+
+        network.run_until(Duration::from_secs(10), |n| {
+            n.server.as_ref().map(|s| s.state()) == Some(ChannelState::Established)
+        });
+
+        for payload in &sent {
+            network.server.as_mut().unwrap().send(payload.clone());
+        }
+
+        network.run_until(Duration::from_secs(60), |n| {
+            n.server_received.len() == sent.len() && n.client_received.len() == sent.len()
+        });
+
+        assert_eq!(sent, network.server_received);
+        assert_eq!(sent, network.client_received);
+        assert!(!network.client.is_closed());
+    }
+
+    #[test]
+    fn test_packet_roundtrip() {
+        let packet = Packet {
+            kind: PacketKind::Data,
+            seq: 42,
+            ack: 7,
+            payload: Bytes::from_static(b"payload"),
+        };
+
+        assert_eq!(packet, Packet::decode(packet.encode()).unwrap());
+        assert!(Packet::decode(Bytes::from_static(b"short")).is_err());
+        assert!(Packet::decode(Bytes::from_static(&[9, 0, 0, 0, 0, 0, 0, 0, 0])).is_err());
+    }
+
+    #[test]
+    fn test_handshake() {
+        let mut network = Network::new(0.0, false);
+        assert_eq!(ChannelState::Connecting, network.client.state());
+
+        network.run_until(Duration::from_secs(1), |n| {
+            n.client.state() == ChannelState::Established
+        });
+    }
+
+    #[test]
+    fn test_accept_answers_syn_once() {
+        let now = Instant::now();
+        let mut server = ReliableChannel::accept(ChannelConfig::default(), now);
+
+        let kinds: Vec<PacketKind> = server
+            .poll_transmit(now)
+            .into_iter()
+            .map(|packet| packet.kind)
+            .collect();
+        assert_eq!(vec![PacketKind::SynAck], kinds);
+    }
+
+    #[test]
+    fn test_delivery_without_loss() {
+        exchange(0.0, false);
+    }
+
+    #[test]
+    fn test_delivery_with_loss() {
+        exchange(0.3, false);
+    }
+
+    #[test]
+    fn test_delivery_with_reordering() {
+        exchange(0.0, true);
+    }
+
+    #[test]
+    fn test_delivery_with_loss_and_reordering() {
+        exchange(0.2, true);
+    }
+
+    #[test]
+    fn test_duplicates_are_suppressed() {
+        let now = Instant::now();
+        let mut server = ReliableChannel::accept(ChannelConfig::default(), now);
+        let packet = Packet {
+            kind: PacketKind::Data,
+            seq: 1,
+            ack: 0,
+            payload: Bytes::from_static(b"once"),
+        };
+
+        assert_eq!(1, server.receive(packet.clone(), now).len());
+        assert!(server.receive(packet.clone(), now).is_empty());
+
+        // The duplicate is still acknowledged, in case our earlier ack was lost.
+        let acks = server.poll_transmit(now);
+        assert!(acks.iter().any(|p| p.ack == 1));
+    }
+
+    #[test]
+    fn test_out_of_order_is_buffered() {
+        let now = Instant::now();
+        let mut server = ReliableChannel::accept(ChannelConfig::default(), now);
+        let data = |seq: u32| Packet {
+            kind: PacketKind::Data,
+            seq,
+            ack: 0,
+            payload: Bytes::from(vec![seq as u8]),
+        };
+
+        assert!(server.receive(data(3), now).is_empty());
+        assert!(server.receive(data(2), now).is_empty());
+        assert_eq!(
+            vec![vec![1], vec![2], vec![3]],
+            server
+                .receive(data(1), now)
+                .iter()
+                .map(|p| p.to_vec())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_acked_packets_are_not_retransmitted() {
+        let config = ChannelConfig::default();
+        let mut now = Instant::now();
+        let mut server = ReliableChannel::accept(config.clone(), now);
+
+        server.send(Bytes::from_static(b"data"));
+        let sent = server.poll_transmit(now);
+        assert!(sent.iter().any(|p| p.kind == PacketKind::Data));
+
+        server.receive(Packet::control(PacketKind::Ack, 1), now);
+        now += config.retransmit_timeout * 2;
+
+        assert!(server
+            .poll_transmit(now)
+            .iter()
+            .all(|p| p.kind != PacketKind::Data));
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let config = ChannelConfig::default();
+        let mut now = Instant::now();
+        let mut server = ReliableChannel::accept(config.clone(), now);
+
+        server.send(Bytes::from_static(b"into the void"));
+        for _ in 0..config.max_attempts {
+            let sent = server.poll_transmit(now);
+            assert!(sent.iter().any(|p| p.kind == PacketKind::Data));
+            now += config.retransmit_timeout;
+        }
+
+        server.poll_transmit(now);
+        assert!(server.is_closed());
+    }
+
+    #[test]
+    fn test_idle_timeout() {
+        let config = ChannelConfig::default();
+        let mut now = Instant::now();
+        let mut server = ReliableChannel::accept(config.clone(), now);
+        server.poll_transmit(now);
+
+        now += config.idle_timeout / 2;
+        let sent = server.poll_transmit(now);
+        assert!(sent.iter().any(|p| p.kind == PacketKind::Heartbeat));
+        assert!(!server.is_closed());
+
+        server.receive(Packet::control(PacketKind::Heartbeat, 0), now);
+        now += config.idle_timeout / 2;
+        server.poll_transmit(now);
+        assert!(!server.is_closed());
+
+        now += config.idle_timeout;
+        server.poll_transmit(now);
+        assert!(server.is_closed());
+    }
+
+    #[test]
+    fn test_fin_closes() {
+        let now = Instant::now();
+        let mut client = ReliableChannel::connect(ChannelConfig::default(), now);
+        let mut server = ReliableChannel::accept(ChannelConfig::default(), now);
+
+        for packet in client.poll_transmit(now) {
+            server.receive(packet, now);
+        }
+
+        server.close();
+        for packet in server.poll_transmit(now) {
+            client.receive(packet, now);
+        }
+
+        assert!(client.is_closed());
+    }
+}
@@ -0,0 +1,180 @@
+use std::net::SocketAddr;
+
+use chrono::{DateTime, Utc};
+use hyper::{header, Body, Client, Request, StatusCode};
+use serde_json::{json, Value};
+
+use dks3_proto::Frpg2RequestMessage::PushBroadcastMessage;
+use dks3_server::admin::AdminServer;
+use dks3_server::session::CharacterSummary;
+use dks3_server::telemetry::Event;
+use dks3_server::ServerContext;
+
+use common::GameClient;
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+const TOKEN: &str = "secret";
+
+fn spawn_admin_server(context: &ServerContext) -> SocketAddr {
+    let server = AdminServer::bind(context.clone(), "127.0.0.1:0".parse().unwrap()).unwrap();
+    let addr = server.local_addr();
+    tokio::spawn(server.serve());
+
+    addr
+}
+
+async fn get(addr: SocketAddr, path: &str, token: Option<&str>) -> (StatusCode, Option<Value>) {
+    let mut request = Request::get(format!("http://{}{}", addr, path));
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+
+    let response = Client::new()
+        .request(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+    (status, serde_json::from_slice(&body).ok())
+}
+
+async fn post(addr: SocketAddr, path: &str, body: Value) -> (StatusCode, Option<Value>) {
+    let request = Request::post(format!("http://{}{}", addr, path))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+
+    let response = Client::new().request(request).await.unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+    (status, serde_json::from_slice(&body).ok())
+}
+
+#[tokio::test]
+async fn test_areas_are_listed() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let admin_addr = spawn_admin_server(&context);
+    let _client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let session = context.sessions().by_steam_id(STEAM_ID).unwrap();
+    context.areas().update(
+        session.id,
+        &CharacterSummary {
+            online_area_id: 300_000,
+            ..CharacterSummary::default()
+        },
+    );
+
+    let (status, body) = get(admin_addr, "/areas", None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(
+        Some(json!({
+            "players_online": 1,
+            "areas": [{ "online_area_id": 300_000, "players": 1 }],
+        })),
+        body
+    );
+
+    let (status, _) = get(admin_addr, "/nothing", None).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+}
+
+#[tokio::test]
+async fn test_token_is_required_when_configured() {
+    let context = common::context_with(&format!(
+        r#"
+[admin]
+token = "{}"
+"#,
+        TOKEN
+    ));
+    let admin_addr = spawn_admin_server(&context);
+
+    let (status, _) = get(admin_addr, "/areas", None).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, status);
+
+    let (status, _) = get(admin_addr, "/areas", Some("wrong")).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, status);
+
+    let (status, body) = get(admin_addr, "/areas", Some(TOKEN)).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(Some(json!({ "players_online": 0, "areas": [] })), body);
+}
+
+#[tokio::test]
+async fn test_token_is_required_off_loopback() {
+    let addr: SocketAddr = "0.0.0.0:0".parse().unwrap();
+    assert!(AdminServer::bind(common::context(), addr).is_err());
+
+    let context = common::context_with(&format!("[admin]\ntoken = \"{}\"", TOKEN));
+    assert!(AdminServer::bind(context, addr).is_ok());
+}
+
+#[tokio::test]
+async fn test_statistics_are_listed() {
+    let context = common::context();
+    let admin_addr = spawn_admin_server(&context);
+
+    let purchase = Event::Purchase {
+        online_area_id: 300_000,
+        item_id: 1_000,
+        quantity: 3,
+    };
+    let time = "2020-11-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+    context.telemetry().record(&purchase, time, 10);
+
+    let (status, body) = get(admin_addr, "/stats", None).await;
+    assert_eq!(StatusCode::OK, status);
+    let body = body.unwrap();
+    assert_eq!(json!(1), body["2020-11-01"]["purchases"]);
+
+    let (status, body) = get(admin_addr, "/stats/2020-11-01", None).await;
+    assert_eq!(StatusCode::OK, status);
+    let body = body.unwrap();
+    assert_eq!(json!(1), body["areas"]["300000"]["purchases"]);
+    assert_eq!(
+        json!({ "purchases": 1, "quantity": 3 }),
+        body["items"]["1000"]
+    );
+
+    let (status, _) = get(admin_addr, "/stats/2020-11-02", None).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+}
+
+#[tokio::test]
+async fn test_message_is_broadcast() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let admin_addr = spawn_admin_server(&context);
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let (status, body) = post(
+        admin_addr,
+        "/broadcast",
+        json!({ "header": "Maintenance", "message": "Servers restart soon" }),
+    )
+    .await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(Some(json!({ "players": 1 })), body);
+
+    let push = client.receive_push().await.unwrap();
+    let push: PushBroadcastMessage = push.decode().unwrap();
+    assert_eq!("Servers restart soon", push.get_message());
+
+    let (status, body) = post(
+        admin_addr,
+        "/broadcast",
+        json!({ "message": "Nobody here", "online_area_ids": [300_000] }),
+    )
+    .await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(Some(json!({ "players": 0 })), body);
+
+    let (status, _) = post(admin_addr, "/broadcast", json!({ "header": "No message" })).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+}
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use dks3_proto::Frpg2RequestMessage::{
+    RequestGetAnnounceMessageList, RequestGetAnnounceMessageListResponse,
+};
+use dks3_server::frame::MessageType;
+use dks3_server::ServerContext;
+
+use common::{GameClient, TempDir};
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+
+const ANNOUNCEMENTS: &str = r#"
+[[announcements]]
+header = "Welcome"
+message = "Praise the sun!"
+date = "2020-10-01T00:00:00Z"
+
+[[announcements]]
+header = "Maintenance"
+message = "The server will be down on Sunday."
+date = "2020-11-01T12:00:00Z"
+unk01 = 1
+
+[[announcements]]
+header = "Expired"
+message = "This should never be shown."
+date = "2020-09-01T00:00:00Z"
+visible_until = "2020-09-02T00:00:00Z"
+
+[[announcements]]
+header = "Upcoming"
+message = "This should not be shown yet."
+date = "2020-09-01T00:00:00Z"
+visible_from = "2999-01-01T00:00:00Z"
+"#;
+
+fn announcements_file(dir: &TempDir, contents: &str) -> PathBuf {
+    let path = dir.path().join("announcements.toml");
+    fs::write(&path, contents).unwrap();
+
+    path
+}
+
+/// Overwrite the file at `path` and set its modification time explicitly, rather than relying on
+/// the filesystem's clock resolution.
+fn write_with_modified(path: &Path, contents: &str, modified: SystemTime) {
+    fs::write(path, contents).unwrap();
+    fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+}
+
+fn context_with_announcements(path: &Path) -> ServerContext {
+    common::context_with(&format!(
+        "[announcements]\npath = {:?}",
+        path.display().to_string()
+    ))
+}
+
+async fn headers(client: &mut GameClient, max_entries: Option<u32>) -> Vec<String> {
+    let mut request = RequestGetAnnounceMessageList::new();
+    if let Some(max_entries) = max_entries {
+        request.set_max_entries(max_entries);
+    }
+
+    let reply = client
+        .request(MessageType::RequestGetAnnounceMessageList, &request)
+        .await
+        .unwrap();
+    reply.expect(MessageType::Reply).unwrap();
+
+    let response: RequestGetAnnounceMessageListResponse = reply.decode().unwrap();
+    response
+        .get_data_list()
+        .get_items()
+        .iter()
+        .map(|item| item.get_header().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_visible_announcements_are_served_newest_first() {
+    let dir = TempDir::new("announcements_visible");
+    let path = announcements_file(&dir, ANNOUNCEMENTS);
+    let context = context_with_announcements(&path);
+    assert!(context.announcements().reload_if_changed().unwrap());
+
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    assert_eq!(
+        vec!["Maintenance", "Welcome"],
+        headers(&mut client, None).await
+    );
+    assert_eq!(vec!["Maintenance"], headers(&mut client, Some(1)).await);
+}
+
+#[tokio::test]
+async fn test_announcements_are_reloaded_when_changed() {
+    let dir = TempDir::new("announcements_reload");
+    let path = announcements_file(&dir, ANNOUNCEMENTS);
+    let modified = fs::metadata(&path).unwrap().modified().unwrap();
+    let context = context_with_announcements(&path);
+    assert!(context.announcements().reload_if_changed().unwrap());
+    assert!(!context.announcements().reload_if_changed().unwrap());
+
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    // Keep the old modification time, so only the new length shows the file was edited.
+    write_with_modified(
+        &path,
+        r#"
+[[announcements]]
+header = "Edited"
+message = "Edited without a restart."
+date = "2020-12-01T00:00:00Z"
+"#,
+        modified,
+    );
+
+    assert!(context.announcements().reload_if_changed().unwrap());
+    assert_eq!(vec!["Edited"], headers(&mut client, None).await);
+
+    write_with_modified(
+        &path,
+        "this is not valid toml [",
+        modified + Duration::from_secs(60),
+    );
+    assert!(context.announcements().reload_if_changed().is_err());
+    assert_eq!(vec!["Edited"], headers(&mut client, None).await);
+}
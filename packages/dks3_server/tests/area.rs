@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use tokio::time::delay_for;
+
+use dks3_proto::Frpg2RequestMessage::{
+    RequestCountAreaPlayers, RequestCountAreaPlayersResponse, RequestGetRightMatchingArea,
+    RequestGetRightMatchingAreaResponse,
+};
+use dks3_server::frame::MessageType;
+
+use common::{GameClient, Status};
+
+mod common;
+
+const PLAYERS: [&str; 3] = ["0110000100000001", "0110000100000002", "0110000100000003"];
+const FIRELINK: u32 = 300_000;
+const HIGH_WALL: u32 = 300_001;
+
+async fn count(client: &mut GameClient, online_area_ids: &[u32]) -> Vec<(u32, u32)> {
+    let mut request = RequestCountAreaPlayers::new();
+    request.set_online_area_ids(online_area_ids.to_vec());
+
+    let response: RequestCountAreaPlayersResponse = client
+        .request(MessageType::RequestCountAreaPlayers, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+
+    response
+        .get_areas()
+        .iter()
+        .map(|area| (area.get_online_area_id(), area.get_players()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_areas_are_counted_from_player_status() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut clients = vec![];
+    for steam_id in PLAYERS.iter() {
+        clients.push(GameClient::connect(&context, game_addr, steam_id).await);
+    }
+
+    common::update_status(&mut clients[0], &Status::new(FIRELINK, 50)).await;
+    common::update_status(&mut clients[1], &Status::new(FIRELINK, 50)).await;
+    common::update_status(&mut clients[2], &Status::new(FIRELINK, 50)).await;
+    common::update_status(&mut clients[2], &Status::new(HIGH_WALL, 50)).await;
+
+    assert_eq!(
+        vec![(FIRELINK, 2), (HIGH_WALL, 1), (0, 0)],
+        count(&mut clients[0], &[FIRELINK, HIGH_WALL, 0]).await
+    );
+    assert_eq!(
+        vec![(FIRELINK, 2), (HIGH_WALL, 1)],
+        context.areas().counts()
+    );
+
+    let session = context.sessions().by_steam_id(PLAYERS[2]).unwrap();
+    context.sessions().evict(session.id);
+
+    let mut remaining = context.areas().counts();
+    for _ in 0..50 {
+        if remaining.len() == 1 {
+            break;
+        }
+
+        delay_for(Duration::from_millis(10)).await;
+        remaining = context.areas().counts();
+    }
+    assert_eq!(vec![(FIRELINK, 2)], remaining);
+}
+
+#[tokio::test]
+async fn test_right_matching_area_counts_matching_players() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut clients = vec![];
+    for steam_id in PLAYERS.iter() {
+        clients.push(GameClient::connect(&context, game_addr, steam_id).await);
+    }
+
+    common::update_status(&mut clients[0], &Status::new(FIRELINK, 50)).await;
+    common::update_status(&mut clients[1], &Status::new(HIGH_WALL, 52)).await;
+    common::update_status(&mut clients[2], &Status::new(FIRELINK, 300)).await;
+
+    let mut request = RequestGetRightMatchingArea::new();
+    request.set_matching_parameter(common::matching_parameter(50, None));
+
+    let response: RequestGetRightMatchingAreaResponse = clients[0]
+        .request(MessageType::RequestGetRightMatchingArea, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+
+    let areas: Vec<(u32, u32)> = response
+        .get_areas()
+        .iter()
+        .map(|area| (area.get_online_area_id(), area.get_players()))
+        .collect();
+    assert_eq!(vec![(HIGH_WALL, 1)], areas);
+}
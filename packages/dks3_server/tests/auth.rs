@@ -0,0 +1,65 @@
+use futures::SinkExt;
+use tokio::net::TcpStream;
+use tokio_util::codec::Framed;
+
+use dks3_proto::Frpg2RequestMessage::RequestHandshake;
+use dks3_server::crypto::{rsa_encrypt, CwcCipher};
+use dks3_server::frame::{packet_codec, CwcFramed, Message, MessageType};
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+
+#[tokio::test]
+async fn test_auth_handshake_issues_token() {
+    let context = common::context();
+    let addr = common::spawn_auth_server(&context).await;
+
+    let auth = common::authenticate(&context, addr, STEAM_ID, b"ticket")
+        .await
+        .unwrap();
+
+    assert_eq!("127.0.0.1", auth.server_info.get_server_ip());
+    assert_eq!(50010, auth.server_info.get_port());
+    assert_eq!(16, auth.game_key.len());
+
+    let ticket = context
+        .auth_tokens()
+        .redeem(auth.server_info.get_auth_token())
+        .expect("auth token was not issued");
+
+    assert_eq!(STEAM_ID, ticket.steam_id);
+    assert_eq!(&auth.game_key[..], &ticket.game_key[..]);
+}
+
+#[tokio::test]
+async fn test_auth_token_is_single_use() {
+    let context = common::context();
+    let addr = common::spawn_auth_server(&context).await;
+
+    let auth = common::authenticate(&context, addr, STEAM_ID, b"ticket")
+        .await
+        .unwrap();
+    let token = auth.server_info.get_auth_token();
+
+    assert!(context.auth_tokens().redeem(token).is_some());
+    assert!(context.auth_tokens().redeem(token).is_none());
+}
+
+#[tokio::test]
+async fn test_auth_rejects_wrong_session_key() {
+    let context = common::context();
+    let addr = common::spawn_auth_server(&context).await;
+    let public_key = context.config().security().public_key();
+
+    let mut handshake = RequestHandshake::new();
+    handshake.set_aes_cwc_key(vec![1; 16]);
+    let message = Message::new(MessageType::RequestHandshake, 1, &handshake).unwrap();
+    let packet = rsa_encrypt(&mut rand::thread_rng(), &public_key, &message.encode()).unwrap();
+
+    let mut framed = Framed::new(TcpStream::connect(addr).await.unwrap(), packet_codec());
+    framed.send(packet.into()).await.unwrap();
+
+    let mut conn = CwcFramed::new(framed, CwcCipher::new(&[2; 16]).unwrap());
+    assert!(conn.receive().await.is_err());
+}
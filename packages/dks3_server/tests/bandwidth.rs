@@ -0,0 +1,171 @@
+use std::time::{Duration, Instant};
+
+use dks3_proto::Frpg2RequestMessage::{
+    RequestGetBreakInTargetList, RequestGetBreakInTargetListResponse,
+    RequestMeasureDownloadBandwidth, RequestMeasureDownloadBandwidthResponse,
+    RequestMeasureUploadBandwidth, RequestUpdatePlayerStatus,
+};
+use dks3_server::bandwidth::Bandwidth;
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+use dks3_server::matchmaking::{self, Level};
+
+use common::GameClient;
+
+mod common;
+
+const PLAYER: &str = "0110000100000001";
+const SLOW_HOST: &str = "0110000100000002";
+const FAST_HOST: &str = "0110000100000003";
+const AREA: u32 = 300_000;
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+async fn upload(client: &mut GameClient, bytes: usize) -> Message {
+    let mut request = RequestMeasureUploadBandwidth::new();
+    request.set_data(vec![0; bytes]);
+
+    client
+        .request(MessageType::RequestMeasureUploadBandwidth, &request)
+        .await
+        .unwrap()
+}
+
+async fn download(client: &mut GameClient, size: u32) -> Vec<u8> {
+    let mut request = RequestMeasureDownloadBandwidth::new();
+    request.set_size(size);
+
+    let response: RequestMeasureDownloadBandwidthResponse = client
+        .request(MessageType::RequestMeasureDownloadBandwidth, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    response.get_data().to_vec()
+}
+
+async fn make_invadable(client: &mut GameClient) {
+    let mut request = RequestUpdatePlayerStatus::new();
+    request.set_character_name("Character".to_string());
+    request.set_online_area_id(AREA);
+    request.set_matching_parameter(matchmaking::to_matching_parameter(&Level {
+        soul_level: 50,
+        max_weapon_level: 5,
+        ..Level::default()
+    }));
+    request.set_embered(true);
+    request.set_in_coop(false);
+
+    client
+        .request(MessageType::RequestUpdatePlayerStatus, &request)
+        .await
+        .unwrap()
+        .expect(MessageType::Reply)
+        .unwrap();
+}
+
+#[test]
+fn test_runs_of_measurements_are_timed_and_averaged() {
+    let start = Instant::now();
+    let mut bandwidth = Bandwidth::default();
+
+    // The first upload only starts the clock.
+    bandwidth.upload_received(10_000, start, TIMEOUT);
+    assert_eq!(None, bandwidth.upload_kbps);
+
+    bandwidth.upload_received(10_000, start + Duration::from_millis(100), TIMEOUT);
+    assert_eq!(Some(800), bandwidth.upload_kbps);
+
+    bandwidth.upload_received(10_000, start + Duration::from_millis(300), TIMEOUT);
+    assert_eq!(Some(600), bandwidth.upload_kbps);
+
+    // A measurement arriving long after the last one starts a new run.
+    bandwidth.upload_received(10_000, start + Duration::from_secs(60), TIMEOUT);
+    assert_eq!(Some(600), bandwidth.upload_kbps);
+
+    // Downloads are timed from sending one payload to being asked for the next.
+    bandwidth.download_requested(5_000, start, TIMEOUT);
+    bandwidth.download_requested(5_000, start + Duration::from_millis(400), TIMEOUT);
+    assert_eq!(Some(100), bandwidth.download_kbps);
+
+    assert!(!bandwidth.is_poor(100));
+    assert!(bandwidth.is_poor(101));
+    assert!(!Bandwidth::default().is_poor(u32::MAX));
+}
+
+#[tokio::test]
+async fn test_measurements_are_recorded_on_session() {
+    let context = common::context_with(
+        r#"
+[bandwidth]
+max_payload_bytes = 1000
+"#,
+    );
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, PLAYER).await;
+
+    for _ in 0..2 {
+        upload(&mut client, 1000)
+            .await
+            .expect(MessageType::Reply)
+            .unwrap();
+    }
+    let reply = upload(&mut client, 1001).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    assert_eq!(500, download(&mut client, 500).await.len());
+    assert_eq!(1000, download(&mut client, 5000).await.len());
+
+    let bandwidth = context.sessions().by_steam_id(PLAYER).unwrap().bandwidth;
+    assert!(bandwidth.upload_kbps.is_some());
+    assert!(bandwidth.download_kbps.is_some());
+}
+
+#[tokio::test]
+async fn test_poor_connections_are_offered_last() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut invader = GameClient::connect(&context, game_addr, PLAYER).await;
+    let mut slow_host = GameClient::connect(&context, game_addr, SLOW_HOST).await;
+    let mut fast_host = GameClient::connect(&context, game_addr, FAST_HOST).await;
+
+    make_invadable(&mut slow_host).await;
+    make_invadable(&mut fast_host).await;
+
+    let session = context.sessions().by_steam_id(SLOW_HOST).unwrap();
+    context.sessions().update(session.id, |session| {
+        session.bandwidth.upload_kbps = Some(10);
+    });
+
+    let mut request = RequestGetBreakInTargetList::new();
+    request.set_online_area_id(AREA);
+    request.set_matching_parameter(matchmaking::to_matching_parameter(&Level {
+        soul_level: 50,
+        max_weapon_level: 5,
+        ..Level::default()
+    }));
+
+    for _ in 0..5 {
+        let response: RequestGetBreakInTargetListResponse = invader
+            .request(MessageType::RequestGetBreakInTargetList, &request)
+            .await
+            .unwrap()
+            .decode()
+            .unwrap();
+
+        let targets: Vec<&str> = response
+            .get_targets()
+            .iter()
+            .map(|target| target.get_steam_id())
+            .collect();
+        assert_eq!(vec![FAST_HOST, SLOW_HOST], targets);
+    }
+
+    request.set_max_entries(1);
+    let response: RequestGetBreakInTargetListResponse = invader
+        .request(MessageType::RequestGetBreakInTargetList, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    assert_eq!(FAST_HOST, response.get_targets()[0].get_steam_id());
+}
@@ -0,0 +1,357 @@
+use std::fs;
+use std::time::SystemTime;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use dks3_proto::common::Vector3;
+use dks3_proto::Frpg2RequestMessage::{
+    BloodMessageText, PushEvaluateBloodMessage, RequestCreateBloodMessage,
+    RequestCreateBloodMessageResponse, RequestEvaluateBloodMessage, RequestGetBloodMessageList,
+    RequestGetBloodMessageListResponse, RequestReentryBloodMessage,
+    RequestReentryBloodMessageResponse, RequestRemoveBloodMessage,
+};
+use dks3_server::blood_message::{BloodMessage, BloodMessageStore, Evaluation, MessageText};
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+use dks3_server::location::Location;
+use dks3_server::ServerContext;
+
+use common::{GameClient, TempDir};
+
+mod common;
+
+const AUTHOR: &str = "0110000100000001";
+const READER: &str = "0110000100000002";
+const AREA: u32 = 300_000;
+
+fn create_request(online_area_id: u32, template_id: u32) -> RequestCreateBloodMessage {
+    let mut position = Vector3::new();
+    position.set_x(1.0);
+    position.set_y(2.0);
+    position.set_z(3.0);
+
+    let mut text = BloodMessageText::new();
+    text.set_template_id(template_id);
+    text.set_word_id(7);
+    text.set_conjunction_id(2);
+
+    let mut request = RequestCreateBloodMessage::new();
+    request.set_online_area_id(online_area_id);
+    request.set_map_id(30_000_000);
+    request.set_position(position);
+    request.set_angle(0.5);
+    request.set_text(text);
+    request.set_gesture_id(4);
+    request
+}
+
+async fn create(client: &mut GameClient, online_area_id: u32, template_id: u32) -> u64 {
+    let reply = client
+        .request(
+            MessageType::RequestCreateBloodMessage,
+            &create_request(online_area_id, template_id),
+        )
+        .await
+        .unwrap();
+
+    let response: RequestCreateBloodMessageResponse = reply.decode().unwrap();
+    response.get_message_id()
+}
+
+async fn list(client: &mut GameClient, online_area_id: u32) -> RequestGetBloodMessageListResponse {
+    let mut request = RequestGetBloodMessageList::new();
+    request.set_online_area_id(online_area_id);
+
+    client
+        .request(MessageType::RequestGetBloodMessageList, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap()
+}
+
+async fn evaluate(client: &mut GameClient, message_id: u64, good: bool) -> Message {
+    let mut request = RequestEvaluateBloodMessage::new();
+    request.set_message_id(message_id);
+    request.set_good(good);
+
+    client
+        .request(MessageType::RequestEvaluateBloodMessage, &request)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_messages_are_listed_for_other_players_in_area() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut author = GameClient::connect(&context, game_addr, AUTHOR).await;
+    let mut reader = GameClient::connect(&context, game_addr, READER).await;
+
+    let id = create(&mut author, AREA, 11).await;
+    create(&mut author, AREA + 1, 12).await;
+
+    let response = list(&mut reader, AREA).await;
+    assert_eq!(1, response.get_messages().len());
+
+    let message = &response.get_messages()[0];
+    assert_eq!(id, message.get_message_id());
+    assert_eq!(AUTHOR, message.get_steam_id());
+    assert_eq!(11, message.get_text().get_template_id());
+    assert_eq!(2, message.get_text().get_conjunction_id());
+    assert!(!message.get_text().has_second_word_id());
+    assert_eq!(3.0, message.get_position().get_z());
+    assert_eq!(4, message.get_gesture_id());
+
+    assert!(list(&mut author, AREA).await.get_messages().is_empty());
+}
+
+#[tokio::test]
+async fn test_message_at_non_finite_location_is_rejected() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut author = GameClient::connect(&context, game_addr, AUTHOR).await;
+
+    let mut request = create_request(AREA, 11);
+    request.mut_position().set_y(f32::NAN);
+    let reply = author
+        .request(MessageType::RequestCreateBloodMessage, &request)
+        .await
+        .unwrap();
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    let mut request = create_request(AREA, 11);
+    request.set_angle(f32::INFINITY);
+    let reply = author
+        .request(MessageType::RequestCreateBloodMessage, &request)
+        .await
+        .unwrap();
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    assert!(context.blood_messages().is_empty());
+}
+
+#[tokio::test]
+async fn test_list_is_capped() {
+    let context = common::context_with("[blood_messages]\nmax_list_entries = 3");
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut author = GameClient::connect(&context, game_addr, AUTHOR).await;
+    let mut reader = GameClient::connect(&context, game_addr, READER).await;
+
+    for template_id in 0..5 {
+        create(&mut author, AREA, template_id).await;
+    }
+
+    assert_eq!(3, list(&mut reader, AREA).await.get_messages().len());
+}
+
+#[tokio::test]
+async fn test_oldest_message_is_replaced_at_limit() {
+    let context = common::context_with("[blood_messages]\nmax_per_player = 2");
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut author = GameClient::connect(&context, game_addr, AUTHOR).await;
+
+    let first = create(&mut author, AREA, 1).await;
+    let second = create(&mut author, AREA, 2).await;
+    let third = create(&mut author, AREA, 3).await;
+
+    assert_eq!(None, context.blood_messages().get(first));
+    assert!(context.blood_messages().get(second).is_some());
+    assert!(context.blood_messages().get(third).is_some());
+}
+
+#[tokio::test]
+async fn test_reentry_returns_surviving_messages() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut author = GameClient::connect(&context, game_addr, AUTHOR).await;
+    let mut reader = GameClient::connect(&context, game_addr, READER).await;
+
+    let kept = create(&mut author, AREA, 1).await;
+    let removed = create(&mut author, AREA, 2).await;
+    let someone_elses = create(&mut reader, AREA, 3).await;
+    context.blood_messages().remove_owned(removed, AUTHOR);
+
+    let mut request = RequestReentryBloodMessage::new();
+    request.set_message_ids(vec![kept, removed, someone_elses]);
+
+    let response: RequestReentryBloodMessageResponse = author
+        .request(MessageType::RequestReentryBloodMessage, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+
+    assert_eq!(&[kept], response.get_message_ids());
+}
+
+#[tokio::test]
+async fn test_only_author_can_remove_message() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut author = GameClient::connect(&context, game_addr, AUTHOR).await;
+    let mut reader = GameClient::connect(&context, game_addr, READER).await;
+
+    let id = create(&mut author, AREA, 1).await;
+
+    let mut request = RequestRemoveBloodMessage::new();
+    request.set_message_id(id);
+
+    let reply = reader
+        .request(MessageType::RequestRemoveBloodMessage, &request)
+        .await
+        .unwrap();
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    assert!(context.blood_messages().get(id).is_some());
+
+    let reply = author
+        .request(MessageType::RequestRemoveBloodMessage, &request)
+        .await
+        .unwrap();
+    reply.expect(MessageType::Reply).unwrap();
+    assert!(context.blood_messages().is_empty());
+}
+
+fn rated_message(steam_id: &str, rating_good: u32) -> BloodMessage {
+    BloodMessage {
+        id: 0,
+        steam_id: steam_id.to_string(),
+        character_name: String::new(),
+        location: Location {
+            online_area_id: AREA,
+            ..Location::default()
+        },
+        text: MessageText::default(),
+        gesture_id: 0,
+        created: SystemTime::now(),
+        rating_good,
+        rating_poor: 0,
+    }
+}
+
+#[test]
+fn test_sampling_favours_well_rated_messages() {
+    let store = BloodMessageStore::default();
+    let popular = store.insert(rated_message("popular", 50), 10);
+    for i in 0..9 {
+        store.insert(rated_message(&format!("author{}", i), 0), 10);
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let picked = (0..1000)
+        .filter(|_| store.sample(AREA, READER, 1, &mut rng)[0].id == popular)
+        .count();
+
+    // With equal weights the popular message would be picked around 100 times.
+    assert!(picked > 500, "popular message picked {} times", picked);
+}
+
+#[tokio::test]
+async fn test_evaluation_is_pushed_to_author() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut author = GameClient::connect(&context, game_addr, AUTHOR).await;
+    let mut reader = GameClient::connect(&context, game_addr, READER).await;
+
+    let id = create(&mut author, AREA, 1).await;
+
+    let reply = evaluate(&mut reader, id, true).await;
+    reply.expect(MessageType::Reply).unwrap();
+
+    let push = author.receive_push().await.unwrap();
+    push.expect(MessageType::PushEvaluateBloodMessage).unwrap();
+
+    let push: PushEvaluateBloodMessage = push.decode().unwrap();
+    assert_eq!(id, push.get_message_id());
+    assert!(push.get_good());
+    assert_eq!(1, push.get_rating_good());
+    assert_eq!(0, push.get_rating_poor());
+
+    let response = list(&mut reader, AREA).await;
+    assert_eq!(1, response.get_messages()[0].get_rating_good());
+}
+
+#[tokio::test]
+async fn test_each_player_evaluates_once() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut author = GameClient::connect(&context, game_addr, AUTHOR).await;
+    let mut reader = GameClient::connect(&context, game_addr, READER).await;
+
+    let id = create(&mut author, AREA, 1).await;
+
+    evaluate(&mut reader, id, false)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let reply = evaluate(&mut reader, id, true).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    let reply = evaluate(&mut author, id, true).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    let message = context.blood_messages().get(id).unwrap();
+    assert_eq!(0, message.rating_good);
+    assert_eq!(1, message.rating_poor);
+}
+
+#[test]
+fn test_listing_is_ordered_by_rating() {
+    let store = BloodMessageStore::default();
+    let ids: Vec<u64> = [3, 10, 0, 7]
+        .iter()
+        .enumerate()
+        .map(|(i, rating)| store.insert(rated_message(&format!("author{}", i), *rating), 10))
+        .collect();
+
+    let listed: Vec<u64> = store
+        .sample(AREA, READER, 10, &mut rand::thread_rng())
+        .into_iter()
+        .map(|message| message.id)
+        .collect();
+
+    assert_eq!(vec![ids[1], ids[3], ids[0], ids[2]], listed);
+}
+
+#[test]
+fn test_messages_and_votes_are_persisted() {
+    let dir = TempDir::new("blood_messages");
+    let storage = dir.storage();
+
+    let store = BloodMessageStore::load(&storage).unwrap();
+    let id = store.insert(rated_message(AUTHOR, 0), 10);
+    assert!(matches!(
+        store.evaluate(id, READER, true),
+        Evaluation::Recorded(_)
+    ));
+    store.save(&storage).unwrap();
+
+    let store = BloodMessageStore::load(&storage).unwrap();
+    let message = store.get(id).unwrap();
+    assert_eq!(1, message.rating_good);
+    assert_eq!(
+        1,
+        store
+            .sample(AREA, READER, 10, &mut rand::thread_rng())
+            .len()
+    );
+    assert_eq!(
+        Evaluation::AlreadyEvaluated,
+        store.evaluate(id, READER, true)
+    );
+    assert_ne!(id, store.insert(rated_message(AUTHOR, 0), 10));
+}
+
+#[test]
+fn test_corrupt_snapshot_is_an_error() {
+    let dir = TempDir::new("corrupt");
+    fs::write(dir.path().join("blood_messages.json"), b"{ not json").unwrap();
+
+    let result = ServerContext::new(common::config_with(&format!(
+        "[storage]\ndata_dir = {:?}",
+        dir.path().display().to_string()
+    )));
+    assert!(result.is_err());
+}
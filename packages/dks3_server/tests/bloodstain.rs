@@ -0,0 +1,243 @@
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use dks3_proto::common::Vector3;
+use dks3_proto::Frpg2RequestMessage::{
+    RequestCreateBloodstain, RequestCreateBloodstainResponse, RequestGetBloodstainList,
+    RequestGetBloodstainListResponse, RequestGetBloodstainReplay,
+    RequestGetBloodstainReplayResponse,
+};
+use dks3_server::bloodstain::{Bloodstain, BloodstainStore};
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+use dks3_server::location::Location;
+
+use common::{GameClient, TempDir};
+
+mod common;
+
+const DEAD: &str = "0110000100000001";
+const WATCHER: &str = "0110000100000002";
+const AREA: u32 = 300_000;
+
+fn create_request(online_area_id: u32, replay: &[u8]) -> RequestCreateBloodstain {
+    let mut position = Vector3::new();
+    position.set_x(1.0);
+    position.set_y(2.0);
+    position.set_z(3.0);
+
+    let mut request = RequestCreateBloodstain::new();
+    request.set_online_area_id(online_area_id);
+    request.set_map_id(30_000_000);
+    request.set_position(position);
+    request.set_angle(0.5);
+    request.set_replay_data(replay.to_vec());
+    request
+}
+
+async fn create(client: &mut GameClient, online_area_id: u32, replay: &[u8]) -> Message {
+    client
+        .request(
+            MessageType::RequestCreateBloodstain,
+            &create_request(online_area_id, replay),
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_id(client: &mut GameClient, online_area_id: u32, replay: &[u8]) -> u64 {
+    let response: RequestCreateBloodstainResponse = create(client, online_area_id, replay)
+        .await
+        .decode()
+        .unwrap();
+    response.get_bloodstain_id()
+}
+
+async fn list(client: &mut GameClient, online_area_id: u32) -> RequestGetBloodstainListResponse {
+    let mut request = RequestGetBloodstainList::new();
+    request.set_online_area_id(online_area_id);
+
+    client
+        .request(MessageType::RequestGetBloodstainList, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap()
+}
+
+async fn replay(client: &mut GameClient, bloodstain_id: u64) -> Message {
+    let mut request = RequestGetBloodstainReplay::new();
+    request.set_bloodstain_id(bloodstain_id);
+
+    client
+        .request(MessageType::RequestGetBloodstainReplay, &request)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_bloodstains_are_listed_and_replayed() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut dead = GameClient::connect(&context, game_addr, DEAD).await;
+    let mut watcher = GameClient::connect(&context, game_addr, WATCHER).await;
+
+    let id = create_id(&mut dead, AREA, b"replay").await;
+    create_id(&mut dead, AREA + 1, b"elsewhere").await;
+
+    let response = list(&mut watcher, AREA).await;
+    assert_eq!(1, response.get_bloodstains().len());
+
+    let bloodstain = &response.get_bloodstains()[0];
+    assert_eq!(id, bloodstain.get_bloodstain_id());
+    assert_eq!(DEAD, bloodstain.get_steam_id());
+    assert_eq!(3.0, bloodstain.get_position().get_z());
+    assert!(list(&mut dead, AREA).await.get_bloodstains().is_empty());
+
+    let response: RequestGetBloodstainReplayResponse =
+        replay(&mut watcher, id).await.decode().unwrap();
+    assert_eq!(id, response.get_bloodstain_id());
+    assert_eq!(b"replay", response.get_replay_data());
+
+    let reply = replay(&mut watcher, id + 100).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+}
+
+#[tokio::test]
+async fn test_oversized_replays_are_rejected() {
+    let context = common::context_with("[bloodstains]\nmax_replay_bytes = 4");
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut dead = GameClient::connect(&context, game_addr, DEAD).await;
+
+    let reply = create(&mut dead, AREA, b"12345").await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    assert!(context.bloodstains().is_empty());
+
+    create(&mut dead, AREA, b"1234")
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    assert_eq!(1, context.bloodstains().len());
+}
+
+#[tokio::test]
+async fn test_list_is_capped() {
+    let context = common::context_with("[bloodstains]\nmax_list_entries = 3");
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut dead = GameClient::connect(&context, game_addr, DEAD).await;
+    let mut watcher = GameClient::connect(&context, game_addr, WATCHER).await;
+
+    for _ in 0..5 {
+        create_id(&mut dead, AREA, b"replay").await;
+    }
+
+    assert_eq!(3, list(&mut watcher, AREA).await.get_bloodstains().len());
+}
+
+#[tokio::test]
+async fn test_bloodstain_at_non_finite_location_is_rejected() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut dead = GameClient::connect(&context, game_addr, DEAD).await;
+
+    let mut request = create_request(AREA, b"replay");
+    request.mut_position().set_x(f32::NEG_INFINITY);
+    let reply = dead
+        .request(MessageType::RequestCreateBloodstain, &request)
+        .await
+        .unwrap();
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    assert!(context.bloodstains().is_empty());
+}
+
+#[tokio::test]
+async fn test_bloodstains_are_capped_per_player_and_area() {
+    let context = common::context_with("[bloodstains]\nmax_per_player = 2\nmax_per_area = 3");
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut dead = GameClient::connect(&context, game_addr, DEAD).await;
+    let mut watcher = GameClient::connect(&context, game_addr, WATCHER).await;
+
+    let oldest = create_id(&mut dead, AREA, b"1").await;
+    create_id(&mut dead, AREA, b"2").await;
+    create_id(&mut dead, AREA, b"3").await;
+    assert_eq!(2, context.bloodstains().len());
+    assert_eq!(None, context.bloodstains().get(oldest));
+
+    let oldest = context
+        .bloodstains()
+        .sample(AREA, WATCHER, 10, &mut rand::thread_rng())
+        .into_iter()
+        .map(|bloodstain| bloodstain.id)
+        .min()
+        .unwrap();
+    create_id(&mut watcher, AREA, b"4").await;
+    create_id(&mut watcher, AREA, b"5").await;
+    assert_eq!(3, context.bloodstains().len());
+    assert_eq!(None, context.bloodstains().get(oldest));
+}
+
+fn bloodstain_aged(age: Duration) -> Bloodstain {
+    Bloodstain {
+        id: 0,
+        steam_id: DEAD.to_string(),
+        location: Location {
+            online_area_id: AREA,
+            ..Location::default()
+        },
+        created: SystemTime::now() - age,
+    }
+}
+
+#[test]
+fn test_old_bloodstains_expire() {
+    let store = BloodstainStore::default();
+    let fresh = store.insert(bloodstain_aged(Duration::from_secs(60)), vec![1], 10, 10);
+    let stale = store.insert(
+        bloodstain_aged(Duration::from_secs(2 * 60 * 60)),
+        vec![2],
+        10,
+        10,
+    );
+
+    assert_eq!(1, store.expire(Duration::from_secs(60 * 60)));
+    assert!(store.get(fresh).is_some());
+    assert_eq!(None, store.get(stale));
+    assert_eq!(None, store.replay(stale));
+    assert_eq!(
+        1,
+        store
+            .sample(AREA, WATCHER, 10, &mut rand::thread_rng())
+            .len()
+    );
+}
+
+#[test]
+fn test_bloodstains_are_persisted() {
+    let dir = TempDir::new("bloodstains");
+    let storage = dir.storage();
+
+    let store = BloodstainStore::load(&storage).unwrap();
+    let id = store.insert(
+        bloodstain_aged(Duration::from_secs(0)),
+        vec![1, 2, 3],
+        10,
+        10,
+    );
+    store.save(&storage).unwrap();
+
+    let replay_path = dir.path().join("bloodstains").join(format!("{}.bin", id));
+    assert_eq!(vec![1, 2, 3], fs::read(&replay_path).unwrap());
+
+    let store = BloodstainStore::load(&storage).unwrap();
+    assert_eq!(DEAD, store.get(id).unwrap().steam_id);
+    assert_eq!(Some(vec![1, 2, 3]), store.replay(id));
+
+    let newer = store.insert(bloodstain_aged(Duration::from_secs(0)), vec![4], 1, 10);
+    assert_ne!(id, newer);
+    store.save(&storage).unwrap();
+    assert!(!replay_path.exists());
+
+    let store = BloodstainStore::load(&storage).unwrap();
+    assert_eq!(None, store.get(id));
+    assert_eq!(Some(vec![4]), store.replay(newer));
+}
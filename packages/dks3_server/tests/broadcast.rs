@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use tokio::time::timeout;
+
+use dks3_config::Covenant;
+use dks3_proto::Frpg2RequestMessage::PushBroadcastMessage;
+use dks3_server::broadcast::BroadcastFilter;
+use dks3_server::frame::MessageType;
+use dks3_server::session::CharacterSummary;
+use dks3_server::ServerContext;
+
+use common::GameClient;
+
+mod common;
+
+const FIRST: &str = "0110000100000001";
+const SECOND: &str = "0110000100000002";
+
+fn set_character(
+    context: &ServerContext,
+    steam_id: &str,
+    online_area_id: u32,
+    covenant: Option<Covenant>,
+) {
+    let session = context.sessions().by_steam_id(steam_id).unwrap();
+    context.sessions().set_character(
+        session.id,
+        CharacterSummary {
+            online_area_id,
+            covenant,
+            ..CharacterSummary::default()
+        },
+    );
+}
+
+async fn receive(client: &mut GameClient) -> PushBroadcastMessage {
+    let push = timeout(Duration::from_secs(5), client.receive_push())
+        .await
+        .expect("nothing was pushed")
+        .unwrap();
+    push.expect(MessageType::PushBroadcastMessage).unwrap();
+    push.decode().unwrap()
+}
+
+#[tokio::test]
+async fn test_broadcast_reaches_everyone_online() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut first = GameClient::connect(&context, game_addr, FIRST).await;
+    let mut second = GameClient::connect(&context, game_addr, SECOND).await;
+
+    let sent = context.broadcast(
+        &BroadcastFilter::default(),
+        "Maintenance",
+        "Servers restart in 10 minutes",
+    );
+    assert_eq!(2, sent);
+
+    for client in [&mut first, &mut second].iter_mut() {
+        let push = receive(client).await;
+        assert_eq!("Maintenance", push.get_header());
+        assert_eq!("Servers restart in 10 minutes", push.get_message());
+    }
+}
+
+#[tokio::test]
+async fn test_broadcast_is_filtered() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut first = GameClient::connect(&context, game_addr, FIRST).await;
+    let _second = GameClient::connect(&context, game_addr, SECOND).await;
+
+    set_character(&context, FIRST, 300_000, Some(Covenant::WayOfBlue));
+    set_character(&context, SECOND, 400_000, Some(Covenant::MoundMakers));
+
+    let by_area = BroadcastFilter {
+        online_area_ids: vec![300_000],
+        ..BroadcastFilter::default()
+    };
+    assert_eq!(1, context.broadcast(&by_area, "", "area"));
+    assert_eq!("area", receive(&mut first).await.get_message());
+
+    let by_covenant = BroadcastFilter {
+        covenants: vec![Covenant::WayOfBlue],
+        ..BroadcastFilter::default()
+    };
+    assert_eq!(1, context.broadcast(&by_covenant, "", "covenant"));
+    assert_eq!("covenant", receive(&mut first).await.get_message());
+
+    let by_steam_id = BroadcastFilter {
+        steam_ids: vec![FIRST.to_string()],
+        ..BroadcastFilter::default()
+    };
+    assert_eq!(1, context.broadcast(&by_steam_id, "", "player"));
+    assert_eq!("player", receive(&mut first).await.get_message());
+
+    let nobody = BroadcastFilter {
+        online_area_ids: vec![300_000],
+        covenants: vec![Covenant::MoundMakers],
+        ..BroadcastFilter::default()
+    };
+    assert_eq!(0, context.broadcast(&nobody, "", "nobody"));
+}
@@ -0,0 +1,202 @@
+use dks3_config::Covenant;
+use dks3_proto::Frpg2RequestMessage::{
+    PushRequestVisit, RequestBreakInTarget, RequestRegisterCovenant, RequestUnregisterCovenant,
+};
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+use dks3_server::session::SessionId;
+use dks3_server::ServerContext;
+
+use common::{GameClient, Status};
+
+mod common;
+
+const INVADER: &str = "0110000100000001";
+const HOST: &str = "0110000100000002";
+const VISITOR: &str = "0110000100000003";
+const OTHER_VISITOR: &str = "0110000100000004";
+const FARRON: u32 = 300_000;
+
+fn embered(online_area_id: u32, soul_level: u32) -> Status<'static> {
+    Status {
+        embered: true,
+        ..Status::new(online_area_id, soul_level)
+    }
+}
+
+async fn register(client: &mut GameClient, covenant: u32) -> Message {
+    let mut request = RequestRegisterCovenant::new();
+    request.set_covenant(covenant);
+
+    client
+        .request(MessageType::RequestRegisterCovenant, &request)
+        .await
+        .unwrap()
+}
+
+async fn break_in(client: &mut GameClient, steam_id: &str) {
+    let mut request = RequestBreakInTarget::new();
+    request.set_steam_id(steam_id.to_string());
+
+    client
+        .request(MessageType::RequestBreakInTarget, &request)
+        .await
+        .unwrap()
+        .expect(MessageType::Reply)
+        .unwrap();
+}
+
+async fn visit(client: &mut GameClient) -> PushRequestVisit {
+    let push = client.receive_push().await.unwrap();
+    push.expect(MessageType::PushRequestVisit).unwrap();
+    push.decode().unwrap()
+}
+
+fn session_id(context: &ServerContext, steam_id: &str) -> SessionId {
+    context.sessions().by_steam_id(steam_id).unwrap().id
+}
+
+#[tokio::test]
+async fn test_way_of_blue_hosts_summon_blue_sentinels() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut invader = GameClient::connect(&context, game_addr, INVADER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut visitor = GameClient::connect(&context, game_addr, VISITOR).await;
+    let mut other_visitor = GameClient::connect(&context, game_addr, OTHER_VISITOR).await;
+
+    common::update_status(&mut host, &embered(FARRON + 1, 50)).await;
+    common::update_status(&mut visitor, &embered(FARRON + 2, 55)).await;
+    common::update_status(&mut other_visitor, &embered(FARRON + 2, 55)).await;
+    register(&mut host, Covenant::WayOfBlue.id())
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    for client in [&mut visitor, &mut other_visitor].iter_mut() {
+        register(client, Covenant::BlueSentinels.id())
+            .await
+            .expect(MessageType::Reply)
+            .unwrap();
+    }
+
+    common::update_status(&mut invader, &Status::new(FARRON + 1, 50)).await;
+    break_in(&mut invader, HOST).await;
+    host.receive_push().await.unwrap();
+
+    // Only the sentinel who has waited longest is sent.
+    let push = visit(&mut visitor).await;
+    assert_eq!(Covenant::BlueSentinels.id(), push.get_covenant());
+    assert_eq!(HOST, push.get_host_steam_id());
+    assert_eq!(FARRON + 1, push.get_online_area_id());
+
+    let other_visitor_id = session_id(&context, OTHER_VISITOR);
+    assert_eq!(
+        vec![other_visitor_id],
+        context.covenants().queued(Covenant::BlueSentinels)
+    );
+    assert_eq!(
+        None,
+        context
+            .covenants()
+            .covenant_of(session_id(&context, VISITOR))
+    );
+}
+
+#[tokio::test]
+async fn test_area_policies_summon_matching_members() {
+    let context = common::context_with(&format!(
+        r#"
+[[covenants.visit_policies]]
+covenant = "watchdogs_of_farron"
+online_area_ids = [{}]
+max_visitors = 2
+"#,
+        FARRON
+    ));
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut invader = GameClient::connect(&context, game_addr, INVADER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut visitor = GameClient::connect(&context, game_addr, VISITOR).await;
+    let mut other_visitor = GameClient::connect(&context, game_addr, OTHER_VISITOR).await;
+
+    common::update_status(&mut visitor, &embered(FARRON + 1, 55)).await;
+    common::update_status(&mut other_visitor, &embered(FARRON + 1, 300)).await;
+    for client in [&mut visitor, &mut other_visitor].iter_mut() {
+        register(client, Covenant::WatchdogsOfFarron.id())
+            .await
+            .expect(MessageType::Reply)
+            .unwrap();
+    }
+
+    // Hosts outside Farron don't get help.
+    common::update_status(&mut host, &embered(FARRON + 1, 50)).await;
+    common::update_status(&mut invader, &Status::new(FARRON + 1, 50)).await;
+    break_in(&mut invader, HOST).await;
+    host.receive_push().await.unwrap();
+    assert_eq!(
+        2,
+        context
+            .covenants()
+            .queued(Covenant::WatchdogsOfFarron)
+            .len()
+    );
+
+    let mut other_host = GameClient::connect(&context, game_addr, "0110000100000005").await;
+    common::update_status(&mut other_host, &embered(FARRON, 50)).await;
+    common::update_status(&mut invader, &Status::new(FARRON, 50)).await;
+    break_in(&mut invader, "0110000100000005").await;
+    other_host.receive_push().await.unwrap();
+
+    let push = visit(&mut visitor).await;
+    assert_eq!(Covenant::WatchdogsOfFarron.id(), push.get_covenant());
+    assert_eq!("0110000100000005", push.get_host_steam_id());
+
+    // The other watchdog is too high level to defend this host.
+    assert_eq!(
+        vec![session_id(&context, OTHER_VISITOR)],
+        context.covenants().queued(Covenant::WatchdogsOfFarron)
+    );
+}
+
+#[tokio::test]
+async fn test_register_and_unregister_covenant() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut visitor = GameClient::connect(&context, game_addr, VISITOR).await;
+    let visitor_id = session_id(&context, VISITOR);
+
+    let reply = register(&mut visitor, 0).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    register(&mut visitor, Covenant::BlueSentinels.id())
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    register(&mut visitor, Covenant::AldrichFaithful.id())
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    assert_eq!(
+        Some(Covenant::AldrichFaithful),
+        context.covenants().covenant_of(visitor_id)
+    );
+    assert!(context
+        .covenants()
+        .queued(Covenant::BlueSentinels)
+        .is_empty());
+
+    visitor
+        .request(
+            MessageType::RequestUnregisterCovenant,
+            &RequestUnregisterCovenant::new(),
+        )
+        .await
+        .unwrap()
+        .expect(MessageType::Reply)
+        .unwrap();
+    assert_eq!(None, context.covenants().covenant_of(visitor_id));
+    assert!(context
+        .covenants()
+        .queued(Covenant::AldrichFaithful)
+        .is_empty());
+}
@@ -0,0 +1,232 @@
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use dks3_proto::Frpg2RequestMessage::{
+    GetServiceStatus, GetServiceStatusResponse, KeyMaterial, RequestErrorResponse,
+};
+use dks3_server::dispatch::{
+    Dispatcher, ErrorCode, Middleware, Next, RequestContext, RequestError,
+};
+use dks3_server::frame::{Message, MessageType};
+use dks3_server::game;
+use dks3_server::Result;
+
+use common::GameClient;
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+
+async fn service_status(
+    request: RequestContext,
+    status: GetServiceStatus,
+) -> Result<GetServiceStatusResponse> {
+    let mut response = GetServiceStatusResponse::new();
+    response.set_f1(status.get_f1());
+    response.set_steam_id(request.steam_id().to_string());
+    response.set_f3(0);
+    response.set_f4(status.get_f4());
+
+    Ok(response)
+}
+
+async fn failing(_request: RequestContext, _body: KeyMaterial) -> Result<KeyMaterial> {
+    Err("database is on fire".into())
+}
+
+fn status_request() -> GetServiceStatus {
+    let mut status = GetServiceStatus::new();
+    status.set_f1(1);
+    status.set_steam_id(STEAM_ID.to_string());
+    status.set_f4(115);
+    status
+}
+
+fn key_material() -> KeyMaterial {
+    let mut key_material = KeyMaterial::new();
+    key_material.set_key_material(vec![0; 8]);
+    key_material
+}
+
+fn error_code(reply: &Message) -> (ErrorCode, RequestErrorResponse) {
+    reply.expect(MessageType::ErrorReply).unwrap();
+
+    let error: RequestErrorResponse = reply.decode().unwrap();
+    (ErrorCode::try_from(error.get_code()).unwrap(), error)
+}
+
+#[tokio::test]
+async fn test_request_is_routed_to_handler() {
+    let context = common::context();
+    let mut dispatcher = game::dispatcher(&context);
+    dispatcher.register(MessageType::GetServiceStatus, service_status);
+
+    let game_addr = common::spawn_game_server_with(&context, dispatcher).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let reply = client
+        .request(MessageType::GetServiceStatus, &status_request())
+        .await
+        .unwrap();
+    reply.expect(MessageType::Reply).unwrap();
+
+    let response: GetServiceStatusResponse = reply.decode().unwrap();
+    assert_eq!(STEAM_ID, response.get_steam_id());
+    assert_eq!(115, response.get_f4());
+
+    let stats = context.request_metrics().get(MessageType::GetServiceStatus);
+    assert_eq!(1, stats.count);
+    assert_eq!(0, stats.errors);
+}
+
+#[tokio::test]
+async fn test_unhandled_request_is_answered_with_error() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let reply = client
+        .request(MessageType::GetServiceStatus, &status_request())
+        .await
+        .unwrap();
+
+    let (code, error) = error_code(&reply);
+    assert_eq!(ErrorCode::UnknownRequest, code);
+    assert_eq!(MessageType::GetServiceStatus.id(), error.get_message_type());
+
+    let stats = context.request_metrics().get(MessageType::GetServiceStatus);
+    assert_eq!(1, stats.errors);
+}
+
+#[tokio::test]
+async fn test_unknown_request_types_share_metrics() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    for message_type in 0xfff0..0xfff4 {
+        let reply = client.request_with_id(message_type, b"").await.unwrap();
+        assert_eq!(ErrorCode::UnknownRequest, error_code(&reply).0);
+    }
+
+    let metrics = context.request_metrics();
+    assert_eq!(4, metrics.unknown().count);
+    assert_eq!(4, metrics.unknown().errors);
+}
+
+#[tokio::test]
+async fn test_handler_errors_are_not_leaked() {
+    let context = common::context();
+    let mut dispatcher = game::dispatcher(&context);
+    dispatcher.register(MessageType::KeyMaterial, failing);
+
+    let game_addr = common::spawn_game_server_with(&context, dispatcher).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let reply = client
+        .request(MessageType::KeyMaterial, &key_material())
+        .await
+        .unwrap();
+
+    let (code, error) = error_code(&reply);
+    assert_eq!(ErrorCode::Internal, code);
+    assert!(!error.has_reason());
+}
+
+#[tokio::test]
+async fn test_malformed_request_is_rejected() {
+    let context = common::context();
+    let mut dispatcher = game::dispatcher(&context);
+    dispatcher.register(MessageType::GetServiceStatus, service_status);
+
+    let game_addr = common::spawn_game_server_with(&context, dispatcher).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let reply = client
+        .request_raw(MessageType::GetServiceStatus, b"\xff\xff\xff")
+        .await
+        .unwrap();
+
+    assert_eq!(ErrorCode::InvalidRequest, error_code(&reply).0);
+}
+
+#[tokio::test]
+async fn test_requests_are_rate_limited() {
+    let context = common::context_with("[sessions]\nrequest_rate = 0\nrequest_burst = 2");
+    let mut dispatcher = game::dispatcher(&context);
+    dispatcher.register(MessageType::GetServiceStatus, service_status);
+
+    let game_addr = common::spawn_game_server_with(&context, dispatcher).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    for _ in 0..2 {
+        let reply = client
+            .request(MessageType::GetServiceStatus, &status_request())
+            .await
+            .unwrap();
+        reply.expect(MessageType::Reply).unwrap();
+    }
+
+    let reply = client
+        .request(MessageType::GetServiceStatus, &status_request())
+        .await
+        .unwrap();
+    assert_eq!(ErrorCode::RateLimited, error_code(&reply).0);
+}
+
+#[derive(Default)]
+struct Deny {
+    seen: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Middleware for Deny {
+    async fn handle(
+        &self,
+        request: &RequestContext,
+        message: &Message,
+        next: Next<'_>,
+    ) -> Result<Message> {
+        self.seen.fetch_add(1, Ordering::SeqCst);
+
+        if message.message_type() == Some(MessageType::KeyMaterial) {
+            return Err(RequestError::new(ErrorCode::Unauthorized, "denied").into());
+        }
+
+        next.run(request, message).await
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_wraps_every_request() {
+    let context = common::context();
+    let seen = Arc::new(AtomicUsize::new(0));
+
+    let mut dispatcher = Dispatcher::default();
+    dispatcher
+        .wrap(Deny { seen: seen.clone() })
+        .register(MessageType::GetServiceStatus, service_status)
+        .register(MessageType::KeyMaterial, failing);
+
+    let game_addr = common::spawn_game_server_with(&context, dispatcher).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let reply = client
+        .request(MessageType::GetServiceStatus, &status_request())
+        .await
+        .unwrap();
+    reply.expect(MessageType::Reply).unwrap();
+
+    let reply = client
+        .request(MessageType::KeyMaterial, &key_material())
+        .await
+        .unwrap();
+    let (code, error) = error_code(&reply);
+    assert_eq!(ErrorCode::Unauthorized, code);
+    assert_eq!("denied", error.get_reason());
+
+    assert_eq!(2, seen.load(Ordering::SeqCst));
+}
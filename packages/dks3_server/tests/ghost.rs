@@ -0,0 +1,123 @@
+use dks3_proto::Frpg2RequestMessage::{
+    RequestCreateGhostData, RequestGetGhostDataList, RequestGetGhostDataListResponse,
+};
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+use dks3_server::ghost::{Ghost, GhostStore};
+
+use common::GameClient;
+
+mod common;
+
+const RECORDER: &str = "0110000100000001";
+const WATCHER: &str = "0110000100000002";
+const AREA: u32 = 300_000;
+
+async fn upload(client: &mut GameClient, online_area_id: u32, data: &[u8]) -> Message {
+    let mut request = RequestCreateGhostData::new();
+    request.set_online_area_id(online_area_id);
+    request.set_map_id(30_000_000);
+    request.set_ghost_data(data.to_vec());
+
+    client
+        .request(MessageType::RequestCreateGhostData, &request)
+        .await
+        .unwrap()
+}
+
+async fn list(client: &mut GameClient, online_area_id: u32) -> RequestGetGhostDataListResponse {
+    let mut request = RequestGetGhostDataList::new();
+    request.set_online_area_id(online_area_id);
+
+    client
+        .request(MessageType::RequestGetGhostDataList, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_ghosts_are_shared_with_other_players_in_area() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut recorder = GameClient::connect(&context, game_addr, RECORDER).await;
+    let mut watcher = GameClient::connect(&context, game_addr, WATCHER).await;
+
+    upload(&mut recorder, AREA, b"walking")
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let response = list(&mut watcher, AREA).await;
+    assert_eq!(1, response.get_ghosts().len());
+
+    let ghost = &response.get_ghosts()[0];
+    assert_eq!(RECORDER, ghost.get_steam_id());
+    assert_eq!(b"walking", ghost.get_ghost_data());
+
+    assert!(list(&mut watcher, AREA + 1).await.get_ghosts().is_empty());
+    assert!(list(&mut recorder, AREA).await.get_ghosts().is_empty());
+}
+
+#[tokio::test]
+async fn test_uploads_are_rate_limited_per_session() {
+    let context = common::context_with("[ghosts]\nupload_interval_secs = 60");
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut recorder = GameClient::connect(&context, game_addr, RECORDER).await;
+    let mut watcher = GameClient::connect(&context, game_addr, WATCHER).await;
+
+    upload(&mut recorder, AREA, b"first")
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let reply = upload(&mut recorder, AREA, b"second").await;
+    assert_eq!(Some(ErrorCode::RateLimited), common::error_code(&reply));
+
+    upload(&mut watcher, AREA, b"other")
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    assert_eq!(2, context.ghosts().area_len(AREA));
+}
+
+#[tokio::test]
+async fn test_oversized_ghosts_are_rejected() {
+    let context = common::context_with("[ghosts]\nmax_data_bytes = 4");
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut recorder = GameClient::connect(&context, game_addr, RECORDER).await;
+
+    let reply = upload(&mut recorder, AREA, b"12345").await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    assert_eq!(0, context.ghosts().area_len(AREA));
+}
+
+#[test]
+fn test_oldest_ghosts_are_dropped_when_area_is_full() {
+    let store = GhostStore::default();
+    let ids: Vec<u64> = (0..5u8)
+        .map(|i| {
+            let ghost = Ghost {
+                id: 0,
+                steam_id: format!("recorder{}", i),
+                online_area_id: AREA,
+                map_id: 0,
+                data: vec![i],
+            };
+            store.insert(ghost, 3)
+        })
+        .collect();
+
+    assert_eq!(3, store.area_len(AREA));
+
+    let mut kept: Vec<u64> = store
+        .sample(AREA, WATCHER, 10, &mut rand::thread_rng())
+        .into_iter()
+        .map(|ghost| ghost.id)
+        .collect();
+    kept.sort_unstable();
+
+    assert_eq!(&ids[2..], kept.as_slice());
+}
@@ -0,0 +1,206 @@
+use dks3_proto::Frpg2RequestMessage::{
+    PushRequestBreakIn, RequestBreakInTarget, RequestGetBreakInTargetList,
+    RequestGetBreakInTargetListResponse,
+};
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+
+use common::{GameClient, Status};
+
+mod common;
+
+const INVADER: &str = "0110000100000001";
+const HOST: &str = "0110000100000002";
+const AREA: u32 = 300_000;
+
+fn embered(online_area_id: u32, soul_level: u32) -> Status<'static> {
+    Status {
+        embered: true,
+        ..Status::new(online_area_id, soul_level)
+    }
+}
+
+async fn targets(client: &mut GameClient, soul_level: u32) -> Vec<String> {
+    targets_with_password(client, soul_level, None).await
+}
+
+async fn targets_with_password(
+    client: &mut GameClient,
+    soul_level: u32,
+    password: Option<&str>,
+) -> Vec<String> {
+    let mut request = RequestGetBreakInTargetList::new();
+    request.set_online_area_id(AREA);
+    request.set_matching_parameter(common::matching_parameter(soul_level, password));
+
+    let response: RequestGetBreakInTargetListResponse = client
+        .request(MessageType::RequestGetBreakInTargetList, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+
+    let mut steam_ids: Vec<String> = response
+        .get_targets()
+        .iter()
+        .map(|target| target.get_steam_id().to_string())
+        .collect();
+    steam_ids.sort();
+    steam_ids
+}
+
+async fn break_in(client: &mut GameClient, steam_id: &str) -> Message {
+    let mut request = RequestBreakInTarget::new();
+    request.set_steam_id(steam_id.to_string());
+
+    client
+        .request(MessageType::RequestBreakInTarget, &request)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_targets_are_invadable_hosts_in_band() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut invader = GameClient::connect(&context, game_addr, INVADER).await;
+
+    let hosts = [
+        ("0110000100000010", AREA, 50, true, false),
+        ("0110000100000011", AREA, 55, false, true),
+        ("0110000100000012", AREA, 50, false, false),
+        ("0110000100000013", AREA, 100, true, false),
+        ("0110000100000014", AREA + 1, 50, true, false),
+    ];
+
+    let mut clients = vec![];
+    for (steam_id, online_area_id, soul_level, embered, in_coop) in hosts.iter() {
+        let mut client = GameClient::connect(&context, game_addr, steam_id).await;
+        let status = Status {
+            embered: *embered,
+            in_coop: *in_coop,
+            ..Status::new(*online_area_id, *soul_level)
+        };
+        common::update_status(&mut client, &status).await;
+        clients.push(client);
+    }
+
+    common::update_status(&mut invader, &embered(AREA, 50)).await;
+
+    assert_eq!(
+        vec!["0110000100000010", "0110000100000011"],
+        targets(&mut invader, 50).await
+    );
+}
+
+#[tokio::test]
+async fn test_password_hosts_are_only_targeted_with_same_password() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut invader = GameClient::connect(&context, game_addr, INVADER).await;
+
+    let hosts = [
+        ("0110000100000010", 50, None),
+        ("0110000100000011", 50, Some("sunbros")),
+        ("0110000100000012", 300, Some("sunbros")),
+        ("0110000100000013", 50, Some("darkwraiths")),
+    ];
+
+    let mut clients = vec![];
+    for (steam_id, soul_level, password) in hosts.iter() {
+        let mut client = GameClient::connect(&context, game_addr, steam_id).await;
+        let status = Status {
+            password: *password,
+            ..embered(AREA, *soul_level)
+        };
+        common::update_status(&mut client, &status).await;
+        clients.push(client);
+    }
+
+    assert_eq!(vec!["0110000100000010"], targets(&mut invader, 50).await);
+    assert_eq!(
+        vec!["0110000100000011", "0110000100000012"],
+        targets_with_password(&mut invader, 50, Some("sunbros")).await
+    );
+}
+
+#[tokio::test]
+async fn test_break_in_is_pushed_and_host_is_left_alone_afterwards() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut invader = GameClient::connect(&context, game_addr, INVADER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    common::update_status(&mut host, &embered(AREA, 50)).await;
+    common::update_status(&mut invader, &Status::new(AREA, 50)).await;
+    assert_eq!(vec![HOST], targets(&mut invader, 50).await);
+
+    break_in(&mut invader, HOST)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let push = host.receive_push().await.unwrap();
+    push.expect(MessageType::PushRequestBreakIn).unwrap();
+    let push: PushRequestBreakIn = push.decode().unwrap();
+    assert_eq!(INVADER, push.get_invader_steam_id());
+
+    assert!(targets(&mut invader, 50).await.is_empty());
+    let reply = break_in(&mut invader, HOST).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+}
+
+#[tokio::test]
+async fn test_break_in_is_held_to_target_list_rules() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut invader = GameClient::connect(&context, game_addr, INVADER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    common::update_status(&mut host, &embered(AREA, 50)).await;
+
+    // Without a status of their own, in another area, out of range, or with a password.
+    let attempts = [
+        None,
+        Some(Status::new(AREA + 1, 50)),
+        Some(Status::new(AREA, 300)),
+        Some(Status {
+            password: Some("sunbros"),
+            ..Status::new(AREA, 50)
+        }),
+    ];
+    for attempt in attempts.iter() {
+        if let Some(status) = attempt {
+            common::update_status(&mut invader, status).await;
+        }
+
+        let reply = break_in(&mut invader, HOST).await;
+        assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    }
+
+    // Nor into their own world.
+    common::update_status(&mut invader, &embered(AREA, 50)).await;
+    let reply = break_in(&mut invader, INVADER).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    break_in(&mut invader, HOST)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_break_in_is_rejected_when_host_has_left() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut invader = GameClient::connect(&context, game_addr, INVADER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    common::update_status(&mut host, &embered(AREA, 50)).await;
+    common::update_status(&mut invader, &Status::new(AREA, 50)).await;
+    let session = context.sessions().by_steam_id(HOST).unwrap();
+    context.sessions().evict(session.id);
+
+    let reply = break_in(&mut invader, HOST).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+}
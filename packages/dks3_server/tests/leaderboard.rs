@@ -0,0 +1,268 @@
+use std::time::{Duration, SystemTime};
+
+use dks3_config::{Leaderboard, ScorePolicy};
+use dks3_proto::Frpg2RequestMessage::{
+    RequestCountRanking, RequestCountRankingResponse, RequestGetCurrentRank,
+    RequestGetCurrentRankResponse, RequestGetRankingPage, RequestGetRankingPageResponse,
+    RequestRegisterRanking, RequestRegisterRankingResponse,
+};
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::MessageType;
+use dks3_server::leaderboard::LeaderboardStore;
+
+use common::{GameClient, TempDir};
+
+mod common;
+
+const PLAYER: &str = "0110000100000001";
+const OTHER_PLAYER: &str = "0110000100000002";
+
+fn at(secs: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+fn register(store: &LeaderboardStore, board: &Leaderboard, steam_id: &str, score: u64) -> u64 {
+    store.register(board, steam_id, "Character", score, at(0))
+}
+
+fn order(store: &LeaderboardStore, id: u32) -> Vec<String> {
+    store
+        .page(id, 0, 10)
+        .into_iter()
+        .map(|(_, ranking)| ranking.steam_id)
+        .collect()
+}
+
+async fn register_ranking(client: &mut GameClient, board_id: u32, score: u64) -> u64 {
+    let mut request = RequestRegisterRanking::new();
+    request.set_board_id(board_id);
+    request.set_score(score);
+
+    let response: RequestRegisterRankingResponse = client
+        .request(MessageType::RequestRegisterRanking, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    response.get_score()
+}
+
+#[test]
+fn test_score_policies() {
+    let store = LeaderboardStore::default();
+    let highest = Leaderboard::new(1, ScorePolicy::Highest);
+    let total = Leaderboard::new(2, ScorePolicy::Total);
+    let latest = Leaderboard::new(3, ScorePolicy::Latest);
+
+    for score in [30, 50, 20].iter() {
+        register(&store, &highest, PLAYER, *score);
+        register(&store, &total, PLAYER, *score);
+        register(&store, &latest, PLAYER, *score);
+    }
+
+    assert_eq!(50, store.rank(1, PLAYER).unwrap().1.score);
+    assert_eq!(100, store.rank(2, PLAYER).unwrap().1.score);
+    assert_eq!(20, store.rank(3, PLAYER).unwrap().1.score);
+}
+
+#[test]
+fn test_rankings_are_ordered_by_score_then_time() {
+    let store = LeaderboardStore::default();
+    let board = Leaderboard::new(1, ScorePolicy::Highest);
+
+    store.register(&board, "late", "Character", 10, at(2));
+    store.register(&board, "early", "Character", 10, at(1));
+    store.register(&board, "best", "Character", 20, at(3));
+
+    assert_eq!(vec!["best", "early", "late"], order(&store, 1));
+    assert_eq!(3, store.rank(1, "late").unwrap().0);
+    assert_eq!(None, store.rank(1, "nobody"));
+    assert_eq!(3, store.count(1));
+    assert_eq!(0, store.count(2));
+
+    let page = store.page(1, 1, 1);
+    assert_eq!(1, page.len());
+    assert_eq!((2, "early"), (page[0].0, page[0].1.steam_id.as_str()));
+}
+
+#[test]
+fn test_boards_are_reset_after_their_interval() {
+    let store = LeaderboardStore::default();
+    let config = common::config_with(
+        r#"
+[[leaderboards.boards]]
+id = 1
+score_policy = "total"
+reset_interval_secs = 100
+"#,
+    );
+    let weekly = config.leaderboards().board(1).unwrap().clone();
+    let permanent = Leaderboard::new(2, ScorePolicy::Total);
+    let boards = [weekly.clone(), permanent.clone()];
+
+    store.register(&weekly, PLAYER, "Character", 10, at(0));
+    store.register(&permanent, PLAYER, "Character", 10, at(0));
+
+    assert!(store.reset_expired(&boards, at(99)).is_empty());
+    assert_eq!(vec![1], store.reset_expired(&boards, at(100)));
+    assert_eq!(0, store.count(1));
+    assert_eq!(1, store.count(2));
+
+    // The interval starts again from the reset.
+    store.register(&weekly, PLAYER, "Character", 10, at(150));
+    assert!(store.reset_expired(&boards, at(199)).is_empty());
+    assert_eq!(vec![1], store.reset_expired(&boards, at(200)));
+}
+
+#[test]
+fn test_leaderboards_are_persisted() {
+    let dir = TempDir::new("leaderboards");
+    let storage = dir.storage();
+    let board = Leaderboard::new(1, ScorePolicy::Total);
+
+    let store = LeaderboardStore::load(&storage).unwrap();
+    register(&store, &board, PLAYER, 10);
+    register(&store, &board, OTHER_PLAYER, 20);
+    store.save(&storage).unwrap();
+
+    let store = LeaderboardStore::load(&storage).unwrap();
+    assert_eq!(vec![OTHER_PLAYER, PLAYER], order(&store, 1));
+    assert_eq!(15, register(&store, &board, PLAYER, 5));
+}
+
+#[tokio::test]
+async fn test_ranking_requests() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, PLAYER).await;
+    let mut other_client = GameClient::connect(&context, game_addr, OTHER_PLAYER).await;
+
+    assert_eq!(5, register_ranking(&mut client, 1, 5).await);
+    assert_eq!(8, register_ranking(&mut client, 1, 3).await);
+    assert_eq!(10, register_ranking(&mut other_client, 1, 10).await);
+
+    let mut request = RequestGetCurrentRank::new();
+    request.set_board_id(1);
+    let response: RequestGetCurrentRankResponse = client
+        .request(MessageType::RequestGetCurrentRank, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    let ranking = response.get_ranking();
+    assert_eq!((2, 8), (ranking.get_rank(), ranking.get_score()));
+
+    request.set_board_id(2);
+    let response: RequestGetCurrentRankResponse = client
+        .request(MessageType::RequestGetCurrentRank, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    assert!(!response.has_ranking());
+
+    let mut request = RequestCountRanking::new();
+    request.set_board_id(1);
+    let response: RequestCountRankingResponse = client
+        .request(MessageType::RequestCountRanking, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    assert_eq!(2, response.get_count());
+
+    let mut request = RequestGetRankingPage::new();
+    request.set_board_id(1);
+    request.set_offset(1);
+    request.set_max_entries(5);
+    let response: RequestGetRankingPageResponse = client
+        .request(MessageType::RequestGetRankingPage, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    let rankings = response.get_rankings();
+    assert_eq!(1, rankings.len());
+    assert_eq!(
+        (2, PLAYER),
+        (rankings[0].get_rank(), rankings[0].get_steam_id())
+    );
+}
+
+#[tokio::test]
+async fn test_pages_are_limited_by_config() {
+    let context = common::context_with(
+        r#"
+[[leaderboards.boards]]
+id = 100
+score_policy = "highest"
+max_page_entries = 1
+"#,
+    );
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, PLAYER).await;
+    let mut other_client = GameClient::connect(&context, game_addr, OTHER_PLAYER).await;
+
+    register_ranking(&mut client, 100, 5).await;
+    register_ranking(&mut other_client, 100, 10).await;
+
+    let mut request = RequestGetRankingPage::new();
+    request.set_board_id(100);
+    request.set_offset(0);
+    let response: RequestGetRankingPageResponse = client
+        .request(MessageType::RequestGetRankingPage, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    let rankings = response.get_rankings();
+    assert_eq!(1, rankings.len());
+    assert_eq!(OTHER_PLAYER, rankings[0].get_steam_id());
+}
+
+#[tokio::test]
+async fn test_unknown_boards_are_rejected() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, PLAYER).await;
+
+    let mut request = RequestRegisterRanking::new();
+    request.set_board_id(1000);
+    request.set_score(1);
+    let reply = client
+        .request(MessageType::RequestRegisterRanking, &request)
+        .await
+        .unwrap();
+
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    assert_eq!(0, context.leaderboards().count(1000));
+}
+
+#[tokio::test]
+async fn test_out_of_range_scores_are_rejected() {
+    let context = common::context_with(
+        r#"
+[[leaderboards.boards]]
+id = 100
+score_policy = "highest"
+min_score = 1
+max_score = 100
+"#,
+    );
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, PLAYER).await;
+
+    for score in [0, 101, u64::from(u32::MAX), u64::MAX].iter() {
+        let mut request = RequestRegisterRanking::new();
+        request.set_board_id(100);
+        request.set_score(*score);
+        let reply = client
+            .request(MessageType::RequestRegisterRanking, &request)
+            .await
+            .unwrap();
+        assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    }
+
+    assert_eq!(0, context.leaderboards().count(100));
+    assert_eq!(100, register_ranking(&mut client, 100, 100).await);
+}
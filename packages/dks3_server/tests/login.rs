@@ -0,0 +1,60 @@
+use futures::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio_util::codec::Framed;
+
+use dks3_proto::Frpg2RequestMessage::{
+    RequestQueryLoginServerInfo, RequestQueryLoginServerInfoResponse,
+};
+use dks3_server::crypto::rsa_encrypt;
+use dks3_server::frame::{packet_codec, Message, MessageType};
+use dks3_server::login::LoginServer;
+
+mod common;
+
+#[tokio::test]
+async fn test_login_server_info() {
+    let context = common::context();
+    let server = LoginServer::bind(context.clone(), "127.0.0.1:0")
+        .await
+        .unwrap();
+    let addr = server.local_addr().unwrap();
+    tokio::spawn(server.serve());
+
+    let mut request = RequestQueryLoginServerInfo::new();
+    request.set_steam_id("0110000100000001".to_string());
+    request.set_f3(114);
+
+    let message = Message::new(MessageType::RequestQueryLoginServerInfo, 1, &request).unwrap();
+    let public_key = context.config().security().public_key();
+    let packet = rsa_encrypt(&mut rand::thread_rng(), &public_key, &message.encode()).unwrap();
+
+    let mut client = Framed::new(TcpStream::connect(addr).await.unwrap(), packet_codec());
+    client.send(packet.into()).await.unwrap();
+
+    let reply = Message::parse(client.next().await.unwrap().unwrap().freeze()).unwrap();
+    assert_eq!(Some(MessageType::Reply), reply.message_type());
+    assert_eq!(1, reply.index());
+
+    let response: RequestQueryLoginServerInfoResponse = reply.decode().unwrap();
+    assert_eq!("127.0.0.1", response.get_server_ip());
+    assert_eq!(50001, response.get_port());
+}
+
+#[tokio::test]
+async fn test_login_rejects_plaintext() {
+    let server = LoginServer::bind(common::context(), "127.0.0.1:0")
+        .await
+        .unwrap();
+    let addr = server.local_addr().unwrap();
+    tokio::spawn(server.serve());
+
+    let mut request = RequestQueryLoginServerInfo::new();
+    request.set_steam_id("0110000100000001".to_string());
+    request.set_f3(114);
+
+    let message = Message::new(MessageType::RequestQueryLoginServerInfo, 1, &request).unwrap();
+    let mut client = Framed::new(TcpStream::connect(addr).await.unwrap(), packet_codec());
+    client.send(message.encode()).await.unwrap();
+
+    assert!(client.next().await.is_none());
+}
@@ -0,0 +1,164 @@
+use dks3_config::Matchmaking;
+use dks3_server::matchmaking::{is_match, Level, MultiplayerType};
+
+const MAX_SOUL_LEVEL: u32 = 802;
+const MAX_WEAPON_LEVEL: u32 = 10;
+
+/// The weapon levels the official servers match a player at each weapon level with.
+const VANILLA_WEAPON_LEVELS: [(u32, u32); 11] = [
+    (0, 1),
+    (0, 2),
+    (1, 3),
+    (2, 4),
+    (2, 5),
+    (3, 6),
+    (4, 7),
+    (5, 8),
+    (5, 9),
+    (6, 10),
+    (7, 10),
+];
+
+fn level(soul_level: u32, max_weapon_level: u32) -> Level {
+    Level {
+        soul_level,
+        max_weapon_level,
+        password: None,
+    }
+}
+
+fn with_password(soul_level: u32, password: &str) -> Level {
+    Level {
+        password: Some(password.to_string()),
+        ..level(soul_level, 5)
+    }
+}
+
+fn vanilla_soul_levels_match(ty: MultiplayerType, a: u32, b: u32) -> bool {
+    match ty {
+        MultiplayerType::Coop | MultiplayerType::Arena => {
+            let range = 10 + a / 10;
+            b + range >= a && b <= a + range
+        }
+        MultiplayerType::Invasion | MultiplayerType::Covenant => {
+            b + a / 10 >= a && b <= a + a / 10 + 20
+        }
+    }
+}
+
+const TYPES: [MultiplayerType; 4] = [
+    MultiplayerType::Coop,
+    MultiplayerType::Invasion,
+    MultiplayerType::Covenant,
+    MultiplayerType::Arena,
+];
+
+#[test]
+fn test_soul_level_bands_match_vanilla() {
+    let matchmaking = Matchmaking::default();
+
+    for ty in TYPES.iter().copied() {
+        for a in 1..=MAX_SOUL_LEVEL {
+            for b in 1..=MAX_SOUL_LEVEL {
+                assert_eq!(
+                    vanilla_soul_levels_match(ty, a, b),
+                    is_match(&matchmaking, ty, &level(a, 5), &level(b, 5)),
+                    "{:?} between soul levels {} and {}",
+                    ty,
+                    a,
+                    b
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_weapon_levels_match_vanilla() {
+    let matchmaking = Matchmaking::default();
+
+    for ty in TYPES.iter().copied() {
+        for a in 0..=MAX_WEAPON_LEVEL {
+            let (lowest, highest) = VANILLA_WEAPON_LEVELS[a as usize];
+            for b in 0..=MAX_WEAPON_LEVEL {
+                assert_eq!(
+                    b >= lowest && b <= highest,
+                    is_match(&matchmaking, ty, &level(100, a), &level(100, b)),
+                    "{:?} between weapon levels {} and {}",
+                    ty,
+                    a,
+                    b
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_matching_passwords_bypass_soul_level() {
+    let matchmaking = Matchmaking::default();
+
+    for ty in [MultiplayerType::Coop, MultiplayerType::Invasion]
+        .iter()
+        .copied()
+    {
+        assert!(is_match(
+            &matchmaking,
+            ty,
+            &with_password(10, "sunbros"),
+            &with_password(700, "sunbros")
+        ));
+        assert!(!is_match(
+            &matchmaking,
+            ty,
+            &with_password(100, "sunbros"),
+            &with_password(100, "darkwraiths")
+        ));
+        assert!(!is_match(
+            &matchmaking,
+            ty,
+            &with_password(100, "sunbros"),
+            &level(100, 5)
+        ));
+        assert!(!is_match(
+            &matchmaking,
+            ty,
+            &level(100, 5),
+            &with_password(100, "sunbros")
+        ));
+    }
+
+    // Passwords don't get around weapon levels.
+    let mut upgraded = with_password(100, "sunbros");
+    upgraded.max_weapon_level = 10;
+    assert!(!is_match(
+        &matchmaking,
+        MultiplayerType::Coop,
+        &with_password(100, "sunbros"),
+        &upgraded
+    ));
+}
+
+#[test]
+fn test_covenants_keep_soul_levels_with_passwords() {
+    let matchmaking = Matchmaking::default();
+
+    assert!(!is_match(
+        &matchmaking,
+        MultiplayerType::Covenant,
+        &with_password(100, "sunbros"),
+        &level(100, 5)
+    ));
+    assert!(is_match(
+        &matchmaking,
+        MultiplayerType::Covenant,
+        &with_password(100, "sunbros"),
+        &with_password(100, "sunbros")
+    ));
+    assert!(!is_match(
+        &matchmaking,
+        MultiplayerType::Covenant,
+        &with_password(10, "sunbros"),
+        &with_password(700, "sunbros")
+    ));
+}
@@ -0,0 +1,149 @@
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use dks3_config::Covenant;
+use dks3_proto::Frpg2RequestMessage::RequestUpdatePlayerStatus;
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+use dks3_server::matchmaking::{self, Level};
+use dks3_server::player::PlayerHistory;
+use dks3_server::session::CharacterSummary;
+
+use common::{GameClient, TempDir};
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+
+fn status(soul_level: u32, covenant: u32) -> RequestUpdatePlayerStatus {
+    let mut request = RequestUpdatePlayerStatus::new();
+    request.set_character_name("Siegward".to_string());
+    request.set_online_area_id(300_000);
+    request.set_matching_parameter(matchmaking::to_matching_parameter(&Level {
+        soul_level,
+        max_weapon_level: 7,
+        ..Level::default()
+    }));
+    request.set_embered(true);
+    request.set_in_coop(false);
+    request.set_covenant(covenant);
+    request.set_ng_cycle(2);
+    request
+}
+
+async fn update_status(client: &mut GameClient, request: &RequestUpdatePlayerStatus) -> Message {
+    client
+        .request(MessageType::RequestUpdatePlayerStatus, request)
+        .await
+        .unwrap()
+}
+
+fn character(soul_level: u32) -> CharacterSummary {
+    CharacterSummary {
+        name: "Siegward".to_string(),
+        soul_level,
+        ..CharacterSummary::default()
+    }
+}
+
+#[tokio::test]
+async fn test_status_is_decoded_and_recorded() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let request = status(80, Covenant::WarriorOfSunlight.id());
+    for _ in 0..2 {
+        update_status(&mut client, &request)
+            .await
+            .expect(MessageType::Reply)
+            .unwrap();
+    }
+
+    let session = context.sessions().by_steam_id(STEAM_ID).unwrap();
+    let character = session.character.unwrap();
+    assert_eq!("Siegward", character.name);
+    assert_eq!((80, 7), (character.soul_level, character.max_weapon_level));
+    assert_eq!(Some(Covenant::WarriorOfSunlight), character.covenant);
+    assert_eq!(2, character.ng_cycle);
+    assert!(character.embered);
+
+    // Only changes are kept in the history.
+    update_status(&mut client, &status(81, 0))
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let history = context.player_history().history(STEAM_ID);
+    assert_eq!(2, history.len());
+    assert_eq!(character, history[0].character);
+    assert_eq!(81, history[1].character.soul_level);
+    assert_eq!(None, history[1].character.covenant);
+}
+
+#[tokio::test]
+async fn test_unknown_covenant_is_rejected() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let reply = update_status(&mut client, &status(80, 100)).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    let session = context.sessions().by_steam_id(STEAM_ID).unwrap();
+    assert_eq!(None, session.character);
+    assert!(context.player_history().history(STEAM_ID).is_empty());
+}
+
+#[test]
+fn test_history_keeps_latest_entries() {
+    let history = PlayerHistory::default();
+    for soul_level in 1..=5 {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(soul_level.into());
+        assert!(history.record(STEAM_ID, &character(soul_level), time, 3));
+    }
+
+    let soul_levels: Vec<u32> = history
+        .history(STEAM_ID)
+        .iter()
+        .map(|record| record.character.soul_level)
+        .collect();
+    assert_eq!(vec![3, 4, 5], soul_levels);
+    assert!(history.history("someone else").is_empty());
+}
+
+#[test]
+fn test_history_is_persisted() {
+    let dir = TempDir::new("player_history");
+    let storage = dir.storage();
+
+    let history = PlayerHistory::load(&storage).unwrap();
+    history.record(STEAM_ID, &character(10), SystemTime::now(), 10);
+    history.record(STEAM_ID, &character(11), SystemTime::now(), 10);
+    history.save(&storage).unwrap();
+
+    let history = PlayerHistory::load(&storage).unwrap();
+    let records = history.history(STEAM_ID);
+    assert_eq!(2, records.len());
+    assert_eq!(character(11), records[1].character);
+    assert!(!history.record(STEAM_ID, &character(11), SystemTime::now(), 10));
+}
+
+#[test]
+fn test_history_leaves_out_passwords() {
+    let dir = TempDir::new("player_history_passwords");
+    let storage = dir.storage();
+    let with_password = |password: &str| CharacterSummary {
+        password: Some(password.to_string()),
+        ..character(10)
+    };
+
+    let history = PlayerHistory::load(&storage).unwrap();
+    assert!(history.record(STEAM_ID, &with_password("hunter2"), SystemTime::now(), 10));
+    assert!(!history.record(STEAM_ID, &with_password("swordfish"), SystemTime::now(), 10));
+    assert_eq!(None, history.history(STEAM_ID)[0].character.password);
+
+    history.save(&storage).unwrap();
+    let saved = fs::read_to_string(dir.path().join("player_history.json")).unwrap();
+    assert!(!saved.contains("hunter2"));
+}
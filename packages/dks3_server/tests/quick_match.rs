@@ -0,0 +1,372 @@
+use std::time::Duration;
+
+use tokio::time::timeout;
+
+use dks3_proto::Frpg2RequestMessage::{
+    PushJoinQuickMatchResult, PushQuickMatchDissolved, PushRequestJoinQuickMatch,
+    PushStartQuickMatch, RequestAcceptQuickMatch, RequestJoinQuickMatch, RequestRegisterQuickMatch,
+    RequestRegisterQuickMatchResponse, RequestSearchQuickMatch, RequestSearchQuickMatchResponse,
+    RequestSendQuickMatchResult, RequestStartQuickMatch,
+};
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+use dks3_server::quick_match::{self, MatchResult, QuickMatchMode};
+
+use common::GameClient;
+
+mod common;
+
+const HOST: &str = "0110000100000001";
+const CHALLENGER: &str = "0110000100000002";
+const OTHER_CHALLENGER: &str = "0110000100000003";
+const MAP: u32 = 0x0a00_0000;
+
+async fn register(client: &mut GameClient, mode: QuickMatchMode, soul_level: u32) -> u64 {
+    let mut request = RequestRegisterQuickMatch::new();
+    request.set_mode(mode.id());
+    request.set_map_id(MAP);
+    request.set_matching_parameter(common::matching_parameter(soul_level, None));
+
+    let response: RequestRegisterQuickMatchResponse = client
+        .request(MessageType::RequestRegisterQuickMatch, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    response.get_match_id()
+}
+
+async fn search(
+    client: &mut GameClient,
+    mode: QuickMatchMode,
+    map_id: u32,
+    soul_level: u32,
+) -> Vec<u64> {
+    let mut request = RequestSearchQuickMatch::new();
+    request.set_mode(mode.id());
+    request.set_map_id(map_id);
+    request.set_matching_parameter(common::matching_parameter(soul_level, None));
+
+    let response: RequestSearchQuickMatchResponse = client
+        .request(MessageType::RequestSearchQuickMatch, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+
+    response
+        .get_matches()
+        .iter()
+        .map(|data| data.get_match_id())
+        .collect()
+}
+
+async fn join(client: &mut GameClient, match_id: u64, soul_level: u32) -> Message {
+    let mut request = RequestJoinQuickMatch::new();
+    request.set_match_id(match_id);
+    request.set_matching_parameter(common::matching_parameter(soul_level, None));
+
+    client
+        .request(MessageType::RequestJoinQuickMatch, &request)
+        .await
+        .unwrap()
+}
+
+async fn accept(client: &mut GameClient, match_id: u64, steam_id: &str, accepted: bool) {
+    let mut request = RequestAcceptQuickMatch::new();
+    request.set_match_id(match_id);
+    request.set_steam_id(steam_id.to_string());
+    request.set_accepted(accepted);
+
+    client
+        .request(MessageType::RequestAcceptQuickMatch, &request)
+        .await
+        .unwrap()
+        .expect(MessageType::Reply)
+        .unwrap();
+}
+
+async fn start(client: &mut GameClient, match_id: u64) -> Message {
+    let mut request = RequestStartQuickMatch::new();
+    request.set_match_id(match_id);
+
+    client
+        .request(MessageType::RequestStartQuickMatch, &request)
+        .await
+        .unwrap()
+}
+
+async fn report(client: &mut GameClient, match_id: u64, result: MatchResult) -> Message {
+    let mut request = RequestSendQuickMatchResult::new();
+    request.set_match_id(match_id);
+    request.set_result(result.id());
+
+    client
+        .request(MessageType::RequestSendQuickMatchResult, &request)
+        .await
+        .unwrap()
+}
+
+async fn receive<M: protobuf::Message>(client: &mut GameClient, message_type: MessageType) -> M {
+    let push = timeout(Duration::from_secs(5), client.receive_push())
+        .await
+        .expect("nothing was pushed")
+        .unwrap();
+    push.expect(message_type).unwrap();
+    push.decode().unwrap()
+}
+
+/// Have `challenger` join the match with `match_id`, and the host accept them.
+async fn join_and_accept(host: &mut GameClient, challenger: &mut GameClient, match_id: u64) {
+    join(challenger, match_id, 50)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    let _: PushRequestJoinQuickMatch = receive(host, MessageType::PushRequestJoinQuickMatch).await;
+
+    accept(host, match_id, CHALLENGER, true).await;
+    let _: PushJoinQuickMatchResult =
+        receive(challenger, MessageType::PushJoinQuickMatchResult).await;
+}
+
+/// Register a duel hosted by `host`, and start it with `challenger`, returning its ID.
+async fn start_duel(host: &mut GameClient, challenger: &mut GameClient) -> u64 {
+    let id = register(host, QuickMatchMode::Duel, 50).await;
+    join_and_accept(host, challenger, id).await;
+
+    start(host, id).await.expect(MessageType::Reply).unwrap();
+    let _: PushStartQuickMatch = receive(challenger, MessageType::PushStartQuickMatch).await;
+
+    id
+}
+
+#[tokio::test]
+async fn test_search_finds_open_matches_in_band() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut challenger = GameClient::connect(&context, game_addr, CHALLENGER).await;
+
+    let id = register(&mut host, QuickMatchMode::Duel, 50).await;
+
+    assert_eq!(
+        vec![id],
+        search(&mut challenger, QuickMatchMode::Duel, MAP, 55).await
+    );
+    assert!(search(&mut challenger, QuickMatchMode::Duel, MAP, 200)
+        .await
+        .is_empty());
+    assert!(search(&mut challenger, QuickMatchMode::Brawl, MAP, 55)
+        .await
+        .is_empty());
+    assert!(search(&mut challenger, QuickMatchMode::Duel, MAP + 1, 55)
+        .await
+        .is_empty());
+    assert!(search(&mut host, QuickMatchMode::Duel, MAP, 50)
+        .await
+        .is_empty());
+
+    let reply = join(&mut challenger, id, 200).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+}
+
+#[tokio::test]
+async fn test_duel_is_joined_started_and_reported() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut challenger = GameClient::connect(&context, game_addr, CHALLENGER).await;
+    let mut other_challenger = GameClient::connect(&context, game_addr, OTHER_CHALLENGER).await;
+
+    let id = register(&mut host, QuickMatchMode::Duel, 50).await;
+    let reply = start(&mut host, id).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    join(&mut challenger, id, 50)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    let push: PushRequestJoinQuickMatch =
+        receive(&mut host, MessageType::PushRequestJoinQuickMatch).await;
+    assert_eq!(id, push.get_match_id());
+    assert_eq!(CHALLENGER, push.get_steam_id());
+
+    // The match is full while the host decides.
+    assert!(search(&mut other_challenger, QuickMatchMode::Duel, MAP, 50)
+        .await
+        .is_empty());
+    let reply = join(&mut other_challenger, id, 50).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    accept(&mut host, id, CHALLENGER, true).await;
+    let push: PushJoinQuickMatchResult =
+        receive(&mut challenger, MessageType::PushJoinQuickMatchResult).await;
+    assert!(push.get_accepted());
+
+    start(&mut host, id)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    let push: PushStartQuickMatch =
+        receive(&mut challenger, MessageType::PushStartQuickMatch).await;
+    assert_eq!(id, push.get_match_id());
+    assert_eq!(&[HOST, CHALLENGER], push.get_steam_ids());
+
+    report(&mut host, id, MatchResult::Win)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    let reply = report(&mut host, id, MatchResult::Win).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    assert_eq!(1, context.quick_matches().get(id).unwrap().results.len());
+
+    report(&mut challenger, id, MatchResult::Lose)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    assert!(context.quick_matches().is_empty());
+
+    let ratings = context.config().ratings();
+    let host_rating = context.ratings().get(QuickMatchMode::Duel, HOST).unwrap();
+    assert_eq!(1, host_rating.wins);
+    assert!(host_rating.rating > ratings.initial_rating());
+    assert!(
+        context
+            .ratings()
+            .rating(ratings, QuickMatchMode::Duel, CHALLENGER)
+            < ratings.initial_rating()
+    );
+}
+
+#[tokio::test]
+async fn test_rejected_player_frees_their_slot() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut challenger = GameClient::connect(&context, game_addr, CHALLENGER).await;
+
+    let id = register(&mut host, QuickMatchMode::Duel, 50).await;
+    join(&mut challenger, id, 50)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    let _: PushRequestJoinQuickMatch =
+        receive(&mut host, MessageType::PushRequestJoinQuickMatch).await;
+
+    accept(&mut host, id, CHALLENGER, false).await;
+    let push: PushJoinQuickMatchResult =
+        receive(&mut challenger, MessageType::PushJoinQuickMatchResult).await;
+    assert!(!push.get_accepted());
+
+    assert_eq!(
+        vec![id],
+        search(&mut challenger, QuickMatchMode::Duel, MAP, 50).await
+    );
+}
+
+#[tokio::test]
+async fn test_stale_matches_are_dissolved() {
+    let context = common::context_with(
+        r#"
+[quick_matches]
+lobby_timeout_secs = 0
+"#,
+    );
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut challenger = GameClient::connect(&context, game_addr, CHALLENGER).await;
+
+    let id = register(&mut host, QuickMatchMode::Duel, 50).await;
+    join_and_accept(&mut host, &mut challenger, id).await;
+
+    assert_eq!(1, quick_match::dissolve_stale(&context));
+    assert!(context.quick_matches().is_empty());
+
+    let push: PushQuickMatchDissolved =
+        receive(&mut challenger, MessageType::PushQuickMatchDissolved).await;
+    assert_eq!(id, push.get_match_id());
+}
+
+#[tokio::test]
+async fn test_match_is_dissolved_when_host_leaves() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut challenger = GameClient::connect(&context, game_addr, CHALLENGER).await;
+
+    let id = register(&mut host, QuickMatchMode::TwoVersusTwo, 50).await;
+    join_and_accept(&mut host, &mut challenger, id).await;
+
+    let session = context.sessions().by_steam_id(HOST).unwrap();
+    context.sessions().evict(session.id);
+
+    let push: PushQuickMatchDissolved =
+        receive(&mut challenger, MessageType::PushQuickMatchDissolved).await;
+    assert_eq!(id, push.get_match_id());
+    assert!(context.quick_matches().is_empty());
+}
+
+#[tokio::test]
+async fn test_leaving_a_started_match_counts_as_a_loss() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut challenger = GameClient::connect(&context, game_addr, CHALLENGER).await;
+
+    let id = start_duel(&mut host, &mut challenger).await;
+    report(&mut host, id, MatchResult::Win)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let session = context.sessions().by_steam_id(CHALLENGER).unwrap();
+    quick_match::session_ended(&context, session.id);
+    assert!(context.quick_matches().is_empty());
+
+    let ratings = context.ratings();
+    assert_eq!(1, ratings.get(QuickMatchMode::Duel, HOST).unwrap().wins);
+    assert_eq!(
+        1,
+        ratings
+            .get(QuickMatchMode::Duel, CHALLENGER)
+            .unwrap()
+            .losses
+    );
+}
+
+#[tokio::test]
+async fn test_stale_started_match_is_settled_on_the_reports_received() {
+    let context = common::context_with(
+        r#"
+[quick_matches]
+lobby_timeout_secs = 0
+"#,
+    );
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut challenger = GameClient::connect(&context, game_addr, CHALLENGER).await;
+
+    let id = start_duel(&mut host, &mut challenger).await;
+    report(&mut challenger, id, MatchResult::Win)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    assert_eq!(1, quick_match::dissolve_stale(&context));
+    assert!(context.quick_matches().is_empty());
+
+    let ratings = context.ratings();
+    assert_eq!(1, ratings.get(QuickMatchMode::Duel, HOST).unwrap().losses);
+    assert_eq!(
+        1,
+        ratings.get(QuickMatchMode::Duel, CHALLENGER).unwrap().wins
+    );
+
+    // Nobody reported on this one, so it's dissolved without touching anyone's rating.
+    let id = start_duel(&mut host, &mut challenger).await;
+    assert_eq!(1, quick_match::dissolve_stale(&context));
+    let push: PushQuickMatchDissolved =
+        receive(&mut challenger, MessageType::PushQuickMatchDissolved).await;
+    assert_eq!(id, push.get_match_id());
+    assert_eq!(1, ratings.get(QuickMatchMode::Duel, HOST).unwrap().losses);
+}
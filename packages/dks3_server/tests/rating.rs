@@ -0,0 +1,227 @@
+use dks3_config::Ratings;
+use dks3_proto::Frpg2RequestMessage::{
+    RequestGetArenaLadder, RequestGetArenaLadderResponse, RequestRegisterQuickMatch,
+    RequestSearchQuickMatch, RequestSearchQuickMatchResponse,
+};
+use dks3_server::frame::MessageType;
+use dks3_server::quick_match::{MatchResult, QuickMatchMode};
+use dks3_server::rating::{Outcome, RatingStore};
+
+use common::{GameClient, TempDir};
+
+mod common;
+
+const SEARCHER: &str = "0110000100000001";
+const MAP: u32 = 0x0a00_0000;
+
+fn outcome(steam_id: &str, result: MatchResult) -> Outcome {
+    Outcome {
+        steam_id: steam_id.to_string(),
+        character_name: format!("Character {}", steam_id),
+        result,
+    }
+}
+
+fn rating(store: &RatingStore, steam_id: &str) -> f64 {
+    store.get(QuickMatchMode::Duel, steam_id).unwrap().rating
+}
+
+/// Have `winner` beat `loser` in a duel `times` times.
+fn beat(store: &RatingStore, winner: &str, loser: &str, times: usize) {
+    for _ in 0..times {
+        store.record(
+            &Ratings::default(),
+            QuickMatchMode::Duel,
+            &[
+                outcome(winner, MatchResult::Win),
+                outcome(loser, MatchResult::Lose),
+            ],
+        );
+    }
+}
+
+#[test]
+fn test_duel_moves_ratings_by_elo() {
+    let store = RatingStore::default();
+    beat(&store, "winner", "loser", 1);
+
+    assert!((rating(&store, "winner") - 1516.0).abs() < 1e-9);
+    assert!((rating(&store, "loser") - 1484.0).abs() < 1e-9);
+
+    let winner = store.get(QuickMatchMode::Duel, "winner").unwrap();
+    assert_eq!((1, 0, 0), (winner.wins, winner.losses, winner.draws));
+    assert_eq!("Character winner", winner.character_name);
+    assert_eq!(None, store.get(QuickMatchMode::Brawl, "winner"));
+
+    // An upset moves ratings further than an expected result.
+    beat(&store, "loser", "winner", 1);
+    assert!(rating(&store, "loser") - 1484.0 > 16.0);
+}
+
+#[test]
+fn test_team_matches_score_players_against_opponents() {
+    let store = RatingStore::default();
+    store.record(
+        &Ratings::default(),
+        QuickMatchMode::TwoVersusTwo,
+        &[
+            outcome("a", MatchResult::Win),
+            outcome("b", MatchResult::Win),
+            outcome("c", MatchResult::Lose),
+            outcome("d", MatchResult::Lose),
+        ],
+    );
+
+    for (steam_id, expected) in [("a", 1516.0), ("b", 1516.0), ("c", 1484.0), ("d", 1484.0)].iter()
+    {
+        let rating = store
+            .get(QuickMatchMode::TwoVersusTwo, steam_id)
+            .unwrap()
+            .rating;
+        assert!(
+            (rating - expected).abs() < 1e-9,
+            "{} is {}",
+            steam_id,
+            rating
+        );
+    }
+}
+
+#[test]
+fn test_draws_and_disputed_results() {
+    let store = RatingStore::default();
+    beat(&store, "strong", "weak", 5);
+    let (strong, weak) = (rating(&store, "strong"), rating(&store, "weak"));
+
+    // The better rated player loses rating by drawing with the worse.
+    store.record(
+        &Ratings::default(),
+        QuickMatchMode::Duel,
+        &[
+            outcome("strong", MatchResult::Draw),
+            outcome("weak", MatchResult::Draw),
+        ],
+    );
+    assert!(rating(&store, "strong") < strong);
+    assert!(rating(&store, "weak") > weak);
+
+    // Both players claiming the win changes nothing.
+    let (strong, weak) = (rating(&store, "strong"), rating(&store, "weak"));
+    store.record(
+        &Ratings::default(),
+        QuickMatchMode::Duel,
+        &[
+            outcome("strong", MatchResult::Win),
+            outcome("weak", MatchResult::Win),
+        ],
+    );
+    assert_eq!(strong, rating(&store, "strong"));
+    assert_eq!(weak, rating(&store, "weak"));
+
+    let strong = store.get(QuickMatchMode::Duel, "strong").unwrap();
+    assert_eq!((5, 0, 1), (strong.wins, strong.losses, strong.draws));
+    let weak = store.get(QuickMatchMode::Duel, "weak").unwrap();
+    assert_eq!((0, 5, 1), (weak.wins, weak.losses, weak.draws));
+}
+
+#[test]
+fn test_ratings_are_persisted() {
+    let dir = TempDir::new("ratings");
+    let storage = dir.storage();
+
+    let store = RatingStore::load(&storage).unwrap();
+    beat(&store, "winner", "loser", 1);
+    store.save(&storage).unwrap();
+
+    let store = RatingStore::load(&storage).unwrap();
+    assert!((rating(&store, "winner") - 1516.0).abs() < 1e-9);
+    assert_eq!(2, store.players(QuickMatchMode::Duel));
+}
+
+#[tokio::test]
+async fn test_ladder_is_paged_by_rating() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, SEARCHER).await;
+
+    beat(context.ratings(), "first", "third", 2);
+    beat(context.ratings(), "second", "fourth", 1);
+
+    let mut request = RequestGetArenaLadder::new();
+    request.set_mode(QuickMatchMode::Duel.id());
+    request.set_offset(1);
+    request.set_max_entries(2);
+
+    let response: RequestGetArenaLadderResponse = client
+        .request(MessageType::RequestGetArenaLadder, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+
+    assert_eq!(4, response.get_total());
+    let entries = response.get_entries();
+    assert_eq!(2, entries.len());
+    assert_eq!(
+        (2, "second"),
+        (entries[0].get_rank(), entries[0].get_steam_id())
+    );
+    assert_eq!(1516, entries[0].get_rating());
+    assert_eq!(
+        (3, "fourth"),
+        (entries[1].get_rank(), entries[1].get_steam_id())
+    );
+    assert_eq!(1, entries[1].get_losses());
+}
+
+#[tokio::test]
+async fn test_search_prefers_hosts_with_close_ratings() {
+    let context = common::context_with(
+        r#"
+[ratings]
+pairing_range = 100
+"#,
+    );
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut searcher = GameClient::connect(&context, game_addr, SEARCHER).await;
+
+    let hosts = ["0110000100000010", "0110000100000011", "0110000100000012"];
+    beat(context.ratings(), hosts[0], "other", 3);
+    beat(context.ratings(), hosts[1], "other", 1);
+    beat(context.ratings(), "other", hosts[2], 10);
+
+    let mut clients = vec![];
+    for steam_id in hosts.iter() {
+        let mut client = GameClient::connect(&context, game_addr, steam_id).await;
+        let mut request = RequestRegisterQuickMatch::new();
+        request.set_mode(QuickMatchMode::Duel.id());
+        request.set_map_id(MAP);
+        request.set_matching_parameter(common::matching_parameter(50, None));
+        client
+            .request(MessageType::RequestRegisterQuickMatch, &request)
+            .await
+            .unwrap()
+            .expect(MessageType::Reply)
+            .unwrap();
+        clients.push(client);
+    }
+
+    let mut request = RequestSearchQuickMatch::new();
+    request.set_mode(QuickMatchMode::Duel.id());
+    request.set_map_id(MAP);
+    request.set_matching_parameter(common::matching_parameter(50, None));
+
+    let response: RequestSearchQuickMatchResponse = searcher
+        .request(MessageType::RequestSearchQuickMatch, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+
+    let found: Vec<&str> = response
+        .get_matches()
+        .iter()
+        .map(|data| data.get_host_steam_id())
+        .collect();
+    assert_eq!(vec![hosts[1], hosts[0]], found);
+}
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use dks3_server::session::CharacterSummary;
+
+use common::GameClient;
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+
+#[tokio::test]
+async fn test_session_is_registered() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let _client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let session = context.sessions().by_steam_id(STEAM_ID).unwrap();
+    assert_eq!(STEAM_ID, session.steam_id);
+    assert_eq!(None, session.character);
+    assert_eq!(1, context.sessions().len());
+
+    let character = CharacterSummary {
+        name: "Solaire".to_string(),
+        soul_level: 50,
+        online_area_id: 300_000,
+        ..CharacterSummary::default()
+    };
+
+    context
+        .sessions()
+        .set_character(session.id, character.clone());
+    assert_eq!(
+        Some(character),
+        context.sessions().get(session.id).unwrap().character
+    );
+}
+
+#[tokio::test]
+async fn test_evicted_session_is_disconnected() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let session = context.sessions().by_steam_id(STEAM_ID).unwrap();
+    assert!(context.sessions().evict(session.id).is_some());
+    assert!(context.sessions().is_empty());
+
+    while client.connection().recv().await.is_some() {}
+}
+
+#[tokio::test]
+async fn test_idle_sessions_expire() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let _client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    assert!(context
+        .sessions()
+        .expire_idle(Duration::from_secs(60))
+        .is_empty());
+
+    let expired = context.sessions().expire_idle(Duration::from_secs(0));
+    assert_eq!(1, expired.len());
+    assert_eq!(STEAM_ID, expired[0].steam_id);
+    assert!(context.sessions().by_steam_id(STEAM_ID).is_none());
+}
@@ -0,0 +1,359 @@
+use std::time::Duration;
+
+use tokio::time::timeout;
+
+use dks3_proto::common::Vector3;
+use dks3_proto::Frpg2RequestMessage::{
+    PushRequestSummonSign, PushSummonSignResult, RequestCreateSign, RequestCreateSignResponse,
+    RequestGetSignList, RequestGetSignListResponse, RequestRespondSummonSign, RequestSummonSign,
+};
+use dks3_server::dispatch::ErrorCode;
+use dks3_server::frame::{Message, MessageType};
+
+use common::{GameClient, Status};
+
+mod common;
+
+const OWNER: &str = "0110000100000001";
+const HOST: &str = "0110000100000002";
+const OTHER_HOST: &str = "0110000100000003";
+const AREA: u32 = 300_000;
+
+fn position() -> Vector3 {
+    let mut position = Vector3::new();
+    position.set_x(1.0);
+    position.set_y(2.0);
+    position.set_z(3.0);
+    position
+}
+
+async fn create(client: &mut GameClient, online_area_id: u32, soul_level: u32) -> u64 {
+    create_with_password(client, online_area_id, soul_level, None).await
+}
+
+fn create_request(
+    online_area_id: u32,
+    soul_level: u32,
+    password: Option<&str>,
+) -> RequestCreateSign {
+    let mut request = RequestCreateSign::new();
+    request.set_online_area_id(online_area_id);
+    request.set_map_id(30_000_000);
+    request.set_position(position());
+    request.set_angle(0.0);
+    request.set_sign_type(0);
+    request.set_item_id(0x4000_0000);
+    request.set_matching_parameter(common::matching_parameter(soul_level, password));
+    request
+}
+
+async fn create_with_password(
+    client: &mut GameClient,
+    online_area_id: u32,
+    soul_level: u32,
+    password: Option<&str>,
+) -> u64 {
+    let request = create_request(online_area_id, soul_level, password);
+    let response: RequestCreateSignResponse = client
+        .request(MessageType::RequestCreateSign, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap();
+    response.get_sign_id()
+}
+
+async fn list(
+    client: &mut GameClient,
+    online_area_id: u32,
+    soul_level: u32,
+) -> RequestGetSignListResponse {
+    list_with_password(client, online_area_id, soul_level, None).await
+}
+
+async fn list_with_password(
+    client: &mut GameClient,
+    online_area_id: u32,
+    soul_level: u32,
+    password: Option<&str>,
+) -> RequestGetSignListResponse {
+    let mut request = RequestGetSignList::new();
+    request.set_online_area_id(online_area_id);
+    request.set_matching_parameter(common::matching_parameter(soul_level, password));
+
+    client
+        .request(MessageType::RequestGetSignList, &request)
+        .await
+        .unwrap()
+        .decode()
+        .unwrap()
+}
+
+async fn summon(client: &mut GameClient, sign_id: u64) -> Message {
+    let mut request = RequestSummonSign::new();
+    request.set_sign_id(sign_id);
+
+    client
+        .request(MessageType::RequestSummonSign, &request)
+        .await
+        .unwrap()
+}
+
+async fn respond(client: &mut GameClient, sign_id: u64, accepted: bool) -> Message {
+    let mut request = RequestRespondSummonSign::new();
+    request.set_sign_id(sign_id);
+    request.set_accepted(accepted);
+
+    client
+        .request(MessageType::RequestRespondSummonSign, &request)
+        .await
+        .unwrap()
+}
+
+async fn summon_result(client: &mut GameClient) -> PushSummonSignResult {
+    let push = timeout(Duration::from_secs(5), client.receive_push())
+        .await
+        .expect("no summon result was pushed")
+        .unwrap();
+    push.expect(MessageType::PushSummonSignResult).unwrap();
+    push.decode().unwrap()
+}
+
+#[tokio::test]
+async fn test_signs_are_listed_for_matching_hosts_in_area() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut owner = GameClient::connect(&context, game_addr, OWNER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    let id = create(&mut owner, AREA, 50).await;
+
+    let response = list(&mut host, AREA, 55).await;
+    assert_eq!(1, response.get_signs().len());
+    assert_eq!(id, response.get_signs()[0].get_sign_id());
+    assert_eq!(OWNER, response.get_signs()[0].get_steam_id());
+
+    assert!(list(&mut host, AREA, 120).await.get_signs().is_empty());
+    assert!(list(&mut host, AREA + 1, 50).await.get_signs().is_empty());
+    assert!(list(&mut owner, AREA, 50).await.get_signs().is_empty());
+}
+
+#[tokio::test]
+async fn test_password_signs_are_only_listed_for_same_password() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    let owners = [
+        ("0110000100000010", 50, None),
+        ("0110000100000011", 50, Some("sunbros")),
+        ("0110000100000012", 300, Some("sunbros")),
+        ("0110000100000013", 50, Some("darkwraiths")),
+    ];
+
+    let mut ids = vec![];
+    let mut clients = vec![];
+    for (steam_id, soul_level, password) in owners.iter() {
+        let mut client = GameClient::connect(&context, game_addr, steam_id).await;
+        ids.push(create_with_password(&mut client, AREA, *soul_level, *password).await);
+        clients.push(client);
+    }
+
+    let sign_ids = |response: RequestGetSignListResponse| {
+        let mut sign_ids: Vec<u64> = response
+            .get_signs()
+            .iter()
+            .map(|sign| sign.get_sign_id())
+            .collect();
+        sign_ids.sort();
+        sign_ids
+    };
+
+    assert_eq!(vec![ids[0]], sign_ids(list(&mut host, AREA, 50).await));
+    assert_eq!(
+        vec![ids[1], ids[2]],
+        sign_ids(list_with_password(&mut host, AREA, 50, Some("sunbros")).await)
+    );
+
+    // The password never goes out with the sign.
+    let response = list_with_password(&mut host, AREA, 50, Some("darkwraiths")).await;
+    assert_eq!(vec![ids[3]], sign_ids(response.clone()));
+    assert!(!response.get_signs()[0]
+        .get_matching_parameter()
+        .has_password());
+}
+
+#[tokio::test]
+async fn test_signs_cannot_be_summoned_without_matching() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut owner = GameClient::connect(&context, game_addr, OWNER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    let id = create_with_password(&mut owner, AREA, 50, Some("sunbros")).await;
+
+    let reply = summon(&mut host, id).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    common::update_status(&mut host, &Status::new(AREA, 50)).await;
+    let reply = summon(&mut host, id).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    let wrong_password = Status {
+        password: Some("darkwraiths"),
+        ..Status::new(AREA, 50)
+    };
+    common::update_status(&mut host, &wrong_password).await;
+    let reply = summon(&mut host, id).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    let out_of_range = create(&mut owner, AREA, 300).await;
+    common::update_status(&mut host, &Status::new(AREA, 50)).await;
+    let reply = summon(&mut host, out_of_range).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    let id = create_with_password(&mut owner, AREA, 50, Some("sunbros")).await;
+    let same_password = Status {
+        password: Some("sunbros"),
+        ..Status::new(AREA, 50)
+    };
+    common::update_status(&mut host, &same_password).await;
+    summon(&mut host, id)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_sign_at_non_finite_location_is_rejected() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut owner = GameClient::connect(&context, game_addr, OWNER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    let mut request = create_request(AREA, 50, None);
+    request.mut_position().set_z(f32::NAN);
+    let reply = owner
+        .request(MessageType::RequestCreateSign, &request)
+        .await
+        .unwrap();
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    assert!(list(&mut host, AREA, 50).await.get_signs().is_empty());
+}
+
+#[tokio::test]
+async fn test_new_sign_replaces_previous() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut owner = GameClient::connect(&context, game_addr, OWNER).await;
+
+    let first = create(&mut owner, AREA, 50).await;
+    let second = create(&mut owner, AREA, 50).await;
+
+    assert_eq!(None, context.signs().get(first));
+    assert!(context.signs().get(second).is_some());
+}
+
+#[tokio::test]
+async fn test_accepted_summon_uses_up_sign() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut owner = GameClient::connect(&context, game_addr, OWNER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut other_host = GameClient::connect(&context, game_addr, OTHER_HOST).await;
+
+    let id = create(&mut owner, AREA, 50).await;
+    common::update_status(&mut host, &Status::new(AREA, 50)).await;
+    summon(&mut host, id)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let push = owner.receive_push().await.unwrap();
+    push.expect(MessageType::PushRequestSummonSign).unwrap();
+    let push: PushRequestSummonSign = push.decode().unwrap();
+    assert_eq!(id, push.get_sign_id());
+    assert_eq!(HOST, push.get_host_steam_id());
+
+    // Nobody else can summon the owner while they're deciding.
+    common::update_status(&mut other_host, &Status::new(AREA, 50)).await;
+    assert!(list(&mut other_host, AREA, 50).await.get_signs().is_empty());
+    let reply = summon(&mut other_host, id).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+
+    respond(&mut owner, id, true)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let result = summon_result(&mut host).await;
+    assert_eq!(id, result.get_sign_id());
+    assert!(result.get_accepted());
+    assert!(context.signs().is_empty());
+}
+
+#[tokio::test]
+async fn test_rejected_summon_returns_sign_to_pool() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut owner = GameClient::connect(&context, game_addr, OWNER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    let id = create(&mut owner, AREA, 50).await;
+    common::update_status(&mut host, &Status::new(AREA, 50)).await;
+    summon(&mut host, id)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    owner.receive_push().await.unwrap();
+
+    respond(&mut owner, id, false)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+    assert!(!summon_result(&mut host).await.get_accepted());
+
+    let reply = respond(&mut owner, id, true).await;
+    assert_eq!(Some(ErrorCode::InvalidRequest), common::error_code(&reply));
+    assert_eq!(1, list(&mut host, AREA, 50).await.get_signs().len());
+}
+
+#[tokio::test]
+async fn test_abandoned_summon_is_rejected() {
+    let context = common::context_with("[signs]\nsummon_timeout_secs = 0");
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut owner = GameClient::connect(&context, game_addr, OWNER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+    let mut other_host = GameClient::connect(&context, game_addr, OTHER_HOST).await;
+
+    let id = create(&mut owner, AREA, 50).await;
+    for client in [&mut host, &mut other_host].iter_mut() {
+        common::update_status(client, &Status::new(AREA, 50)).await;
+        summon(client, id).await.expect(MessageType::Reply).unwrap();
+    }
+
+    let result = summon_result(&mut host).await;
+    assert_eq!(id, result.get_sign_id());
+    assert!(!result.get_accepted());
+}
+
+#[tokio::test]
+async fn test_signs_expire_when_owner_disconnects() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut owner = GameClient::connect(&context, game_addr, OWNER).await;
+    let mut host = GameClient::connect(&context, game_addr, HOST).await;
+
+    let id = create(&mut owner, AREA, 50).await;
+    common::update_status(&mut host, &Status::new(AREA, 50)).await;
+    summon(&mut host, id)
+        .await
+        .expect(MessageType::Reply)
+        .unwrap();
+
+    let session = context.sessions().by_steam_id(OWNER).unwrap();
+    context.sessions().evict(session.id);
+
+    assert!(!summon_result(&mut host).await.get_accepted());
+    assert!(context.signs().is_empty());
+}
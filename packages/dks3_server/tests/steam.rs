@@ -0,0 +1,136 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server, StatusCode};
+
+use dks3_server::steam::{Allowlist, TicketVerifier, Verdict};
+use dks3_server::ServerContext;
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+const OTHER_STEAM_ID: &str = "0110000100000002";
+const TICKET: &[u8] = b"ticket";
+
+/// A stand-in for a ticket verification service, accepting only `STEAM_ID` with `TICKET`.
+async fn spawn_verification_service() -> SocketAddr {
+    async fn verify(request: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let query = request.uri().query().unwrap_or_default();
+        let expected = format!("steam_id={}&ticket={}", STEAM_ID, hex::encode(TICKET));
+
+        let status = match request.uri().path() {
+            "/verify" if query == expected => StatusCode::OK,
+            "/verify" => StatusCode::FORBIDDEN,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        Ok(Response::builder()
+            .status(status)
+            .body(Body::empty())
+            .unwrap())
+    }
+
+    let make_service = make_service_fn(|_| async { Ok::<_, Infallible>(service_fn(verify)) });
+    let server = Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(make_service);
+    let addr = server.local_addr();
+    tokio::spawn(server);
+
+    addr
+}
+
+async fn authenticates(context: &ServerContext, steam_id: &str) -> bool {
+    let addr = common::spawn_auth_server(context).await;
+    common::authenticate(context, addr, steam_id, TICKET)
+        .await
+        .is_ok()
+}
+
+#[tokio::test]
+async fn test_accept_all_by_default() {
+    let context = common::context();
+
+    assert!(authenticates(&context, STEAM_ID).await);
+    assert!(authenticates(&context, OTHER_STEAM_ID).await);
+}
+
+#[tokio::test]
+async fn test_allowlist() {
+    let verifier = Allowlist::new(vec![STEAM_ID.to_string()]);
+
+    assert_eq!(
+        Verdict::Accepted,
+        verifier.verify(STEAM_ID, TICKET).await.unwrap()
+    );
+    assert!(matches!(
+        verifier.verify(OTHER_STEAM_ID, TICKET).await.unwrap(),
+        Verdict::Rejected(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_allowlist_from_config() {
+    let context = ServerContext::new(common::config_with(&format!(
+        r#"
+[steam]
+verifier = "allowlist"
+steam_ids = ["{}"]
+"#,
+        STEAM_ID
+    )))
+    .unwrap();
+
+    assert!(authenticates(&context, STEAM_ID).await);
+    assert!(!authenticates(&context, OTHER_STEAM_ID).await);
+}
+
+#[tokio::test]
+async fn test_http_verifier() {
+    let service = spawn_verification_service().await;
+    let context = ServerContext::new(common::config_with(&format!(
+        r#"
+[steam]
+verifier = "http"
+endpoint = "http://{}/verify"
+"#,
+        service
+    )))
+    .unwrap();
+
+    assert!(authenticates(&context, STEAM_ID).await);
+    assert!(!authenticates(&context, OTHER_STEAM_ID).await);
+}
+
+#[tokio::test]
+async fn test_http_verifier_errors_are_not_verdicts() {
+    let service = spawn_verification_service().await;
+    let context = ServerContext::new(common::config_with(&format!(
+        r#"
+[steam]
+verifier = "http"
+endpoint = "http://{}/broken"
+"#,
+        service
+    )))
+    .unwrap();
+
+    assert!(context
+        .ticket_verifier()
+        .verify(STEAM_ID, TICKET)
+        .await
+        .is_err());
+    assert!(!authenticates(&context, STEAM_ID).await);
+}
+
+#[test]
+fn test_invalid_verifier_config_is_an_error() {
+    let result = ServerContext::new(common::config_with(
+        r#"
+[steam]
+verifier = "http"
+endpoint = "not a uri"
+"#,
+    ));
+
+    assert!(result.is_err());
+}
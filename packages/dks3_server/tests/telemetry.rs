@@ -0,0 +1,125 @@
+use chrono::{DateTime, Utc};
+
+use dks3_proto::Frpg2RequestMessage::{
+    RequestNotifyBuyItem, RequestNotifyDeath, RequestNotifyJoinMultiplayer, RequestNotifyKillBoss,
+};
+use dks3_server::frame::MessageType;
+use dks3_server::telemetry::{self, Event, TelemetryStore};
+
+use common::{GameClient, TempDir};
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+const FIRELINK: u32 = 300_000;
+const HIGH_WALL: u32 = 300_001;
+
+fn time(rfc3339: &str) -> DateTime<Utc> {
+    rfc3339.parse().unwrap()
+}
+
+async fn notify<M: protobuf::Message>(
+    client: &mut GameClient,
+    message_type: MessageType,
+    body: &M,
+) {
+    client
+        .request(message_type, body)
+        .await
+        .unwrap()
+        .expect(MessageType::Reply)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_notifications_are_aggregated() {
+    let context = common::context();
+    let game_addr = common::spawn_game_server(&context).await;
+    let mut client = GameClient::connect(&context, game_addr, STEAM_ID).await;
+
+    let mut death = RequestNotifyDeath::new();
+    death.set_online_area_id(HIGH_WALL);
+    notify(&mut client, MessageType::RequestNotifyDeath, &death).await;
+    notify(&mut client, MessageType::RequestNotifyDeath, &death).await;
+
+    let mut boss = RequestNotifyKillBoss::new();
+    boss.set_online_area_id(HIGH_WALL);
+    boss.set_boss_id(14_000_800);
+    notify(&mut client, MessageType::RequestNotifyKillBoss, &boss).await;
+
+    let mut purchase = RequestNotifyBuyItem::new();
+    purchase.set_online_area_id(FIRELINK);
+    purchase.set_item_id(1_000);
+    purchase.set_quantity(5);
+    notify(&mut client, MessageType::RequestNotifyBuyItem, &purchase).await;
+    notify(&mut client, MessageType::RequestNotifyBuyItem, &purchase).await;
+
+    let mut join = RequestNotifyJoinMultiplayer::new();
+    join.set_online_area_id(FIRELINK);
+    notify(
+        &mut client,
+        MessageType::RequestNotifyJoinMultiplayer,
+        &join,
+    )
+    .await;
+
+    let stats = context
+        .telemetry()
+        .day(&telemetry::day(Utc::now()))
+        .unwrap();
+    assert_eq!(2, stats.totals.deaths);
+    assert_eq!(1, stats.totals.boss_kills);
+    assert_eq!(2, stats.totals.purchases);
+    assert_eq!(1, stats.totals.multiplayer_joins);
+
+    let high_wall = &stats.areas[&HIGH_WALL];
+    assert_eq!(
+        (2, 1, 0),
+        (high_wall.deaths, high_wall.boss_kills, high_wall.purchases)
+    );
+    assert_eq!(2, stats.areas[&FIRELINK].purchases);
+
+    assert_eq!(Some(&1), stats.bosses.get(&14_000_800));
+    let sales = &stats.items[&1_000];
+    assert_eq!((2, 10), (sales.purchases, sales.quantity));
+}
+
+#[test]
+fn test_oldest_days_are_dropped() {
+    let store = TelemetryStore::default();
+    let death = Event::Death {
+        online_area_id: FIRELINK,
+    };
+
+    for day in 1..=4 {
+        store.record(&death, time(&format!("2020-11-0{}T12:00:00Z", day)), 3);
+    }
+    store.record(&death, time("2020-11-04T23:59:59Z"), 3);
+
+    assert_eq!(vec!["2020-11-02", "2020-11-03", "2020-11-04"], store.days());
+    assert_eq!(2, store.day("2020-11-04").unwrap().totals.deaths);
+    assert_eq!(1, store.totals()["2020-11-02"].deaths);
+}
+
+#[test]
+fn test_statistics_are_persisted() {
+    let dir = TempDir::new("telemetry");
+    let storage = dir.storage();
+    let time = time("2020-11-01T00:00:00Z");
+
+    let store = TelemetryStore::load(&storage).unwrap();
+    store.record(
+        &Event::BossKill {
+            online_area_id: HIGH_WALL,
+            boss_id: 14_000_800,
+        },
+        time,
+        10,
+    );
+    store.save(&storage).unwrap();
+
+    let store = TelemetryStore::load(&storage).unwrap();
+    let stats = store.day("2020-11-01").unwrap();
+    assert_eq!(1, stats.areas[&HIGH_WALL].boss_kills);
+    assert_eq!(Some(&1), stats.bosses.get(&14_000_800));
+}
@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use tokio::net::UdpSocket;
+
+use dks3_server::transport::{self, ChannelConfig, Listener};
+
+mod common;
+
+const STEAM_ID: &str = "0110000100000001";
+
+#[tokio::test]
+async fn test_connect_and_echo() {
+    let context = common::context();
+    let auth_addr = common::spawn_auth_server(&context).await;
+    let auth = common::authenticate(&context, auth_addr, STEAM_ID, b"ticket")
+        .await
+        .unwrap();
+
+    let mut listener = Listener::bind(context.clone(), "127.0.0.1:0")
+        .await
+        .unwrap();
+    let game_addr = listener.local_addr();
+
+    tokio::spawn(async move {
+        let (mut connection, ticket) = listener.accept().await.unwrap();
+        assert_eq!(STEAM_ID, ticket.steam_id);
+
+        while let Some(payload) = connection.recv().await {
+            connection.send(payload).unwrap();
+        }
+    });
+
+    let mut client =
+        transport::connect(game_addr, auth.server_info.get_auth_token(), &auth.game_key)
+            .await
+            .unwrap();
+
+    let sent: Vec<Bytes> = (0..50)
+        .map(|i| Bytes::from(format!("payload {}", i)))
+        .collect();
+
+    for payload in &sent {
+        client.send(payload.clone()).unwrap();
+    }
+
+    for payload in &sent {
+        assert_eq!(Some(payload), client.recv().await.as_ref());
+    }
+}
+
+#[tokio::test]
+async fn test_unknown_token_is_refused() {
+    let context = common::context();
+    let listener = Listener::bind(context, "127.0.0.1:0").await.unwrap();
+
+    let config = ChannelConfig {
+        retransmit_timeout: Duration::from_millis(20),
+        max_attempts: 3,
+        ..ChannelConfig::default()
+    };
+
+    let mut client = transport::connect_with_config(listener.local_addr(), 1234, &[0; 16], config)
+        .await
+        .unwrap();
+
+    client.send(Bytes::from_static(b"hello")).unwrap();
+    assert_eq!(None, client.recv().await);
+}
+
+#[tokio::test]
+async fn test_corrupt_first_datagram_does_not_use_up_token() {
+    let context = common::context();
+    let auth_addr = common::spawn_auth_server(&context).await;
+    let auth = common::authenticate(&context, auth_addr, STEAM_ID, b"ticket")
+        .await
+        .unwrap();
+    let token = auth.server_info.get_auth_token();
+
+    let mut listener = Listener::bind(context.clone(), "127.0.0.1:0")
+        .await
+        .unwrap();
+    let game_addr = listener.local_addr();
+
+    let mut datagram = token.to_be_bytes().to_vec();
+    datagram.extend_from_slice(b"not a sealed packet");
+    let mut socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    socket.send_to(&datagram, game_addr).await.unwrap();
+
+    tokio::spawn(async move {
+        let (mut connection, _) = listener.accept().await.unwrap();
+        while let Some(payload) = connection.recv().await {
+            connection.send(payload).unwrap();
+        }
+    });
+
+    let mut client = transport::connect(game_addr, token, &auth.game_key)
+        .await
+        .unwrap();
+    client.send(Bytes::from_static(b"hello")).unwrap();
+    assert_eq!(Some(Bytes::from_static(b"hello")), client.recv().await);
+}
+
+#[tokio::test]
+async fn test_close_delivers_everything_sent_first() {
+    let context = common::context();
+    let auth_addr = common::spawn_auth_server(&context).await;
+    let auth = common::authenticate(&context, auth_addr, STEAM_ID, b"ticket")
+        .await
+        .unwrap();
+
+    let mut listener = Listener::bind(context.clone(), "127.0.0.1:0")
+        .await
+        .unwrap();
+    let game_addr = listener.local_addr();
+
+    let sent: Vec<Bytes> = (0..200)
+        .map(|i| Bytes::from(format!("payload {}", i)))
+        .collect();
+    let to_send = sent.clone();
+
+    tokio::spawn(async move {
+        let (mut connection, _) = listener.accept().await.unwrap();
+        connection.recv().await;
+        for payload in to_send {
+            connection.send(payload).unwrap();
+        }
+        connection.close();
+        while connection.recv().await.is_some() {}
+    });
+
+    let mut client =
+        transport::connect(game_addr, auth.server_info.get_auth_token(), &auth.game_key)
+            .await
+            .unwrap();
+    client.send(Bytes::from_static(b"hello")).unwrap();
+
+    for payload in &sent {
+        assert_eq!(Some(payload), client.recv().await.as_ref());
+    }
+    assert_eq!(None, client.recv().await);
+}